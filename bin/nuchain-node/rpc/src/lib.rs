@@ -114,6 +114,8 @@ where
 	C::Api: BabeApi<Block>,
 	C::Api: BlockBuilder<Block>,
     C::Api: pallet_did_rpc::DidRuntimeApi<Block, AccountId>,
+    C::Api: pallet_organization_rpc::OrganizationRuntimeApi<Block, AccountId>,
+    C::Api: pallet_certificate_rpc::CertificateRuntimeApi<Block, AccountId, BlockNumber>,
 	P: TransactionPool + 'static,
 	SC: SelectChain<Block> + 'static,
 	B: sc_client_api::Backend<Block> + Send + Sync + 'static,
@@ -128,6 +130,8 @@ where
 	use substrate_frame_rpc_system::{System, SystemApiServer};
 	use substrate_state_trie_migration_rpc::{StateMigration, StateMigrationApiServer};
     use pallet_did_rpc::{Did, DidApiServer};
+    use pallet_organization_rpc::{Organization, OrganizationApiServer};
+    use pallet_certificate_rpc::{Certificate, CertificateApiServer};
 
 	let mut io = RpcModule::new(());
 	let FullDeps { client, pool, select_chain, chain_spec, deny_unsafe, babe, grandpa } = deps;
@@ -176,7 +180,9 @@ where
 
 	io.merge(StateMigration::new(client.clone(), backend, deny_unsafe).into_rpc())?;
 	io.merge(Dev::new(client.clone(), deny_unsafe).into_rpc())?;
-    io.merge(Did::new(client, deny_unsafe).into_rpc())?;
+    io.merge(Did::new(client.clone(), deny_unsafe).into_rpc())?;
+    io.merge(Organization::new(client.clone()).into_rpc())?;
+    io.merge(Certificate::new(client).into_rpc())?;
 
 	Ok(io)
 }
@@ -31,8 +31,8 @@ use frame_support::{
 	pallet_prelude::Get,
 	parameter_types,
 	traits::{
-		AsEnsureOriginWithArg, ConstU128, ConstU16, ConstU32, Currency, EitherOfDiverse,
-		EqualPrivilegeOnly, Everything, Imbalance, InstanceFilter, KeyOwnerProofSystem,
+		AsEnsureOriginWithArg, ConstBool, ConstU128, ConstU16, ConstU32, Contains, Currency,
+		EitherOfDiverse, EqualPrivilegeOnly, Imbalance, InstanceFilter, KeyOwnerProofSystem,
 		LockIdentifier, Nothing, OnUnbalanced, U128CurrencyToVote,
 	},
 	weights::{
@@ -125,10 +125,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	// and set impl_version to 0. If only runtime
 	// implementation changes and behavior does not, then leave spec_version as
 	// is and increment impl_version.
-	spec_version: 270,
+	spec_version: 271,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 2,
+	transaction_version: 3,
 	state_version: 1,
 };
 
@@ -199,8 +199,18 @@ parameter_types! {
 
 const_assert!(NORMAL_DISPATCH_RATIO.deconstruct() >= AVERAGE_ON_INITIALIZE_RATIO.deconstruct());
 
+/// Filters out calls that can't succeed with the current runtime configuration.
+pub struct BaseFilter;
+impl Contains<Call> for BaseFilter {
+	fn contains(c: &Call) -> bool {
+		// `issue_as_nft` always fails while the certificate pallet has no NFT
+		// backend (`Nft = ()`), don't expose it until one is configured.
+		!matches!(c, Call::Certificate(pallet_certificate::Call::issue_as_nft { .. }))
+	}
+}
+
 impl frame_system::Config for Runtime {
-	type BaseCallFilter = Everything;
+	type BaseCallFilter = BaseFilter;
 	type BlockWeights = RuntimeBlockWeights;
 	type BlockLength = RuntimeBlockLength;
 	type DbWeight = RocksDbWeight;
@@ -1381,10 +1391,28 @@ impl pallet_vesting::Config for Runtime {
 	const MAX_VESTING_SCHEDULES: u32 = 28;
 }
 
+parameter_types! {
+	pub const ProofExpiryBlocks: BlockNumber = 7 * DAYS;
+	pub const MaxNetworkNameLength: u32 = 32;
+	pub const LiquidityMaxReasonLen: u32 = 128;
+	/// DID identity trusted to attest KYC with `kyc` delegates, set by governance.
+	/// The default account has no known key, nobody passes KYC until it is set.
+	pub storage LiquidityKycAttester: AccountId = AccountId::new([0u8; 32]);
+}
+
 impl pallet_liquidity::Config for Runtime {
   type Event = Event;
   type Currency = Balances;
   type OperatorOrigin = pallet_liquidity::EnsureOperator<Runtime>;
+  type ProofExpiryBlocks = ProofExpiryBlocks;
+  type MaxNetworkNameLength = MaxNetworkNameLength;
+  type MaxReasonLen = LiquidityMaxReasonLen;
+  type Public = <Signature as traits::Verify>::Signer;
+  type Signature = Signature;
+  type Kyc = pallet_liquidity::DidKyc<Runtime, LiquidityKycAttester>;
+  type RequireKyc = ConstBool<false>;
+  type BridgeFee = ConstU128<0>;
+  type FeeHandler = Treasury;
   type WeightInfo = pallet_liquidity::weights::SubstrateWeight<Runtime>;
 }
 
@@ -1512,6 +1540,11 @@ impl pallet_state_trie_migration::Config for Runtime {
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	pub const DidAttributeDeposit: Balance = 10 * CENTS;
+	pub const DidDelegateDeposit: Balance = 10 * CENTS;
+}
+
 impl pallet_did::Config for Runtime {
 	type Event = Event;
 	type Public = <Signature as traits::Verify>::Signer;
@@ -1519,6 +1552,16 @@ impl pallet_did::Config for Runtime {
 	type Time = Timestamp;
 	type WeightInfo = pallet_did::weights::SubstrateWeight<Runtime>;
     type MaxLength = ConstU32<64>;
+	type MaxDelegateTypeLength = ConstU32<64>;
+	type MaxAttributeNameLength = ConstU32<64>;
+	type MaxServiceIdLength = ConstU32<64>;
+	type MaxServiceTypeLength = ConstU32<64>;
+	type MaxServiceEndpointLength = ConstU32<256>;
+	type MaxServicePerDid = ConstU32<16>;
+	type MaxAttributes = ConstU32<64>;
+	type Currency = Balances;
+	type AttributeDeposit = DidAttributeDeposit;
+	type DelegateDeposit = DidDelegateDeposit;
 }
 
 parameter_types! {
@@ -1526,6 +1569,7 @@ parameter_types! {
 	pub const MaxOrgNameLength: u32 = 100;
 	pub const MaxMemberCount: u32 = 100;
 	pub const CreationFee: u64 = 20;
+	pub const OrganizationDeposit: u64 = 100;
 }
 
 impl pallet_organization::Config for Runtime {
@@ -1534,6 +1578,9 @@ impl pallet_organization::Config for Runtime {
 	type CreationFee = CreationFee;
 	type Currency = Balances;
 	type Payment = ();
+	type CreationFeeHandler = pallet_organization::NativeCreationFee<Runtime>;
+	type CreationDeposit = OrganizationDeposit;
+	type DeleteGuard = Certificate;
 	type ForceOrigin = EnsureRoot<AccountId>;
 	type MinOrgNameLength = MinOrgNameLength;
 	type MaxOrgNameLength = MaxOrgNameLength;
@@ -1543,6 +1590,7 @@ impl pallet_organization::Config for Runtime {
 	type Signature = Signature;
 	type Did = Did;
 	type MaxLength = ConstU32<64>;
+	type AdminChangeCooldown = ConstU32<DAYS>;
 	// type MaxHandledOrgCount = ConstU32<32>;
 }
 
@@ -1554,6 +1602,9 @@ impl pallet_certificate::Config for Runtime {
 	type WeightInfo = pallet_certificate::weights::SubstrateWeight<Runtime>;
     type MaxProps = ConstU32<10>;
 	type MaxLength = ConstU32<64>;
+	type MaxCertsPerHolder = ConstU32<1000>;
+	type MaxIssueBatch = ConstU32<100>;
+	type Nft = ();
 }
 
 construct_runtime!(
@@ -1659,7 +1710,17 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
-	pallet_nomination_pools::migration::v2::MigrateToV2<Runtime>,
+	(
+		pallet_nomination_pools::migration::v2::MigrateToV2<Runtime>,
+		pallet_organization::migrations::v1::MigrateToV1<Runtime>,
+		pallet_organization::migrations::v2::MigrateToV2<Runtime>,
+		pallet_organization::migrations::v3::MigrateToV3<Runtime>,
+		pallet_certificate::migrations::v7::MigrateToV7<Runtime>,
+		pallet_certificate::migrations::v8::MigrateToV8<Runtime>,
+		pallet_certificate::migrations::v9::MigrateToV9<Runtime>,
+		pallet_did::migrations::v1::MigrateToV1<Runtime>,
+		pallet_did::migrations::v2::MigrateToV2<Runtime>,
+	),
 >;
 
 // /// MMR helper types.
@@ -1893,6 +1954,98 @@ impl_runtime_apis! {
         fn get_owner(id: AccountId) -> Option<AccountId> {
             pallet_did::OwnerOf::<Runtime>::get(id)
         }
+
+        fn get_document(id: AccountId) -> pallet_did_runtime_api::DidDocument<AccountId> {
+            use pallet_did::Did as _;
+
+            pallet_did_runtime_api::DidDocument {
+                owner: Did::identity_owner(&id),
+                delegates: Did::valid_delegates_of(&id),
+                services: Did::services_of(&id)
+                    .into_iter()
+                    .map(|s| pallet_did_runtime_api::DidService {
+                        id: s.id.into_inner(),
+                        service_type: s.service_type.into_inner(),
+                        endpoint: s.endpoint.into_inner(),
+                    })
+                    .collect(),
+                id,
+            }
+        }
+
+        fn is_valid_delegate(
+            identity: AccountId,
+            delegate_type: Vec<u8>,
+            delegate: AccountId,
+        ) -> bool {
+            use pallet_did::Did as _;
+
+            Did::valid_delegate(&identity, &delegate_type, &delegate).is_ok()
+        }
+
+        fn has_valid_attribute(identity: AccountId, name: Vec<u8>) -> bool {
+            Did::has_valid_attribute(&identity, &name)
+        }
+    }
+
+    impl pallet_certificate_runtime_api::CertificateApi<Block, AccountId, BlockNumber> for Runtime {
+        fn verify(
+            issued_id: Vec<u8>,
+        ) -> Option<pallet_certificate_runtime_api::CertVerification<BlockNumber>> {
+            let issued_id: [u8; 11] = issued_id.try_into().ok()?;
+            let proof = Certificate::issued_cert(&issued_id)?;
+
+            Some(pallet_certificate_runtime_api::CertVerification {
+                valid: Certificate::valid_certificate(&issued_id),
+                cert_id: proof.cert_id,
+                human_id: proof.human_id,
+                recipient: proof.recipient,
+                time: proof.time,
+                expired: proof.expired,
+                revoked: proof.revoked,
+                revoked_at: proof.revoked_at,
+                block: proof.block,
+                signer_name: proof.signer_name,
+                props: proof
+                    .props
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|p| (p.name().to_vec(), p.value().to_vec()))
+                    .collect(),
+            })
+        }
+
+        fn is_valid(issued_id: Vec<u8>) -> bool {
+            match issued_id.try_into() {
+                Ok(issued_id) => Certificate::valid_certificate(&issued_id),
+                Err(_) => false,
+            }
+        }
+
+        fn certs_of_holder(holder: AccountId) -> Vec<(AccountId, Vec<u8>)> {
+            Certificate::certs_of_holder(&holder)
+                .into_iter()
+                .map(|(org_id, issued_id)| (org_id, issued_id.to_vec()))
+                .collect()
+        }
+    }
+
+    impl pallet_organization_runtime_api::OrganizationApi<Block, AccountId> for Runtime {
+        fn organization_by_index(index: u64) -> Option<AccountId> {
+            Organization::organization_index(index)
+        }
+
+        fn organization_count() -> u64 {
+            Organization::organization_count()
+        }
+
+        fn has_admin_access(org_id: AccountId, who: AccountId) -> bool {
+            Organization::has_admin_access(&org_id, &who)
+        }
+
+        fn predicted_org_id(name: Vec<u8>, description: Vec<u8>, website: Vec<u8>, email: Vec<u8>) -> AccountId {
+            Organization::predicted_org_id(&name, &description, &website, &email)
+        }
     }
 
 	impl pallet_contracts_rpc_runtime_api::ContractsApi<
@@ -0,0 +1,56 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+/// Issued certificate proof with its validity at the current block.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct CertVerification<BlockNumber> {
+	/// ID of the certificate.
+	pub cert_id: [u8; 32],
+	/// Human readable provider based ID.
+	pub human_id: Vec<u8>,
+	/// Recipient person name.
+	pub recipient: Vec<u8>,
+	/// Issuance time in milliseconds.
+	pub time: u64,
+	/// Expiration in days.
+	pub expired: Option<u64>,
+	/// Whether the certificate is revoked.
+	pub revoked: bool,
+	/// Revoked at block, if recorded.
+	///
+	/// Revocation has no timestamp, for historical queries compare this block
+	/// with the block of interest instead of relying on `revoked`.
+	pub revoked_at: Option<BlockNumber>,
+	/// Issued at block.
+	pub block: BlockNumber,
+	/// Signer person name.
+	pub signer_name: Option<Vec<u8>>,
+	/// Properties as pairs of name and value.
+	pub props: Vec<(Vec<u8>, Vec<u8>)>,
+	/// Whether the certificate is neither expired nor revoked at the current block.
+	pub valid: bool,
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait CertificateApi<AccountId, BlockNumber>
+	where
+		AccountId: Codec,
+		BlockNumber: Codec,
+	{
+		/// Get issued certificate `issued_id` with its validity,
+		/// `None` if the certificate does not exist.
+		fn verify(issued_id: Vec<u8>) -> Option<CertVerification<BlockNumber>>;
+
+		/// Check whether issued certificate `issued_id` is valid,
+		/// false if the certificate does not exist.
+		fn is_valid(issued_id: Vec<u8>) -> bool;
+
+		/// Get issued certificates of `holder` across organizations
+		/// as pairs of organization id and issued id.
+		fn certs_of_holder(holder: AccountId) -> Vec<(AccountId, Vec<u8>)>;
+	}
+}
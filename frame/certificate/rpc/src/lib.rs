@@ -0,0 +1,180 @@
+use codec::Codec;
+use jsonrpsee::{
+	core::{Error as JsonRpseeError, RpcResult},
+	proc_macros::rpc,
+};
+use sc_client_api::{BlockBackend, HeaderBackend};
+use serde::{Deserialize, Serialize};
+use sp_api::{BlockId, ProvideRuntimeApi};
+use sp_core::H256;
+use sp_runtime::traits::Block as BlockT;
+use std::{
+	marker::{PhantomData, Send, Sync},
+	sync::Arc,
+};
+
+#[rpc(client, server)]
+pub trait CertificateApi<BlockHash, AccountId, BlockNumber> {
+	/// Get issued certificate `issued_id` with its validity at the best block,
+	/// returns `None` for unknown ids.
+	#[method(name = "certificate_verify")]
+	fn verify(&self, issued_id: String) -> RpcResult<Option<CertVerification<BlockNumber>>>;
+
+	/// Check whether issued certificate `issued_id` is valid at the best block,
+	/// returns false for unknown ids.
+	#[method(name = "certificate_isValid")]
+	fn is_valid(&self, issued_id: String) -> RpcResult<bool>;
+
+	/// Get issued certificates of `holder` across organizations at the best block.
+	#[method(name = "certificate_certsOfHolder")]
+	fn certs_of_holder(&self, holder: AccountId) -> RpcResult<Vec<HolderCert<AccountId>>>;
+}
+
+/// Property of an issued certificate.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CertProp {
+	pub name: String,
+	pub value: String,
+}
+
+/// Issued certificate of a holder.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HolderCert<AccountId> {
+	pub org_id: AccountId,
+	pub issued_id: String,
+}
+
+/// Issued certificate as served over RPC.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CertVerification<BlockNumber> {
+	pub cert_id: H256,
+	pub human_id: String,
+	pub recipient: String,
+	pub time: u64,
+	pub expired: Option<u64>,
+	pub revoked: bool,
+	pub revoked_at: Option<BlockNumber>,
+	pub block: BlockNumber,
+	pub signer_name: Option<String>,
+	pub props: Vec<CertProp>,
+	pub valid: bool,
+}
+
+impl<BlockNumber> From<pallet_certificate_runtime_api::CertVerification<BlockNumber>>
+	for CertVerification<BlockNumber>
+{
+	fn from(cert: pallet_certificate_runtime_api::CertVerification<BlockNumber>) -> Self {
+		let text = |b: Vec<u8>| String::from_utf8_lossy(&b).into_owned();
+		Self {
+			cert_id: H256::from(cert.cert_id),
+			human_id: text(cert.human_id),
+			recipient: text(cert.recipient),
+			time: cert.time,
+			expired: cert.expired,
+			revoked: cert.revoked,
+			revoked_at: cert.revoked_at,
+			block: cert.block,
+			signer_name: cert.signer_name.map(text),
+			props: cert
+				.props
+				.into_iter()
+				.map(|(name, value)| CertProp { name: text(name), value: text(value) })
+				.collect(),
+			valid: cert.valid,
+		}
+	}
+}
+
+pub struct Certificate<Block: BlockT, Client> {
+	client: Arc<Client>,
+	_marker: PhantomData<Block>,
+}
+
+impl<Block: BlockT, Client> Certificate<Block, Client> {
+	/// Create a new Certificate API.
+	pub fn new(client: Arc<Client>) -> Self {
+		Self { client, _marker: PhantomData::default() }
+	}
+}
+
+pub use pallet_certificate_runtime_api::CertificateApi as CertificateRuntimeApi;
+
+impl<Block, Client, AccountId, BlockNumber>
+	CertificateApiServer<Block::Hash, AccountId, BlockNumber> for Certificate<Block, Client>
+where
+	Block: BlockT,
+	Client: BlockBackend<Block>
+		+ HeaderBackend<Block>
+		+ ProvideRuntimeApi<Block>
+		+ Send
+		+ Sync
+		+ 'static,
+	AccountId: Codec + Send + Sync + Serialize + for<'de> Deserialize<'de> + 'static,
+	BlockNumber: Codec + Send + Sync + Serialize + for<'de> Deserialize<'de> + 'static,
+	Client::Api: pallet_certificate_runtime_api::CertificateApi<Block, AccountId, BlockNumber>,
+{
+	fn verify(&self, issued_id: String) -> RpcResult<Option<CertVerification<BlockNumber>>> {
+		let api = self.client.runtime_api();
+		let block_id = BlockId::hash(self.client.info().best_hash);
+
+		api.verify(&block_id, issued_id.into_bytes())
+			.map(|cert| cert.map(Into::into))
+			.map_err(|e| JsonRpseeError::to_call_error(e))
+	}
+
+	fn is_valid(&self, issued_id: String) -> RpcResult<bool> {
+		let api = self.client.runtime_api();
+		let block_id = BlockId::hash(self.client.info().best_hash);
+
+		api.is_valid(&block_id, issued_id.into_bytes())
+			.map_err(|e| JsonRpseeError::to_call_error(e))
+	}
+
+	fn certs_of_holder(&self, holder: AccountId) -> RpcResult<Vec<HolderCert<AccountId>>> {
+		let api = self.client.runtime_api();
+		let block_id = BlockId::hash(self.client.info().best_hash);
+
+		api.certs_of_holder(&block_id, holder)
+			.map(|certs| {
+				certs
+					.into_iter()
+					.map(|(org_id, issued_id)| HolderCert {
+						org_id,
+						issued_id: String::from_utf8_lossy(&issued_id).into_owned(),
+					})
+					.collect()
+			})
+			.map_err(|e| JsonRpseeError::to_call_error(e))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn cert_verification_serializes_as_camel_case() {
+		let cert: CertVerification<u32> = pallet_certificate_runtime_api::CertVerification {
+			cert_id: [1u8; 32],
+			human_id: b"ORG/KOM/11321".to_vec(),
+			recipient: b"Dave Grohl".to_vec(),
+			time: 1_600_000_000_000,
+			expired: Some(1),
+			revoked: false,
+			revoked_at: None,
+			block: 7,
+			signer_name: None,
+			props: vec![(b"grade".to_vec(), b"A".to_vec())],
+			valid: true,
+		}
+		.into();
+
+		let json = serde_json::to_value(&cert).unwrap();
+		assert_eq!(json["humanId"], "ORG/KOM/11321");
+		assert_eq!(json["props"][0]["name"], "grade");
+		assert_eq!(json["valid"], true);
+		assert_eq!(json["certId"], format!("0x{}", "01".repeat(32)));
+	}
+}
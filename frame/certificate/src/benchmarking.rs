@@ -136,6 +136,31 @@ benchmarks! {
 		let now = get_time_now::<T>();
 	}: _(RawOrigin::Signed(caller), org_id, cert_id, b"cert/01".to_vec(), b"Bob".to_vec(), None, None, Some(now))
 
+	issue_batch {
+		let r in 1 .. T::MaxIssueBatch::get();
+		let (caller, org_id) = setup::<T>();
+		let cert_detail:CertDetail<T::AccountId> = CertDetail::<T::AccountId>::new(org_id.clone()).signer(SIGNER.to_vec());
+		let cert_id:CertId = Certificate::<T>::generate_hash(cert_detail.encode());
+		Certificates::<T>::insert(cert_id, cert_detail);
+		let now = get_time_now::<T>();
+		let recipients = (0 .. r)
+			.map(|i| ([&b"cert/"[..], &i.to_le_bytes()].concat(), b"Bob".to_vec(), Some(account("holder", i, 0))))
+			.collect::<Vec<_>>();
+	}: _(RawOrigin::Signed(caller), org_id, cert_id, recipients, None, Some(now))
+
+	// requires the runtime to provide a working `Nft`, minting fails with `Nft = ()`.
+	issue_as_nft {
+		let (caller, org_id) = setup::<T>();
+		let cert_detail:CertDetail<T::AccountId> = CertDetail::<T::AccountId>::new(org_id.clone()).signer(SIGNER.to_vec());
+		let cert_id:CertId = Certificate::<T>::generate_hash(cert_detail.encode());
+		Certificates::<T>::insert(cert_id, cert_detail);
+		let now = get_time_now::<T>();
+		let holder: T::AccountId = account("holder", 0, 0);
+	}: _(RawOrigin::Signed(caller), org_id, cert_id, b"cert/01".to_vec(), b"Bob".to_vec(), None, holder.clone(), Some(now))
+	verify {
+		assert_eq!(Certificate::<T>::certs_of_holder(&holder).len(), 1);
+	}
+
 	revoke {
 		let (caller, org_id) = setup::<T>();
 
@@ -161,9 +186,72 @@ benchmarks! {
 			block: T::BlockNumber::one(),
 			signer_name: None,
 			props: None,
+			last_known_valid: true,
+			revoked_at: None,
 		};
 		IssuedCert::<T>::insert(&issued_id, proof);
-		IssuedCertOwner::<T>::insert(&org_id, &caller, vec![issued_id.clone()]);
+		IssuedCertOwner::<T>::insert(
+			&org_id,
+			&caller,
+			BoundedVec::try_from(vec![issued_id.clone()]).unwrap(),
+		);
 
 	}: _(RawOrigin::Signed(caller), org_id, issued_id, true)
+
+	update_issued {
+		let (caller, org_id) = setup::<T>();
+
+		let cert_detail:CertDetail<T::AccountId> = CertDetail::<T::AccountId>::new(org_id.clone()).signer(SIGNER.to_vec());
+		let cert_id:CertId = Certificate::<T>::generate_hash(cert_detail.encode());
+		Certificates::<T>::insert(cert_id, cert_detail);
+		let now = get_time_now::<T>();
+		let issued_id = [1u8; 11];
+
+		let proof = CertProof {
+			cert_id,
+			human_id: b"cert/01".to_vec(),
+			recipient: b"Bob".to_vec(),
+			time: now,
+			expired: Some(1),
+			revoked: false,
+			block: T::BlockNumber::one(),
+			signer_name: None,
+			props: None,
+			last_known_valid: true,
+			revoked_at: None,
+		};
+		IssuedCert::<T>::insert(&issued_id, proof);
+		let props = (0 .. MAX_PROPS)
+			.map(|i| Property::new(vec![b'a'; PROP_NAME_MAX_LENGTH], vec![b'0' + i as u8]))
+			.collect::<Vec<_>>();
+
+	}: _(RawOrigin::Signed(caller), org_id, issued_id, Some(365), Some(props))
+	verify {
+		assert_last_event::<T>(Event::<T>::CertIssuedUpdated(issued_id).into());
+	}
+
+	validate_certificate {
+		let caller: T::AccountId = whitelisted_caller();
+		let cert_id: CertId = Certificate::<T>::generate_hash(b"CERT1".to_vec());
+		let issued_id = [1u8; 11];
+
+		// revoked with stale cached validity
+		let proof = CertProof {
+			cert_id,
+			human_id: b"cert/01".to_vec(),
+			recipient: b"Bob".to_vec(),
+			time: get_time_now::<T>(),
+			expired: None,
+			revoked: true,
+			block: T::BlockNumber::one(),
+			signer_name: None,
+			props: None,
+			last_known_valid: true,
+			revoked_at: Some(T::BlockNumber::one()),
+		};
+		IssuedCert::<T>::insert(&issued_id, proof);
+	}: _(RawOrigin::Signed(caller), issued_id)
+	verify {
+		assert!(!IssuedCert::<T>::get(&issued_id).unwrap().last_known_valid);
+	}
 }
@@ -31,49 +31,79 @@
 //! * `create` - Create certificate.
 //! * `update` - Update certificate.
 //! * `issue` - Issue certificate.
+//! * `issue_as_nft` - Issue certificate backed by a soulbound NFT.
 //! * `revoke` - Revoke certificate.
+//! * `update_issued` - Update expiry and properties of issued certificate.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use base58::ToBase58;
 
 use frame_support::{
+	dispatch::DispatchError,
 	ensure,
 	traits::{EnsureOrigin, StorageVersion, UnixTime},
-	types::Text,
+	types::{now_millis, Text, MILLIS_PER_DAY},
+	Parameter,
 };
 use frame_system::ensure_signed;
 pub use pallet::*;
-use sp_runtime::{traits::Hash, RuntimeDebug, SaturatedConversion};
+use sp_runtime::{traits::Hash, RuntimeDebug};
 use sp_std::{prelude::*, vec};
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod migrations;
 pub mod weights;
 pub use weights::WeightInfo;
 
 use codec::{Decode, Encode};
 
 use core::convert::TryInto;
-// use frame_support::BoundedVec;
+use frame_support::BoundedVec;
 
 /// The current storage version.
-const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(9);
 
 type CertId = [u8; 32];
 type IssuedId = [u8; 11];
+type NftIdOf<T> =
+	<<T as Config>::Nft as CertificateNft<<T as frame_system::Config>::AccountId>>::NftId;
 
 pub const MAX_PROPS: usize = 5;
 pub const PROP_NAME_MAX_LENGTH: usize = 10;
 pub const PROP_VALUE_MAX_LENGTH: usize = 60;
+pub const SIGNER_NAME_MAX_LENGTH: usize = 100;
 
 // type Property<T> = frame_support::types::Property<
 // 	BoundedVec<u8, <T as pallet::Config>::MaxLength>,
 // 	BoundedVec<u8, <T as pallet::Config>::MaxLength>,
 // >;
-use frame_support::types::Property;
+use frame_support::types::{check_props, PropError, Property};
 use pallet_organization::Organization;
 
+/// Mints the soulbound NFT backing a certificate issued with `issue_as_nft`,
+/// keeps the NFT pallet an optional dependency of the runtime.
+pub trait CertificateNft<AccountId> {
+	/// ID of the minted NFT.
+	type NftId: Parameter;
+
+	/// Mint a non-transferable NFT of the issued certificate owned by `recipient`.
+	fn mint_soulbound(
+		recipient: &AccountId,
+		issued_id: &IssuedId,
+	) -> Result<Self::NftId, DispatchError>;
+}
+
+/// No NFT pallet, `issue_as_nft` always fails.
+impl<AccountId> CertificateNft<AccountId> for () {
+	type NftId = ();
+
+	fn mint_soulbound(_recipient: &AccountId, _issued_id: &IssuedId) -> Result<(), DispatchError> {
+		Err(DispatchError::Other("certificate NFT not supported"))
+	}
+}
+
 // type PropertyOrg<T> = frame_support::types::Property<
 // 	BoundedVec<u8, <T as pallet_organization::Config>::MaxLength>,
 // 	BoundedVec<u8, <T as pallet_organization::Config>::MaxLength>,
@@ -126,6 +156,17 @@ pub mod pallet {
 		/// The maximum length a name may be.
 		#[pallet::constant]
 		type MaxLength: Get<u32>;
+
+		/// The maximum number of certificates issued by an organization to one holder.
+		#[pallet::constant]
+		type MaxCertsPerHolder: Get<u32>;
+
+		/// The maximum number of recipients of `issue_batch`.
+		#[pallet::constant]
+		type MaxIssueBatch: Get<u32>;
+
+		/// Soulbound NFT minted by `issue_as_nft`.
+		type Nft: CertificateNft<Self::AccountId>;
 	}
 
 	#[pallet::error]
@@ -160,6 +201,24 @@ pub mod pallet {
 		/// Invalid properties value.
 		InvalidPropValue,
 
+		/// Organization still has certificates.
+		OrganizationHasCertificates,
+
+		/// Unknown properties value type.
+		InvalidPropValueType,
+
+		/// Holder already has the maximum number of certificates of the organization.
+		TooManyCerts,
+
+		/// Too many recipients in one batch.
+		TooManyRecipients,
+
+		/// New value is the same as the current one.
+		NotChanged,
+
+		/// Issued certificate is revoked.
+		CertificateRevoked,
+
 		/// Unknown error occurred
 		Unknown,
 	}
@@ -190,6 +249,24 @@ pub mod pallet {
 		///     2 - Organization ID.
 		///     3 - Recipient of certificate.
 		CertIssued(IssuedId, T::AccountId, Option<T::AccountId>),
+
+		/// Soulbound NFT minted for issued cert
+		///
+		/// params:
+		///     1 - Hash of issued certificate.
+		///     2 - ID of the NFT.
+		///     3 - Owner of the NFT.
+		CertNftIssued(IssuedId, NftIdOf<T>, T::AccountId),
+
+		/// Issued cert revoked or restored
+		///
+		/// params:
+		///     1 - Hash of issued certificate.
+		///     2 - Whether the certificate is now revoked.
+		CertRevoked(IssuedId, bool),
+
+		/// Expiry and properties of issued cert updated.
+		CertIssuedUpdated(IssuedId),
 	}
 
 	#[pallet::storage]
@@ -209,7 +286,8 @@ pub mod pallet {
 		/// Organization ID
 		pub org_id: AccountId,
 
-		/// Name of person who publish the certificate.
+		/// Name of person who publish the certificate,
+		/// defaults to the organization name.
 		pub signer_name: Option<Text>,
 	}
 
@@ -224,7 +302,7 @@ pub mod pallet {
 		/// Recipient person name of the certificate
 		pub recipient: Text,
 
-		/// Creation time
+		/// Creation time in milliseconds
 		pub time: u64,
 
 		/// Expiration in days
@@ -241,6 +319,45 @@ pub mod pallet {
 
 		/// Additional data to embed
 		pub props: Option<Vec<Property<Text, Text>>>,
+
+		/// Cached validity, updated on revoke and refreshed by `validate_certificate`,
+		/// may be stale after the certificate expired.
+		pub last_known_valid: bool,
+
+		/// Revoked at block, `None` when not revoked or revoked before
+		/// the revocation block was recorded.
+		pub revoked_at: Option<BlockNumber>,
+	}
+
+	/// Status of an issued certificate at a point of time.
+	#[derive(Decode, Encode, Clone, Copy, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+	pub enum CertStatus {
+		Valid,
+		Expired,
+		Revoked,
+	}
+
+	impl<BlockNumber> CertProof<BlockNumber> {
+		/// Expiry time in milliseconds, `expired` days after `time`.
+		pub fn expires_at(&self) -> Option<u64> {
+			self.expired
+				.map(|days| self.time.saturating_add(days.saturating_mul(MILLIS_PER_DAY)))
+		}
+
+		/// Status at `at` time in milliseconds.
+		///
+		/// Revocation is recorded by block only (`revoked_at`), which cannot be compared with
+		/// `at`, so a revoked certificate is reported revoked at any time, including times
+		/// before it was revoked.
+		pub fn status_at(&self, at: u64) -> CertStatus {
+			if self.revoked {
+				CertStatus::Revoked
+			} else if self.expires_at().map(|expires_at| at >= expires_at).unwrap_or(false) {
+				CertStatus::Expired
+			} else {
+				CertStatus::Valid
+			}
+		}
 	}
 
 	/// double map pair of: Issued id -> Proof
@@ -260,10 +377,28 @@ pub mod pallet {
 		Blake2_128Concat,
 		T::AccountId, // organization id
 		Blake2_128Concat,
-		T::AccountId,  // acc handler id
-		Vec<IssuedId>, // proof: id of issued certs
+		T::AccountId,                               // acc handler id
+		BoundedVec<IssuedId, T::MaxCertsPerHolder>, // proof: id of issued certs
 	>;
 
+	/// Issued certificates of a holder across organizations:
+	/// Acc handler id -> Issued id -> Organization id,
+	/// see [`Pallet::certs_of_holder`].
+	#[pallet::storage]
+	pub type IssuedCertsByHolder<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId, // acc handler id
+		Blake2_128Concat,
+		IssuedId,
+		T::AccountId, // organization id
+	>;
+
+	/// Soulbound NFT backing issued certificate: Issued id -> NFT id
+	#[pallet::storage]
+	#[pallet::getter(fn issued_cert_nft)]
+	pub type IssuedCertNft<T: Config> = StorageMap<_, Blake2_128Concat, IssuedId, NftIdOf<T>>;
+
 	/// Collection of certificates inside organization
 	#[pallet::storage]
 	#[pallet::getter(fn certificate_of_org)]
@@ -289,12 +424,14 @@ pub mod pallet {
 		/// The dispatch origin for this call must be _Signed_
 		/// and has access to the organization.
 		///
+		/// When `signer_name` is omitted it defaults to the organization name.
+		///
 		/// # <weight>
 		/// # </weight>
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::create())]
 		pub fn create(
 			origin: OriginFor<T>,
-			detail: CertDetail<T::AccountId>,
+			mut detail: CertDetail<T::AccountId>,
 		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
 
@@ -305,7 +442,7 @@ pub mod pallet {
 			ensure!(detail.description.len() <= 1000, Error::<T>::TooLong);
 
 			if let Some(ref signer_name) = detail.signer_name {
-				ensure!(signer_name.len() <= 100, Error::<T>::TooLong);
+				ensure!(signer_name.len() <= SIGNER_NAME_MAX_LENGTH, Error::<T>::TooLong);
 			}
 
 			// ensure access
@@ -313,6 +450,12 @@ pub mod pallet {
 				.ok_or(Error::<T>::OrganizationNotExists)?;
 			Self::ensure_org_access2(&sender, &org)?;
 
+			// default signer name to the organization name when omitted
+			if detail.signer_name.is_none() {
+				ensure!(org.name.len() <= SIGNER_NAME_MAX_LENGTH, Error::<T>::TooLong);
+				detail.signer_name = Some(org.name.to_vec());
+			}
+
 			let index = Self::increment_index();
 			let cert_id: CertId = Self::generate_hash(detail.encode());
 
@@ -350,7 +493,7 @@ pub mod pallet {
 			let sender = ensure_signed(origin)?;
 
 			ensure!(signer_name.len() > 1, Error::<T>::TooShort);
-			ensure!(signer_name.len() <= 100, Error::<T>::TooLong);
+			ensure!(signer_name.len() <= SIGNER_NAME_MAX_LENGTH, Error::<T>::TooLong);
 
 			let cert = Certificates::<T>::get(cert_id).ok_or(Error::<T>::NotExists)?;
 
@@ -393,113 +536,105 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
 
-			// let cert_id:BoundedVec<u8, MaxLength<T>> = cert_id.try_into().map_err(|_|
-			// Error::<T>::TooLong)?;
+			Self::do_issue(
+				&sender,
+				org_id,
+				cert_id,
+				human_id,
+				recipient,
+				props,
+				acc_handler,
+				expired,
+			)?;
 
-			let cert = Certificates::<T>::get(cert_id).ok_or(Error::<T>::NotExists)?;
+			Ok(().into())
+		}
 
-			if let Some(ref props) = props {
-				ensure!((props.len() as u32) < T::MaxProps::get(), Error::<T>::TooManyProps);
+		/// Issue certificate to many recipients at once.
+		///
+		/// `recipients` are pairs of human readable id, recipient name and optional
+		/// account handler, all get the same `props` and `expired`.
+		///
+		/// The dispatch origin for this call must be _signed_
+		/// and has access to organization as admin.
+		#[pallet::weight(T::WeightInfo::issue_batch(recipients.len() as u32))]
+		pub fn issue_batch(
+			origin: OriginFor<T>,
+			org_id: T::AccountId,
+			cert_id: CertId,
+			recipients: Vec<(Text, Text, Option<T::AccountId>)>,
+			props: Option<Vec<Property<Text, Text>>>,
+			expired: Option<u64>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			ensure!(
+				recipients.len() as u32 <= T::MaxIssueBatch::get(),
+				Error::<T>::TooManyRecipients
+			);
+
+			let (cert, org) = Self::ensure_can_issue(&sender, &org_id, cert_id, &props)?;
+			for (human_id, recipient, acc_handler) in recipients {
+				Self::issue_checked(
+					&cert,
+					&org,
+					org_id.clone(),
+					cert_id,
+					human_id,
+					recipient,
+					props.clone(),
+					acc_handler,
+					expired,
+				)?;
 			}
 
-			ensure!(human_id.len() < 100, Error::<T>::TooLong);
-			ensure!(recipient.len() < 100, Error::<T>::TooLong);
-
-			Self::validate_props(&props)?;
+			Ok(().into())
+		}
 
-			// ensure access
-			let org = <pallet_organization::Pallet<T>>::organization(&org_id)
-				.ok_or(Error::<T>::OrganizationNotExists)?;
-			Self::ensure_org_access2(&sender, &org)?;
+		/// Issue certificate like `issue` and mint a soulbound NFT of it
+		/// owned by `acc_handler`.
+		///
+		/// The dispatch origin for this call must be _signed_
+		/// and has access to organization as admin.
+		///
+		/// Always fails when the runtime sets `Nft = ()`, no certificate is
+		/// issued in that case. The Nuchain runtime filters this call out
+		/// until it configures an NFT backend.
+		#[pallet::weight(T::WeightInfo::issue_as_nft())]
+		pub fn issue_as_nft(
+			origin: OriginFor<T>,
+			org_id: T::AccountId,
+			cert_id: CertId,
+			human_id: Text,
+			recipient: Text,
+			props: Option<Vec<Property<Text, Text>>>,
+			acc_handler: T::AccountId,
+			expired: Option<u64>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
 
-			// generate issue id
-			// this id is unique per user per cert.
-			let data = org_id
-				.as_ref()
-				.iter()
-				.chain(cert_id.encode().iter())
-				.chain(human_id.iter())
-				.chain(recipient.iter())
-				.cloned()
-				.collect::<Vec<u8>>();
-
-			let data = if let Some(ref props) = props {
-				data.iter().chain(props.encode().iter()).cloned().collect::<Vec<u8>>()
-			} else {
-				data.iter().cloned().collect::<Vec<u8>>()
-			};
-			let issued_id: IssuedId = Self::generate_issued_id(&org, data);
-
-			// pastikan belum pernah di-issue
-			ensure!(!IssuedCert::<T>::contains_key(&issued_id), Error::<T>::AlreadyExists);
-
-			let block = <frame_system::Pallet<T>>::block_number();
-			let signer_name = cert.signer_name.clone();
-
-			// let human_id: BoundedVec<u8, MaxLength<T>> =
-			// 	human_id.try_into().map_err(|_| Error::<T>::TooLong)?;
-			// let recipient: BoundedVec<u8, MaxLength<T>> =
-			// 	recipient.try_into().map_err(|_| Error::<T>::TooLong)?;
-			// let signer_name: Option<BoundedVec<u8, MaxLength<T>>> =
-			// 	if let Some(signer_name) = signer_name {
-			// 		Some(signer_name.try_into().map_err(|_| Error::<T>::TooLong)?)
-			// 	} else {
-			// 		return Err(Error::<T>::TooLong.into())
-			// 	};
-			// let props: Option<BoundedVec<Property<T>, MaxLength<T>>> = if let Some(props) = props
-			// { 	props
-			// 		.into_iter()
-			// 		.flat_map(|p| {
-			// 			let v: Option<Property<T>> = p.try_into().ok();
-			// 			v
-			// 		})
-			// 		.collect::<Vec<_>>()
-			// 		.try_into()
-			// 		.ok()
-			// } else {
-			// 	return Err(Error::<T>::TooLong.into())
-			// };
-
-			let proof = CertProof {
+			let issued_id = Self::do_issue(
+				&sender,
+				org_id,
 				cert_id,
 				human_id,
 				recipient,
-				time: <T as pallet::Config>::Time::now().as_millis().saturated_into::<u64>(),
-				expired,
-				revoked: false,
-				block,
-				signer_name,
 				props,
-			};
-
-			if let Some(ref acc_handler) = acc_handler {
-				// apabila sudah pernah diisi update isinya
-				// dengan ditambahkan sertifikat pada koleksi penerima.
-				IssuedCertOwner::<T>::try_mutate::<&T::AccountId, &T::AccountId, _, Error<T>, _>(
-					&org_id,
-					acc_handler,
-					|vs| {
-						if let Some(vs) = vs.as_mut() {
-							vs.push(issued_id.clone());
-						} else {
-							// let x: BoundedVec<IssuedId, MaxLength<T>> =
-							// vec![issued_id.clone()].try_into().map_err(|_| Error::<T>::TooLong)?;
-							*vs = Some(vec![issued_id.clone()]);
-						}
-						Ok(())
-					},
-				)?;
-			}
+				Some(acc_handler.clone()),
+				expired,
+			)?;
 
-			IssuedCert::<T>::insert(&issued_id, proof);
+			let nft_id = T::Nft::mint_soulbound(&acc_handler, &issued_id)?;
+			IssuedCertNft::<T>::insert(&issued_id, &nft_id);
 
-			Self::deposit_event(Event::CertIssued(issued_id, org_id, acc_handler));
+			Self::deposit_event(Event::CertNftIssued(issued_id, nft_id, acc_handler));
 
 			Ok(().into())
 		}
 
 		/// Revoke sertifikat berdasarkan issue id-nya.
-		#[pallet::weight(0)]
+		///
+		/// Gagal dengan `NotChanged` apabila status revoke sama dengan sebelumnya.
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::revoke())]
 		pub fn revoke(
 			origin: OriginFor<T>,
 			org_id: T::AccountId,
@@ -512,10 +647,15 @@ pub mod pallet {
 				.ok_or(Error::<T>::Unknown)?;
 			Self::ensure_org_access2(&who, &org)?;
 
+			let now = now_millis::<<T as pallet::Config>::Time>();
 			IssuedCert::<T>::try_mutate(&issued_id, |d| {
 				match d {
 					Some(d) => {
+						ensure!(d.revoked != revoked, Error::<T>::NotChanged);
+
 						d.revoked = revoked;
+						d.revoked_at = revoked.then(<frame_system::Pallet<T>>::block_number);
+						d.last_known_valid = d.status_at(now) == CertStatus::Valid;
 
 						// // also update expiration time
 						// // to current time, this force issued cert to
@@ -528,16 +668,72 @@ pub mod pallet {
 				}
 			})?;
 
+			Self::deposit_event(Event::CertRevoked(issued_id, revoked));
+
+			Ok(().into())
+		}
+
+		/// Update expiry and properties of an issued certificate,
+		/// e.g. to renew a license.
+		///
+		/// The dispatch origin for this call must be _signed_
+		/// and has access to organization as admin.
+		///
+		/// Both `expired` and `props` replace the current values,
+		/// revoked certificate cannot be updated.
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::update_issued())]
+		pub fn update_issued(
+			origin: OriginFor<T>,
+			org_id: T::AccountId,
+			issued_id: IssuedId,
+			expired: Option<u64>,
+			props: Option<Vec<Property<Text, Text>>>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let org = <pallet_organization::Pallet<T>>::organization(&org_id)
+				.ok_or(Error::<T>::OrganizationNotExists)?;
+			Self::ensure_org_access2(&who, &org)?;
+
+			Self::validate_props(&props)?;
+
+			let now = now_millis::<<T as pallet::Config>::Time>();
+			IssuedCert::<T>::try_mutate(&issued_id, |d| {
+				let d = d.as_mut().ok_or(Error::<T>::NotExists)?;
+				let cert = Certificates::<T>::get(d.cert_id).ok_or(Error::<T>::NotExists)?;
+				ensure!(cert.org_id == org_id, Error::<T>::PermissionDenied);
+				ensure!(!d.revoked, Error::<T>::CertificateRevoked);
+
+				d.expired = expired;
+				d.props = props;
+				d.last_known_valid = d.status_at(now) == CertStatus::Valid;
+				Ok::<_, Error<T>>(())
+			})?;
+
+			Self::deposit_event(Event::CertIssuedUpdated(issued_id));
+
 			Ok(().into())
 		}
 
 		/// Check whether certificate is valid.
-		#[pallet::weight(0)]
+		///
+		/// Refreshes the cached validity (`last_known_valid`) of the issued certificate.
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::validate_certificate())]
 		pub fn validate_certificate(
 			origin: OriginFor<T>,
-			_issued_id: IssuedId,
+			issued_id: IssuedId,
 		) -> DispatchResultWithPostInfo {
 			let _who = ensure_signed(origin)?;
+
+			let now = now_millis::<<T as pallet::Config>::Time>();
+			IssuedCert::<T>::try_mutate(&issued_id, |d| match d {
+				Some(d) => {
+					d.last_known_valid = d.status_at(now) == CertStatus::Valid;
+					Ok(())
+				},
+				None => Err(Error::<T>::NotExists),
+			})?;
+
 			Ok(().into())
 		}
 	}
@@ -570,6 +766,182 @@ pub mod pallet {
 // use pallet_organization::Organization;
 
 /// The main implementation of this Certificate pallet.
+/// Organizations that still have certificates cannot be deleted.
+impl<T: Config> pallet_organization::OrganizationDeleteGuard<T::AccountId> for Pallet<T> {
+	fn ensure_can_delete(org_id: &T::AccountId) -> frame_support::dispatch::DispatchResult {
+		ensure!(
+			Self::certificate_of_org(org_id).map(|ids| ids.is_empty()).unwrap_or(true),
+			Error::<T>::OrganizationHasCertificates
+		);
+		Ok(())
+	}
+}
+
+impl<T: Config> Pallet<T>
+where
+	T::AccountId: AsRef<[u8]>,
+{
+	/// Issue certificate on behalf of `sender`, returns the issued id.
+	#[allow(clippy::too_many_arguments)]
+	pub fn do_issue(
+		sender: &T::AccountId,
+		org_id: T::AccountId,
+		cert_id: CertId,
+		human_id: Text,
+		recipient: Text,
+		props: Option<Vec<Property<Text, Text>>>,
+		acc_handler: Option<T::AccountId>,
+		expired: Option<u64>,
+	) -> Result<IssuedId, DispatchError> {
+		let (cert, org) = Self::ensure_can_issue(sender, &org_id, cert_id, &props)?;
+		Self::issue_checked(
+			&cert,
+			&org,
+			org_id,
+			cert_id,
+			human_id,
+			recipient,
+			props,
+			acc_handler,
+			expired,
+		)
+	}
+
+	/// Check that `sender` can issue certificate `cert_id` of organization `org_id`
+	/// with `props`, returns the certificate and the organization.
+	fn ensure_can_issue(
+		sender: &T::AccountId,
+		org_id: &T::AccountId,
+		cert_id: CertId,
+		props: &Option<Vec<Property<Text, Text>>>,
+	) -> Result<(CertDetail<T::AccountId>, Organization<T>), DispatchError> {
+		// let cert_id:BoundedVec<u8, MaxLength<T>> = cert_id.try_into().map_err(|_|
+		// Error::<T>::TooLong)?;
+
+		let cert = Certificates::<T>::get(cert_id).ok_or(Error::<T>::NotExists)?;
+
+		if let Some(ref props) = props {
+			ensure!((props.len() as u32) < T::MaxProps::get(), Error::<T>::TooManyProps);
+		}
+
+		Self::validate_props(props)?;
+
+		// ensure access
+		let org = <pallet_organization::Pallet<T>>::organization(org_id)
+			.ok_or(Error::<T>::OrganizationNotExists)?;
+		Self::ensure_org_access2(sender, &org)?;
+
+		Ok((cert, org))
+	}
+
+	/// Issue certificate to one recipient, access must be checked with `ensure_can_issue`.
+	#[allow(clippy::too_many_arguments)]
+	fn issue_checked(
+		cert: &CertDetail<T::AccountId>,
+		org: &Organization<T>,
+		org_id: T::AccountId,
+		cert_id: CertId,
+		human_id: Text,
+		recipient: Text,
+		props: Option<Vec<Property<Text, Text>>>,
+		acc_handler: Option<T::AccountId>,
+		expired: Option<u64>,
+	) -> Result<IssuedId, DispatchError> {
+		ensure!(human_id.len() < 100, Error::<T>::TooLong);
+		ensure!(recipient.len() < 100, Error::<T>::TooLong);
+
+		// generate issue id
+		// this id is unique per user per cert.
+		let data = org_id
+			.as_ref()
+			.iter()
+			.chain(cert_id.encode().iter())
+			.chain(human_id.iter())
+			.chain(recipient.iter())
+			.cloned()
+			.collect::<Vec<u8>>();
+
+		let data = if let Some(ref props) = props {
+			data.iter().chain(props.encode().iter()).cloned().collect::<Vec<u8>>()
+		} else {
+			data.iter().cloned().collect::<Vec<u8>>()
+		};
+		let issued_id: IssuedId = Self::generate_issued_id(org, data);
+
+		// pastikan belum pernah di-issue
+		ensure!(!IssuedCert::<T>::contains_key(&issued_id), Error::<T>::AlreadyExists);
+
+		let block = <frame_system::Pallet<T>>::block_number();
+		let signer_name = cert.signer_name.clone();
+
+		// let human_id: BoundedVec<u8, MaxLength<T>> =
+		// 	human_id.try_into().map_err(|_| Error::<T>::TooLong)?;
+		// let recipient: BoundedVec<u8, MaxLength<T>> =
+		// 	recipient.try_into().map_err(|_| Error::<T>::TooLong)?;
+		// let signer_name: Option<BoundedVec<u8, MaxLength<T>>> =
+		// 	if let Some(signer_name) = signer_name {
+		// 		Some(signer_name.try_into().map_err(|_| Error::<T>::TooLong)?)
+		// 	} else {
+		// 		return Err(Error::<T>::TooLong.into())
+		// 	};
+		// let props: Option<BoundedVec<Property<T>, MaxLength<T>>> = if let Some(props) = props
+		// { 	props
+		// 		.into_iter()
+		// 		.flat_map(|p| {
+		// 			let v: Option<Property<T>> = p.try_into().ok();
+		// 			v
+		// 		})
+		// 		.collect::<Vec<_>>()
+		// 		.try_into()
+		// 		.ok()
+		// } else {
+		// 	return Err(Error::<T>::TooLong.into())
+		// };
+
+		let proof = CertProof {
+			cert_id,
+			human_id,
+			recipient,
+			time: now_millis::<<T as pallet::Config>::Time>(),
+			expired,
+			revoked: false,
+			block,
+			signer_name,
+			props,
+			last_known_valid: true,
+			revoked_at: None,
+		};
+
+		if let Some(ref acc_handler) = acc_handler {
+			// apabila sudah pernah diisi update isinya
+			// dengan ditambahkan sertifikat pada koleksi penerima.
+			IssuedCertOwner::<T>::try_mutate::<&T::AccountId, &T::AccountId, _, Error<T>, _>(
+				&org_id,
+				acc_handler,
+				|vs| {
+					if let Some(vs) = vs.as_mut() {
+						vs.try_push(issued_id.clone()).map_err(|_| Error::<T>::TooManyCerts)?;
+					} else {
+						*vs = Some(
+							vec![issued_id.clone()]
+								.try_into()
+								.map_err(|_| Error::<T>::TooManyCerts)?,
+						);
+					}
+					Ok(())
+				},
+			)?;
+			IssuedCertsByHolder::<T>::insert(acc_handler, &issued_id, &org_id);
+		}
+
+		IssuedCert::<T>::insert(&issued_id, proof);
+
+		Self::deposit_event(Event::CertIssued(issued_id.clone(), org_id, acc_handler));
+
+		Ok(issued_id)
+	}
+}
+
 impl<T: Config> Pallet<T> {
 	/// Get detail of certificate
 	pub fn get(id: &CertId) -> Option<CertDetail<T::AccountId>> {
@@ -634,26 +1006,39 @@ impl<T: Config> Pallet<T> {
 
 	/// Check whether issued certificate is valid.
 	pub fn valid_certificate(id: &IssuedId) -> bool {
-		Self::issued_cert(id)
-			.map(|proof| {
-				let now = <T as pallet::Config>::Time::now().as_millis().saturated_into::<u64>();
-				proof.expired.map(|a| a < now).unwrap_or(true) && !proof.revoked
-			})
-			.unwrap_or(false)
+		let now = now_millis::<<T as pallet::Config>::Time>();
+		Self::status_at(id, now) == Some(CertStatus::Valid)
+	}
+
+	/// Issued certificates of `holder` across organizations as (organization id, issued id).
+	pub fn certs_of_holder(holder: &T::AccountId) -> Vec<(T::AccountId, IssuedId)> {
+		IssuedCertsByHolder::<T>::iter_prefix(holder)
+			.map(|(issued_id, org_id)| (org_id, issued_id))
+			.collect()
+	}
+
+	/// Status of issued certificate at `at_millis` time in milliseconds,
+	/// for historical queries, `None` if not exists.
+	///
+	/// A revoked certificate is revoked at any time, see [`CertProof::status_at`].
+	pub fn status_at(id: &IssuedId, at_millis: u64) -> Option<CertStatus> {
+		Self::issued_cert(id).map(|proof| proof.status_at(at_millis))
 	}
 
 	/// Validasi properties
 	pub fn validate_props(props: &Option<Vec<Property<Text, Text>>>) -> Result<(), Error<T>> {
-		if let Some(props) = props {
-			ensure!(props.len() <= MAX_PROPS, Error::<T>::TooManyProps);
-			for prop in props {
-				let len = prop.name().len();
-				ensure!(len > 0 && len <= PROP_NAME_MAX_LENGTH, Error::<T>::InvalidPropName);
-				let len = prop.value().len();
-				ensure!(len > 0 && len <= PROP_VALUE_MAX_LENGTH, Error::<T>::InvalidPropValue);
+		let props = match props {
+			Some(props) => props,
+			None => return Ok(()),
+		};
+		check_props(props, MAX_PROPS, PROP_NAME_MAX_LENGTH, PROP_VALUE_MAX_LENGTH).map_err(|e| {
+			match e {
+				PropError::TooMany => Error::<T>::TooManyProps,
+				PropError::InvalidName => Error::<T>::InvalidPropName,
+				PropError::InvalidValue => Error::<T>::InvalidPropValue,
+				PropError::InvalidValueType => Error::<T>::InvalidPropValueType,
 			}
-		}
-		Ok(())
+		})
 	}
 }
 
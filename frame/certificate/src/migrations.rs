@@ -0,0 +1,234 @@
+// This file is part of Nuchain.
+//
+// Copyright (C) 2021-2022 Rantai Nusantara Foundation..
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Storage migrations for the certificate pallet.
+
+use super::*;
+use frame_support::{
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
+
+pub mod v7 {
+	use super::*;
+
+	/// Bound the issued certificates of a holder by `MaxCertsPerHolder`.
+	///
+	/// Certificates beyond the bound stay issued, only the newest ones are dropped
+	/// from the holder entry.
+	pub struct MigrateToV7<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV7<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain = Pallet::<T>::on_chain_storage_version();
+			if onchain >= 7 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let max = T::MaxCertsPerHolder::get() as usize;
+			let mut translated: Weight = 0;
+			IssuedCertOwner::<T>::translate::<Vec<IssuedId>, _>(|_, _, mut ids| {
+				translated = translated.saturating_add(1);
+				ids.truncate(max);
+				ids.try_into().ok()
+			});
+
+			StorageVersion::new(7).put::<Pallet<T>>();
+			T::DbWeight::get()
+				.reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+
+			Self::set_temp_storage(
+				IssuedCertOwner::<T>::iter_keys().count() as u32,
+				"holder_count",
+			);
+			Ok(())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+
+			let holder_count: u32 = Self::get_temp_storage("holder_count")
+				.ok_or("holder count not stored in pre_upgrade")?;
+			ensure!(
+				IssuedCertOwner::<T>::iter().count() as u32 == holder_count,
+				"holder count changed by migration"
+			);
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 7,
+				"storage version not updated by migration"
+			);
+			Ok(())
+		}
+	}
+}
+
+pub mod v8 {
+	use super::*;
+
+	#[derive(Decode)]
+	struct OldProperty {
+		name: Text,
+		value: Text,
+	}
+
+	#[derive(Decode)]
+	struct OldCertProof<BlockNumber> {
+		cert_id: CertId,
+		human_id: Text,
+		recipient: Text,
+		time: u64,
+		expired: Option<u64>,
+		revoked: bool,
+		block: BlockNumber,
+		signer_name: Option<Text>,
+		props: Option<Vec<OldProperty>>,
+	}
+
+	/// Translate issued certificates from the v4 layout in one pass:
+	///
+	/// - add the value type to properties, existing properties get no value type,
+	/// - add the cached validity, set from the revoked flag only, expired certificates are
+	///   refreshed lazily,
+	/// - add the revocation block, unknown for already revoked certificates and left empty.
+	///
+	/// `IssuedCert` keeps the v4 layout until this migration, [`super::v7::MigrateToV7`]
+	/// only touches `IssuedCertOwner` and may run before it.
+	pub struct MigrateToV8<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV8<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain = Pallet::<T>::on_chain_storage_version();
+			if onchain >= 8 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut translated: Weight = 0;
+			IssuedCert::<T>::translate::<OldCertProof<T::BlockNumber>, _>(|_, proof| {
+				translated = translated.saturating_add(1);
+				Some(CertProof {
+					cert_id: proof.cert_id,
+					human_id: proof.human_id,
+					recipient: proof.recipient,
+					time: proof.time,
+					expired: proof.expired,
+					revoked: proof.revoked,
+					block: proof.block,
+					signer_name: proof.signer_name,
+					props: proof.props.map(|ps| {
+						ps.into_iter()
+							.map(|p| Property::from_parts(p.name, p.value, None))
+							.collect()
+					}),
+					last_known_valid: !proof.revoked,
+					revoked_at: None,
+				})
+			});
+
+			StorageVersion::new(8).put::<Pallet<T>>();
+			T::DbWeight::get()
+				.reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+
+			Self::set_temp_storage(IssuedCert::<T>::iter_keys().count() as u32, "issued_count");
+			Ok(())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+
+			let issued_count: u32 = Self::get_temp_storage("issued_count")
+				.ok_or("issued count not stored in pre_upgrade")?;
+			ensure!(
+				IssuedCert::<T>::iter().count() as u32 == issued_count,
+				"issued certificate count changed by migration"
+			);
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 8,
+				"storage version not updated by migration"
+			);
+			Ok(())
+		}
+	}
+}
+
+pub mod v9 {
+	use super::*;
+
+	/// Build the holder index `IssuedCertsByHolder` from `IssuedCertOwner`.
+	pub struct MigrateToV9<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV9<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain = Pallet::<T>::on_chain_storage_version();
+			if onchain >= 9 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut reads: Weight = 1;
+			let mut indexed: Weight = 0;
+			for (org_id, holder, ids) in IssuedCertOwner::<T>::iter() {
+				reads = reads.saturating_add(1);
+				for id in ids {
+					indexed = indexed.saturating_add(1);
+					IssuedCertsByHolder::<T>::insert(&holder, id, &org_id);
+				}
+			}
+
+			StorageVersion::new(9).put::<Pallet<T>>();
+			T::DbWeight::get().reads_writes(reads, indexed.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+
+			let issued_count: u32 =
+				IssuedCertOwner::<T>::iter_values().map(|ids| ids.len() as u32).sum();
+			Self::set_temp_storage(issued_count, "holder_issued_count");
+			Ok(())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+
+			let issued_count: u32 = Self::get_temp_storage("holder_issued_count")
+				.ok_or("holder issued count not stored in pre_upgrade")?;
+			ensure!(
+				IssuedCertsByHolder::<T>::iter_keys().count() as u32 == issued_count,
+				"holder index does not match issued certificates"
+			);
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 9,
+				"storage version not updated by migration"
+			);
+			Ok(())
+		}
+	}
+}
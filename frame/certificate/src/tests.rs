@@ -2,11 +2,11 @@ use super::*;
 use crate as pallet_certificate;
 
 use frame_support::{
-	assert_err_ignore_postinfo, assert_ok, ord_parameter_types,
+	assert_err_ignore_postinfo, assert_noop, assert_ok, ord_parameter_types,
 	pallet_prelude::ConstU32,
 	parameter_types,
-	traits::{Everything, Time},
-	types::Text,
+	traits::{ConstU64, Everything, Time},
+	types::{PropValueType, Text},
 };
 use frame_system::EnsureSignedBy;
 use sp_core::{sr25519, H256};
@@ -15,6 +15,7 @@ use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentityLookup},
 };
+use std::cell::RefCell;
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -96,6 +97,16 @@ impl pallet_did::Config for Test {
 	type Time = Timestamp;
 	type WeightInfo = pallet_did::weights::SubstrateWeight<Self>;
 	type MaxLength = ConstU32<64>;
+	type MaxDelegateTypeLength = ConstU32<64>;
+	type MaxAttributeNameLength = ConstU32<64>;
+	type MaxServiceIdLength = ConstU32<32>;
+	type MaxServiceTypeLength = ConstU32<32>;
+	type MaxServiceEndpointLength = ConstU32<64>;
+	type MaxServicePerDid = ConstU32<2>;
+	type MaxAttributes = ConstU32<4>;
+	type Currency = Balances;
+	type AttributeDeposit = ConstU64<0>;
+	type DelegateDeposit = ConstU64<0>;
 }
 
 parameter_types! {
@@ -115,6 +126,9 @@ impl pallet_organization::Config for Test {
 	type CreationFee = CreationFee;
 	type Currency = Balances;
 	type Payment = ();
+	type CreationFeeHandler = pallet_organization::NativeCreationFee<Self>;
+	type CreationDeposit = ConstU64<0>;
+	type DeleteGuard = Certificate;
 	type ForceOrigin = EnsureSignedBy<Root, sr25519::Public>;
 	type MinOrgNameLength = MinOrgNameLength;
 	type MaxOrgNameLength = MaxOrgNameLength;
@@ -124,6 +138,30 @@ impl pallet_organization::Config for Test {
 	type Signature = sr25519::Signature;
 	type Did = Did;
 	type MaxLength = ConstU32<64>;
+	type AdminChangeCooldown = ConstU64<0>;
+}
+
+thread_local! {
+	static NFTS: RefCell<Vec<(AccountId, IssuedId)>> = RefCell::new(vec![]);
+}
+
+/// Soulbound NFTs minted in order, NFT id is the position.
+pub struct MockNft;
+
+impl CertificateNft<AccountId> for MockNft {
+	type NftId = u32;
+
+	fn mint_soulbound(recipient: &AccountId, issued_id: &IssuedId) -> Result<u32, DispatchError> {
+		NFTS.with(|nfts| {
+			let mut nfts = nfts.borrow_mut();
+			nfts.push((*recipient, *issued_id));
+			Ok(nfts.len() as u32 - 1)
+		})
+	}
+}
+
+fn nft_of(nft_id: u32) -> Option<(AccountId, IssuedId)> {
+	NFTS.with(|nfts| nfts.borrow().get(nft_id as usize).cloned())
 }
 
 impl Config for Test {
@@ -133,6 +171,9 @@ impl Config for Test {
 	type WeightInfo = ();
 	type MaxProps = ConstU32<5>;
 	type MaxLength = ConstU32<64>;
+	type MaxCertsPerHolder = ConstU32<3>;
+	type MaxIssueBatch = ConstU32<3>;
+	type Nft = MockNft;
 }
 
 impl Time for Test {
@@ -331,7 +372,8 @@ fn create_cert_works() {
 
 				assert_eq!(cert.name, b"CERT1".to_vec());
 				assert_eq!(cert.description, b"CERT1 desc".to_vec());
-				assert_eq!(cert.signer_name, None);
+				// signer name inherited from organization name
+				assert_eq!(cert.signer_name, Some(b"ORG1".to_vec()));
 			},
 			_ => assert!(false, "no event"),
 		}
@@ -405,7 +447,181 @@ fn issue_cert_with_account_handler_works() {
 		));
 		let issued_id = get_last_issued_cert_id().unwrap();
 		let account: <Test as frame_system::Config>::AccountId = Charlie.into();
-		assert_eq!(IssuedCertOwner::<Test>::get(&org_id, &account), Some(vec![issued_id]));
+		assert_eq!(
+			IssuedCertOwner::<Test>::get(&org_id, &account).map(|ids| ids.into_inner()),
+			Some(vec![issued_id])
+		);
+	});
+}
+
+#[test]
+fn certs_of_holder_lists_certs_across_organizations() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let holder: AccountId = Alice.into();
+		assert_eq!(Certificate::certs_of_holder(&holder), vec![]);
+
+		create_org!(b"ORG1", Bob.into());
+		let org1_id = last_org_id();
+		let cert1_id = create_cert(Bob, org1_id, "cert1");
+		create_org!(b"ORG2", Charlie.into());
+		let org2_id = last_org_id();
+		let cert2_id = create_cert(Charlie, org2_id, "cert2");
+
+		assert_ok!(Certificate::issue(
+			Origin::signed(Bob.into()),
+			org1_id,
+			cert1_id,
+			(*ORG_CERT_REF).clone(),
+			b"Alice".to_vec(),
+			None,
+			Some(holder),
+			None
+		));
+		let issued1_id = get_last_issued_cert_id().unwrap();
+		assert_ok!(Certificate::issue(
+			Origin::signed(Charlie.into()),
+			org2_id,
+			cert2_id,
+			(*ORG_CERT_REF).clone(),
+			b"Alice".to_vec(),
+			None,
+			Some(holder),
+			None
+		));
+		let issued2_id = get_last_issued_cert_id().unwrap();
+
+		let mut certs = Certificate::certs_of_holder(&holder);
+		certs.sort();
+		let mut expected = vec![(org1_id, issued1_id), (org2_id, issued2_id)];
+		expected.sort();
+		assert_eq!(certs, expected);
+	});
+}
+
+#[test]
+fn issue_cert_to_saturated_holder_fails() {
+	with_org(|org_id| {
+		assert_ok!(Certificate::create(Origin::signed(Bob.into()), CertDetail::new(org_id)));
+		let cert_id = get_last_created_cert_id().unwrap();
+		let account: <Test as frame_system::Config>::AccountId = Charlie.into();
+
+		// MaxCertsPerHolder = 3
+		for recipient in [b"Dave".to_vec(), b"Eve".to_vec(), b"Ferdie".to_vec()] {
+			assert_ok!(Certificate::issue(
+				Origin::signed(Bob.into()),
+				org_id,
+				cert_id,
+				(*ORG_CERT_REF).clone(),
+				recipient,
+				None,
+				Some(Charlie.into()),
+				None
+			));
+		}
+		assert_eq!(IssuedCertOwner::<Test>::get(&org_id, &account).map(|ids| ids.len()), Some(3));
+
+		assert_err_ignore_postinfo!(
+			Certificate::issue(
+				Origin::signed(Bob.into()),
+				org_id,
+				cert_id,
+				(*ORG_CERT_REF).clone(),
+				b"Grohl".to_vec(),
+				None,
+				Some(Charlie.into()),
+				None
+			),
+			Error::<Test>::TooManyCerts
+		);
+		assert_eq!(IssuedCertOwner::<Test>::get(&org_id, &account).map(|ids| ids.len()), Some(3));
+	});
+}
+
+#[test]
+fn issue_batch_works() {
+	with_org(|org_id| {
+		assert_ok!(Certificate::create(Origin::signed(Bob.into()), CertDetail::new(org_id)));
+		let cert_id = get_last_created_cert_id().unwrap();
+
+		assert_ok!(Certificate::issue_batch(
+			Origin::signed(Bob.into()),
+			org_id,
+			cert_id,
+			vec![
+				(b"ORG/1".to_vec(), b"Dave".to_vec(), Some(Charlie.into())),
+				(b"ORG/2".to_vec(), b"Eve".to_vec(), Some(Dave.into())),
+				(b"ORG/3".to_vec(), b"Ferdie".to_vec(), None),
+			],
+			None,
+			None
+		));
+
+		let issued = System::events()
+			.into_iter()
+			.filter_map(|r| match r.event {
+				Event::Certificate(CertEvent::CertIssued(issued_id, _, acc_handler)) =>
+					Some((issued_id, acc_handler)),
+				_ => None,
+			})
+			.collect::<Vec<_>>();
+		assert_eq!(issued.len(), 3);
+		for (issued_id, acc_handler) in issued {
+			assert!(Certificate::valid_certificate(&issued_id));
+			if let Some(acc_handler) = acc_handler {
+				assert_eq!(
+					Certificate::issued_cert_owner(org_id, acc_handler).map(|ids| ids.into_inner()),
+					Some(vec![issued_id])
+				);
+			}
+		}
+	});
+}
+
+#[test]
+fn issue_batch_is_capped_and_all_or_nothing() {
+	with_org(|org_id| {
+		assert_ok!(Certificate::create(Origin::signed(Bob.into()), CertDetail::new(org_id)));
+		let cert_id = get_last_created_cert_id().unwrap();
+		let recipient = |name: &[u8]| (b"ORG/1".to_vec(), name.to_vec(), None);
+
+		// MaxIssueBatch = 3
+		assert_err_ignore_postinfo!(
+			Certificate::issue_batch(
+				Origin::signed(Bob.into()),
+				org_id,
+				cert_id,
+				vec![recipient(b"A"), recipient(b"B"), recipient(b"C"), recipient(b"D")],
+				None,
+				None
+			),
+			Error::<Test>::TooManyRecipients
+		);
+
+		assert_err_ignore_postinfo!(
+			Certificate::issue_batch(
+				Origin::signed(Charlie.into()),
+				org_id,
+				cert_id,
+				vec![recipient(b"A")],
+				None,
+				None
+			),
+			Error::<Test>::PermissionDenied
+		);
+
+		// penerima duplikat, tidak ada sertifikat yang diterbitkan
+		assert_noop!(
+			Certificate::issue_batch(
+				Origin::signed(Bob.into()),
+				org_id,
+				cert_id,
+				vec![recipient(b"A"), recipient(b"A")],
+				None,
+				None
+			),
+			Error::<Test>::AlreadyExists
+		);
 	});
 }
 
@@ -414,6 +630,25 @@ fn issue_cert_works() {
 	with_org_cert_issued(|_, _, _| {});
 }
 
+#[test]
+fn create_cert_without_signer_name_inherits_org_name() {
+	with_org(|org_id| {
+		assert_ok!(Certificate::create(Origin::signed(Bob.into()), CertDetail::new(org_id)));
+		let cert_id = get_last_created_cert_id().expect("cert_id of new created cert");
+		let cert = Certificate::get(&cert_id).unwrap();
+		assert_eq!(cert.signer_name, Some(b"ORG1".to_vec()));
+
+		// explicit signer name is kept as is
+		assert_ok!(Certificate::create(
+			Origin::signed(Bob.into()),
+			CertDetail::new(org_id).set_name(b"CERT2".to_vec()).signer(b"Grohl".to_vec())
+		));
+		let cert_id = get_last_created_cert_id().expect("cert_id of new created cert");
+		let cert = Certificate::get(&cert_id).unwrap();
+		assert_eq!(cert.signer_name, Some(b"Grohl".to_vec()));
+	});
+}
+
 #[test]
 fn cannot_create_cert_without_org() {
 	new_test_ext().execute_with(|| {
@@ -468,6 +703,246 @@ fn revoke_issued_cert_should_work() {
 	});
 }
 
+#[test]
+fn issued_cert_expires_after_expired_days() {
+	with_org(|org_id| {
+		assert_ok!(Certificate::create(Origin::signed(Bob.into()), CertDetail::new(org_id)));
+		let cert_id = get_last_created_cert_id().expect("cert_id of new created cert");
+
+		let issued_at = 1_600_000_000_000;
+		Timestamp::set_timestamp(issued_at);
+
+		assert_ok!(Certificate::issue(
+			Origin::signed(Bob.into()),
+			org_id,
+			cert_id,
+			(*ORG_CERT_REF).clone(),
+			b"Dave Grohl".to_vec(),
+			None,
+			None,
+			Some(1)
+		));
+		let issued_id = get_last_issued_cert_id().expect("get last issued id");
+		let proof = Certificate::issued_cert(&issued_id).expect("issued cert");
+		assert_eq!(proof.time, issued_at);
+		assert_eq!(proof.expires_at(), Some(issued_at + 86_400_000));
+
+		Timestamp::set_timestamp(issued_at + 86_400_000 - 1);
+		assert_eq!(Certificate::valid_certificate(&issued_id), true);
+
+		Timestamp::set_timestamp(issued_at + 86_400_000);
+		assert_eq!(Certificate::valid_certificate(&issued_id), false);
+	});
+}
+
+#[test]
+fn issued_cert_without_expiry_never_expires() {
+	with_org_cert_issued(|org_id, _cert_id, issued_id| {
+		assert_eq!(Certificate::issued_cert(&issued_id).unwrap().expires_at(), None);
+
+		Timestamp::set_timestamp(u64::MAX);
+		assert_eq!(Certificate::valid_certificate(&issued_id), true);
+		assert_eq!(Certificate::status_at(&issued_id, u64::MAX), Some(CertStatus::Valid));
+
+		// kecuali dicabut
+		assert_ok!(Certificate::revoke(
+			Origin::signed(Bob.into()),
+			org_id,
+			issued_id.clone(),
+			true
+		));
+		assert_eq!(Certificate::valid_certificate(&issued_id), false);
+	});
+}
+
+#[test]
+fn status_at_before_and_after_expiry() {
+	with_org(|org_id| {
+		assert_ok!(Certificate::create(Origin::signed(Bob.into()), CertDetail::new(org_id)));
+		let cert_id = get_last_created_cert_id().expect("cert_id of new created cert");
+
+		let issued_at = 1_600_000_000_000;
+		Timestamp::set_timestamp(issued_at);
+
+		assert_ok!(Certificate::issue(
+			Origin::signed(Bob.into()),
+			org_id,
+			cert_id,
+			(*ORG_CERT_REF).clone(),
+			b"Dave Grohl".to_vec(),
+			None,
+			None,
+			Some(1)
+		));
+		let issued_id = get_last_issued_cert_id().expect("get last issued id");
+		let expires_at = issued_at + 86_400_000;
+
+		// historical query tidak tergantung waktu saat ini
+		Timestamp::set_timestamp(expires_at + 86_400_000);
+		assert_eq!(Certificate::status_at(&issued_id, issued_at), Some(CertStatus::Valid));
+		assert_eq!(Certificate::status_at(&issued_id, expires_at - 1), Some(CertStatus::Valid));
+		assert_eq!(Certificate::status_at(&issued_id, expires_at), Some(CertStatus::Expired));
+		assert_eq!(Certificate::status_at(&[0u8; 11], issued_at), None);
+
+		// cache baru diperbarui saat validate_certificate dipanggil
+		assert_eq!(Certificate::issued_cert(&issued_id).unwrap().last_known_valid, true);
+		assert_ok!(Certificate::validate_certificate(
+			Origin::signed(Charlie.into()),
+			issued_id.clone()
+		));
+		assert_eq!(Certificate::issued_cert(&issued_id).unwrap().last_known_valid, false);
+	});
+}
+
+#[test]
+fn revoke_updates_last_known_valid() {
+	with_org_cert_issued(|org_id, _cert_id, issued_id| {
+		assert_ok!(Certificate::revoke(
+			Origin::signed(Bob.into()),
+			org_id,
+			issued_id.clone(),
+			true
+		));
+		assert_eq!(Certificate::issued_cert(&issued_id).unwrap().last_known_valid, false);
+		assert_eq!(Certificate::status_at(&issued_id, 0), Some(CertStatus::Revoked));
+
+		assert_ok!(Certificate::revoke(
+			Origin::signed(Bob.into()),
+			org_id,
+			issued_id.clone(),
+			false
+		));
+		assert_eq!(Certificate::issued_cert(&issued_id).unwrap().last_known_valid, true);
+	});
+}
+
+#[test]
+fn revoke_emits_event_and_records_block() {
+	with_org_cert_issued(|org_id, _cert_id, issued_id| {
+		System::set_block_number(5);
+		assert_ok!(Certificate::revoke(
+			Origin::signed(Bob.into()),
+			org_id,
+			issued_id.clone(),
+			true
+		));
+		assert_eq!(last_event(), CertEvent::CertRevoked(issued_id.clone(), true));
+		assert_eq!(Certificate::issued_cert(&issued_id).unwrap().revoked_at, Some(5));
+
+		assert_ok!(Certificate::revoke(
+			Origin::signed(Bob.into()),
+			org_id,
+			issued_id.clone(),
+			false
+		));
+		assert_eq!(last_event(), CertEvent::CertRevoked(issued_id.clone(), false));
+		assert_eq!(Certificate::issued_cert(&issued_id).unwrap().revoked_at, None);
+	});
+}
+
+#[test]
+fn revoke_with_unchanged_value_fails() {
+	with_org_cert_issued(|org_id, _cert_id, issued_id| {
+		assert_err_ignore_postinfo!(
+			Certificate::revoke(Origin::signed(Bob.into()), org_id, issued_id.clone(), false),
+			Error::<Test>::NotChanged
+		);
+
+		assert_ok!(Certificate::revoke(
+			Origin::signed(Bob.into()),
+			org_id,
+			issued_id.clone(),
+			true
+		));
+		assert_err_ignore_postinfo!(
+			Certificate::revoke(Origin::signed(Bob.into()), org_id, issued_id.clone(), true),
+			Error::<Test>::NotChanged
+		);
+	});
+}
+
+#[test]
+fn update_issued_renews_expiry_and_props() {
+	with_org_cert_issued(|org_id, _cert_id, issued_id| {
+		let issued_at = Certificate::issued_cert(&issued_id).unwrap().time;
+		Timestamp::set_timestamp(issued_at + 86_400_000);
+
+		// perpanjang masa berlaku
+		assert_ok!(Certificate::update_issued(
+			Origin::signed(Bob.into()),
+			org_id,
+			issued_id.clone(),
+			Some(1),
+			None
+		));
+		assert_eq!(last_event(), CertEvent::CertIssuedUpdated(issued_id.clone()));
+		let proof = Certificate::issued_cert(&issued_id).unwrap();
+		assert_eq!(proof.expired, Some(1));
+		assert_eq!(proof.props, None);
+		assert_eq!(proof.last_known_valid, false);
+		assert_eq!(Certificate::valid_certificate(&issued_id), false);
+
+		assert_ok!(Certificate::update_issued(
+			Origin::signed(Bob.into()),
+			org_id,
+			issued_id.clone(),
+			Some(2),
+			Some(vec![Property::new(b"dua".to_vec(), b"2".to_vec())])
+		));
+		let proof = Certificate::issued_cert(&issued_id).unwrap();
+		assert_eq!(proof.props, Some(vec![Property::new(b"dua".to_vec(), b"2".to_vec())]));
+		assert_eq!(proof.last_known_valid, true);
+		assert_eq!(Certificate::valid_certificate(&issued_id), true);
+	});
+}
+
+#[test]
+fn update_issued_checks_access_props_and_revocation() {
+	with_org_cert_issued(|org_id, _cert_id, issued_id| {
+		assert_noop!(
+			Certificate::update_issued(
+				Origin::signed(Charlie.into()),
+				org_id,
+				issued_id.clone(),
+				Some(1),
+				None
+			),
+			Error::<Test>::PermissionDenied
+		);
+		assert_noop!(
+			Certificate::update_issued(
+				Origin::signed(Bob.into()),
+				org_id,
+				issued_id.clone(),
+				None,
+				Some(vec![Property::new(b"".to_vec(), b"1".to_vec())])
+			),
+			Error::<Test>::InvalidPropName
+		);
+		assert_noop!(
+			Certificate::update_issued(Origin::signed(Bob.into()), org_id, [0u8; 11], None, None),
+			Error::<Test>::NotExists
+		);
+
+		assert_ok!(Certificate::revoke(
+			Origin::signed(Bob.into()),
+			org_id,
+			issued_id.clone(),
+			true
+		));
+		assert_noop!(
+			Certificate::update_issued(
+				Origin::signed(Bob.into()),
+				org_id,
+				issued_id.clone(),
+				Some(1),
+				None
+			),
+			Error::<Test>::CertificateRevoked
+		);
+	});
+}
+
 #[test]
 fn only_org_admin_can_revoke() {
 	with_org_cert_issued(|org_id, _cert_id, issued_id| {
@@ -516,3 +991,238 @@ fn test_max_props() {
 		);
 	})
 }
+
+#[test]
+fn issue_cert_with_control_char_props_rejected() {
+	with_org(|org_id| {
+		assert_ok!(Certificate::create(
+			Origin::signed(Bob.into()),
+			CertDetail::new(org_id).signer(b"Grohl".to_vec())
+		));
+
+		let cert_id = get_last_created_cert_id().expect("cert_id of new created cert");
+
+		assert_err_ignore_postinfo!(
+			Certificate::issue(
+				Origin::signed(Bob.into()),
+				org_id,
+				cert_id,
+				(*ORG_CERT_REF).clone(),
+				b"Dave Grohl".to_vec(),
+				Some(vec![Property::new(b"sa\ttu".to_vec(), b"1".to_vec())]),
+				None,
+				None
+			),
+			Error::<Test>::InvalidPropName
+		);
+
+		assert_err_ignore_postinfo!(
+			Certificate::issue(
+				Origin::signed(Bob.into()),
+				org_id,
+				cert_id,
+				(*ORG_CERT_REF).clone(),
+				b"Dave Grohl".to_vec(),
+				Some(vec![Property::new(b"satu".to_vec(), b"1\x1b".to_vec())]),
+				None,
+				None
+			),
+			Error::<Test>::InvalidPropValue
+		);
+
+		assert_ok!(Certificate::issue(
+			Origin::signed(Bob.into()),
+			org_id,
+			cert_id,
+			(*ORG_CERT_REF).clone(),
+			b"Dave Grohl".to_vec(),
+			Some(vec![Property::new(b"satu".to_vec(), b"1".to_vec())]),
+			None,
+			None
+		));
+	})
+}
+
+#[test]
+fn issue_cert_with_typed_props() {
+	with_org(|org_id| {
+		assert_ok!(Certificate::create(Origin::signed(Bob.into()), CertDetail::new(org_id)));
+		let cert_id = get_last_created_cert_id().expect("cert_id of new created cert");
+
+		assert_err_ignore_postinfo!(
+			Certificate::issue(
+				Origin::signed(Bob.into()),
+				org_id,
+				cert_id,
+				(*ORG_CERT_REF).clone(),
+				b"Dave Grohl".to_vec(),
+				Some(vec![Property::from_parts(b"nilai".to_vec(), b"90".to_vec(), Some(4))]),
+				None,
+				None
+			),
+			Error::<Test>::InvalidPropValueType
+		);
+
+		let props = vec![
+			Property::new(b"nilai".to_vec(), b"90".to_vec()).with_value_type(PropValueType::Number),
+			Property::new(b"lulus".to_vec(), b"2022-06-30".to_vec())
+				.with_value_type(PropValueType::Date),
+			Property::new(b"link".to_vec(), b"https://nuchain.network".to_vec())
+				.with_value_type(PropValueType::Uri),
+		];
+		assert_ok!(Certificate::issue(
+			Origin::signed(Bob.into()),
+			org_id,
+			cert_id,
+			(*ORG_CERT_REF).clone(),
+			b"Dave Grohl".to_vec(),
+			Some(props.clone()),
+			None,
+			None
+		));
+
+		let issued_id = get_last_issued_cert_id().expect("get last issued id");
+		let issued_props = Certificate::issued_cert(&issued_id).unwrap().props.unwrap();
+		assert_eq!(issued_props, props);
+		assert_eq!(issued_props[1].value_type(), Some(PropValueType::Date));
+	})
+}
+
+#[test]
+fn issue_cert_with_over_length_props_rejected() {
+	with_org(|org_id| {
+		assert_ok!(Certificate::create(Origin::signed(Bob.into()), CertDetail::new(org_id)));
+		let cert_id = get_last_created_cert_id().expect("cert_id of new created cert");
+
+		let issue = |name: usize, value: usize| {
+			Certificate::issue(
+				Origin::signed(Bob.into()),
+				org_id,
+				cert_id,
+				(*ORG_CERT_REF).clone(),
+				b"Dave Grohl".to_vec(),
+				Some(vec![Property::new(vec![b'a'; name], vec![b'b'; value])]),
+				None,
+				None,
+			)
+		};
+
+		assert_err_ignore_postinfo!(
+			issue(PROP_NAME_MAX_LENGTH + 1, 1),
+			Error::<Test>::InvalidPropName
+		);
+		assert_err_ignore_postinfo!(
+			issue(1, PROP_VALUE_MAX_LENGTH + 1),
+			Error::<Test>::InvalidPropValue
+		);
+		assert_ok!(issue(PROP_NAME_MAX_LENGTH, PROP_VALUE_MAX_LENGTH));
+	})
+}
+
+#[test]
+fn organization_with_certificates_cannot_be_deleted() {
+	with_org(|org_id| {
+		assert_ok!(Certificate::create(Origin::signed(Bob.into()), CertDetail::new(org_id)));
+		assert_err_ignore_postinfo!(
+			Organization::delete(Origin::signed(Bob.into()), org_id, 0),
+			Error::<Test>::OrganizationHasCertificates
+		);
+		assert!(Organization::organization(org_id).is_some());
+	});
+}
+
+#[test]
+fn organization_without_certificates_can_be_deleted() {
+	with_org(|org_id| {
+		assert_ok!(Organization::delete(Origin::signed(Bob.into()), org_id, 0));
+		assert!(Organization::organization(org_id).is_none());
+	});
+}
+
+#[test]
+fn issue_as_nft_mints_soulbound_nft() {
+	with_org(|org_id| {
+		assert_ok!(Certificate::create(Origin::signed(Bob.into()), CertDetail::new(org_id)));
+		let cert_id = get_last_created_cert_id().expect("cert_id of new created cert");
+
+		assert_err_ignore_postinfo!(
+			Certificate::issue_as_nft(
+				Origin::signed(Charlie.into()),
+				org_id,
+				cert_id,
+				(*ORG_CERT_REF).clone(),
+				b"Dave Grohl".to_vec(),
+				None,
+				Charlie.into(),
+				None
+			),
+			Error::<Test>::PermissionDenied
+		);
+
+		assert_ok!(Certificate::issue_as_nft(
+			Origin::signed(Bob.into()),
+			org_id,
+			cert_id,
+			(*ORG_CERT_REF).clone(),
+			b"Dave Grohl".to_vec(),
+			None,
+			Charlie.into(),
+			None
+		));
+		let (issued_id, nft_id) = match last_event() {
+			CertEvent::CertNftIssued(issued_id, nft_id, owner) => {
+				assert_eq!(owner, Charlie.into());
+				(issued_id, nft_id)
+			},
+			_ => panic!("CertNftIssued event expected"),
+		};
+
+		assert!(Certificate::valid_certificate(&issued_id));
+		assert_eq!(
+			Certificate::issued_cert_owner(org_id, AccountId::from(Charlie))
+				.map(|ids| ids.into_inner()),
+			Some(vec![issued_id])
+		);
+		assert_eq!(Certificate::issued_cert_nft(&issued_id), Some(nft_id));
+		assert_eq!(nft_of(nft_id), Some((Charlie.into(), issued_id)));
+	});
+}
+
+#[test]
+fn migrate_to_v8_translates_issued_certs_from_v4() {
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	with_org_cert_issued(|_org_id, _cert_id, issued_id| {
+		let proof = Certificate::issued_cert(&issued_id).unwrap();
+		let old = (
+			&proof.cert_id,
+			&proof.human_id,
+			&proof.recipient,
+			proof.time,
+			proof.expired,
+			true,
+			proof.block,
+			&proof.signer_name,
+			Some(vec![(b"satu".to_vec(), b"1".to_vec())]),
+		)
+			.encode();
+		frame_support::storage::unhashed::put_raw(
+			&IssuedCert::<Test>::hashed_key_for(&issued_id),
+			&old,
+		);
+		StorageVersion::new(7).put::<Pallet<Test>>();
+
+		migrations::v8::MigrateToV8::<Test>::on_runtime_upgrade();
+
+		assert_eq!(Certificate::on_chain_storage_version(), 8);
+		let migrated = Certificate::issued_cert(&issued_id).unwrap();
+		assert_eq!(migrated.cert_id, proof.cert_id);
+		assert!(migrated.revoked);
+		assert!(!migrated.last_known_valid);
+		assert_eq!(migrated.revoked_at, None);
+		assert_eq!(
+			migrated.props,
+			Some(vec![Property::from_parts(b"satu".to_vec(), b"1".to_vec(), None)])
+		);
+	});
+}
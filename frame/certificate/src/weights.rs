@@ -34,6 +34,9 @@
 // --heap-pages=4096
 // --output=frame/certificate/src/weights.rs
 // --template=.maintain/frame-weight-template.hbs
+//
+// Weights marked as estimated were written by hand and must be
+// regenerated with the command above before the next release.
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -46,7 +49,11 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn create() -> Weight;
 	fn issue() -> Weight;
+	fn issue_batch(r: u32, ) -> Weight;
+	fn issue_as_nft() -> Weight;
 	fn revoke() -> Weight;
+	fn update_issued() -> Weight;
+	fn validate_certificate() -> Weight;
 }
 
 /// Weights for pallet_certificate using the Substrate node and recommended hardware.
@@ -70,10 +77,55 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(4 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	// Estimated by hand, not benchmarked yet.
+	// Storage: Certificate Certificates (r:1 w:0)
+	// Storage: Organization Organizations (r:1 w:0)
+	// Storage: Certificate IssuedCert (r:1 w:1)
+	// Storage: Certificate IssuedCertOwner (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	fn issue_batch(r: u32, ) -> Weight {
+		(28_000_000 as Weight)
+			.saturating_add((38_500_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(r as Weight)))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(r as Weight)))
+	}
+	// Estimated by hand, not benchmarked yet.
+	// Storage: Certificate Certificates (r:1 w:0)
+	// Storage: Organization Organizations (r:1 w:0)
+	// Storage: Certificate IssuedCert (r:1 w:1)
+	// Storage: Certificate IssuedCertOwner (r:1 w:1)
+	// Storage: Certificate IssuedCertsByHolder (r:0 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Certificate IssuedCertNft (r:0 w:1)
+	fn issue_as_nft() -> Weight {
+		(72_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Estimated by hand, not benchmarked yet.
 	// Storage: Organization Organizations (r:1 w:0)
 	// Storage: Certificate IssuedCert (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
 	fn revoke() -> Weight {
 		(20_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Organization Organizations (r:1 w:0)
+	// Storage: Certificate IssuedCert (r:1 w:1)
+	// Storage: Certificate Certificates (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	fn update_issued() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Estimated by hand, not benchmarked yet.
+	// Storage: Certificate IssuedCert (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	fn validate_certificate() -> Weight {
+		(15_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
@@ -99,10 +151,55 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+	// Estimated by hand, not benchmarked yet.
+	// Storage: Certificate Certificates (r:1 w:0)
+	// Storage: Organization Organizations (r:1 w:0)
+	// Storage: Certificate IssuedCert (r:1 w:1)
+	// Storage: Certificate IssuedCertOwner (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	fn issue_batch(r: u32, ) -> Weight {
+		(28_000_000 as Weight)
+			.saturating_add((38_500_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(r as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(r as Weight)))
+	}
+	// Estimated by hand, not benchmarked yet.
+	// Storage: Certificate Certificates (r:1 w:0)
+	// Storage: Organization Organizations (r:1 w:0)
+	// Storage: Certificate IssuedCert (r:1 w:1)
+	// Storage: Certificate IssuedCertOwner (r:1 w:1)
+	// Storage: Certificate IssuedCertsByHolder (r:0 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Certificate IssuedCertNft (r:0 w:1)
+	fn issue_as_nft() -> Weight {
+		(72_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Estimated by hand, not benchmarked yet.
 	// Storage: Organization Organizations (r:1 w:0)
 	// Storage: Certificate IssuedCert (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
 	fn revoke() -> Weight {
 		(20_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Organization Organizations (r:1 w:0)
+	// Storage: Certificate IssuedCert (r:1 w:1)
+	// Storage: Certificate Certificates (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	fn update_issued() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Estimated by hand, not benchmarked yet.
+	// Storage: Certificate IssuedCert (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	fn validate_certificate() -> Weight {
+		(15_000_000 as Weight)
 			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
@@ -24,7 +24,11 @@
 //! ### Dispatchable Functions
 //!
 //! * [`mint`](./enum.Call.html#variant.mint) - Use the provided commodity info
-//!   to create a new commodity for the specified user. May only be called by
+//!   to create a new commodity for the specified user, optionally recording a
+//!   creator royalty. May only be called by the commodity admin.
+//!
+//! * [`mint_soulbound`](./enum.Call.html#variant.mint_soulbound) - Like `mint`, but
+//!   the commodity can never be transferred, only burned. May only be called by
 //!   the commodity admin.
 //!
 //! * [`burn`](./enum.Call.html#variant.burn) - Destroy a commodity. May only be
@@ -32,7 +36,21 @@
 //!
 //! * [`transfer`](./enum.Call.html#variant.transfer) - Transfer ownership of
 //!   a commodity to another account. May only be called by current commodity
-//!   owner.
+//!   owner or an approved operator.
+//!
+//! * [`approve`](./enum.Call.html#variant.approve) - Approve an operator to
+//!   transfer a single commodity. May only be called by commodity owner.
+//!
+//! * [`set_approval_for_all`](./enum.Call.html#variant.set_approval_for_all) -
+//!   Approve or revoke an operator to transfer all commodities of the caller.
+//!
+//! * [`set_user_limit`](./enum.Call.html#variant.set_user_limit) - Override the
+//!   maximum number of commodities a specific account may own. May only be
+//!   called by the commodity admin.
+//!
+//! * [`set_user_limit_temp`](./enum.Call.html#variant.set_user_limit_temp) -
+//!   Override the maximum number of commodities a specific account may own
+//!   until the given block. May only be called by the commodity admin.
 //! 
 
 // @TODO(robin):
@@ -47,14 +65,17 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use codec::FullCodec;
+use codec::{Compact, Decode, FullCodec};
 use frame_support::{
     dispatch, ensure,
-    traits::{EnsureOrigin, Get},
+    traits::{Currency, EnsureOrigin, Get},
     Hashable,
 };
 use frame_system::ensure_signed;
-use sp_runtime::traits::{Hash, Member};
+use sp_runtime::{
+    traits::{Hash, Member},
+    Permill,
+};
 use sp_std::{fmt::Debug, vec::Vec};
 
 pub mod nft;
@@ -81,12 +102,22 @@ pub mod pallet {
     pub trait Config<I: 'static = ()>: frame_system::Config {
         /// The dispatch origin that is able to mint new instances of this type of commodity.
         type CommodityAdmin: EnsureOrigin<Self::Origin>;
+        /// The dispatch origin that is able to raise the commodity limit at runtime.
+        type ForceOrigin: EnsureOrigin<Self::Origin>;
         /// The data type that is used to describe this type of commodity.
         type CommodityInfo: Hashable + Member + Debug + Default + FullCodec + Ord;
         /// The maximum number of this type of commodity that may exist (minted - burned).
         type CommodityLimit: Get<u128>;
         /// The maximum number of this type of commodity that any single account may own.
         type UserCommodityLimit: Get<u64>;
+        /// The currency in which royalties of this type of commodity are paid.
+        type RoyaltyCurrency: Currency<Self::AccountId>;
+        /// The maximum royalty that may be set on a commodity.
+        type MaxRoyalty: Get<Permill>;
+        /// The maximum number of commodities that may be minted in a single batch.
+        type MaxBatchSize: Get<u32>;
+        /// The maximum length in bytes of a commodity's metadata URI.
+        type MaxUriLength: Get<u32>;
         type Event: From<Event<Self, I>> + IsType<<Self as frame_system::Config>::Event>;
     }
 
@@ -119,16 +150,90 @@ pub mod pallet {
     pub type AccountForCommodity<T: Config<I>, I: 'static = ()> =
         StorageMap<_, Identity, CommodityId<T>, T::AccountId>;
 
+    /// The info that defines a commodity, i.e. the preimage of its ID.
+    #[pallet::storage]
+    #[pallet::getter(fn info_of)]
+    pub type CommodityInfoOf<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Identity, CommodityId<T>, T::CommodityInfo>;
+
+    /// Metadata URI of a commodity, e.g. pointing at IPFS or HTTP metadata.
+    ///
+    /// At most `MaxUriLength` bytes long.
+    #[pallet::storage]
+    #[pallet::getter(fn token_uri)]
+    pub type TokenUri<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Identity, CommodityId<T>, Vec<u8>>;
+
+    /// Royalty beneficiary and rate of a commodity.
+    #[pallet::storage]
+    #[pallet::getter(fn royalty_of)]
+    pub type RoyaltyOf<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Identity, CommodityId<T>, (T::AccountId, Permill)>;
+
+    /// Commodities that can never be transferred, e.g. credentials bound to their owner.
+    #[pallet::storage]
+    #[pallet::getter(fn is_soulbound)]
+    pub type Soulbound<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Identity, CommodityId<T>, bool, ValueQuery>;
+
+    /// Account approved to transfer a commodity on behalf of its owner.
+    #[pallet::storage]
+    #[pallet::getter(fn approved)]
+    pub type Approved<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Identity, CommodityId<T>, T::AccountId>;
+
+    /// Operators approved by an owner to transfer all of the owner's commodities.
+    #[pallet::storage]
+    #[pallet::getter(fn approval_for_all)]
+    pub type ApprovalForAll<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        Twox64Concat,
+        T::AccountId,
+        bool,
+        ValueQuery,
+    >;
+
+    /// Runtime override of `CommodityLimit`, it can only be raised.
+    #[pallet::storage]
+    #[pallet::getter(fn limit_override)]
+    pub type LimitOverride<T: Config<I>, I: 'static = ()> = StorageValue<_, u128>;
+
+    /// Per-account override of `UserCommodityLimit`, optionally valid only until the given block.
+    #[pallet::storage]
+    #[pallet::getter(fn user_limit_override)]
+    pub type UserLimitOverride<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Twox64Concat, T::AccountId, (u64, Option<T::BlockNumber>)>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
-    #[pallet::metadata(T::AccountId = "AccountId")]
+    #[pallet::metadata(T::AccountId = "AccountId", T::BlockNumber = "BlockNumber")]
     pub enum Event<T: Config<I>, I: 'static = ()> {
         /// The commodity has been burned.
         Burned(CommodityId<T>),
+        /// The commodity has been burned by the commodity admin, carrying the former owner.
+        AdminBurned(CommodityId<T>, T::AccountId),
         /// The commodity has been minted and distributed to the account.
         Minted(CommodityId<T>, T::AccountId),
+        /// The soulbound commodity has been minted and bound to the account.
+        SoulboundMinted(CommodityId<T>, T::AccountId),
+        /// The batch of commodities has been minted and distributed to the account.
+        MintedBatch(Vec<CommodityId<T>>, T::AccountId),
         /// Ownership of the commodity has been transferred to the account.
         Transferred(CommodityId<T>, T::AccountId),
+        /// The royalty of the commodity has been set to the beneficiary account.
+        RoyaltySet(CommodityId<T>, T::AccountId, Permill),
+        /// The owner approved the operator to transfer the commodity.
+        Approval(CommodityId<T>, T::AccountId, T::AccountId),
+        /// The owner approved or revoked the operator to transfer all of the owner's commodities.
+        ApprovalForAll(T::AccountId, T::AccountId, bool),
+        /// The commodity limit of the account has been overridden, optionally until the block.
+        UserLimitSet(T::AccountId, u64, Option<T::BlockNumber>),
+        /// The metadata URI of the commodity has been set.
+        TokenUriSet(CommodityId<T>, Vec<u8>),
+        /// The maximum number of this type of commodity has been raised.
+        LimitSet(u128),
     }
 
     #[pallet::error]
@@ -145,6 +250,18 @@ pub mod pallet {
         // Thrown when an attempt is made to mint or transfer a commodity to an account that already
         // owns the maximum number of this type of commodity.
         TooManyCommoditiesForAccount,
+        // Thrown when the commodity admin attempts to set a royalty higher than `MaxRoyalty`.
+        RoyaltyTooHigh,
+        // Thrown when the commodity admin attempts to mint more than `MaxBatchSize` commodities at once.
+        BatchTooLarge,
+        // Thrown when the origin of `transfer_from` is neither the owner nor approved by the owner.
+        NotApproved,
+        // Thrown when the commodity owner attempts to set a URI longer than `MaxUriLength`.
+        UriTooLong,
+        // Thrown when the force origin attempts to lower the commodity limit.
+        LimitTooLow,
+        // Thrown when there is an attempt to transfer a soulbound commodity.
+        SoulboundCommodity,
     }
 
     #[pallet::call]
@@ -157,21 +274,112 @@ pub mod pallet {
         ///
         /// This function will throw an error if it is called with commodity info that describes
         /// an existing (duplicate) commodity, if the maximum number of this type of commodity already
-        /// exists or if the specified owner already owns the maximum number of this type of
-        /// commodity.
+        /// exists, if the specified owner already owns the maximum number of this type of
+        /// commodity or if the royalty is higher than `MaxRoyalty`.
         ///
         /// - `owner_account`: Receiver of the commodity.
         /// - `commodity_info`: The information that defines the commodity.
+        /// - `royalty`: Optional royalty beneficiary and rate, this pallet only records it,
+        ///   paying the royalty is up to the marketplace.
         #[pallet::weight(100_000)]
         pub fn mint(
             origin: OriginFor<T>,
             owner_account: T::AccountId,
             commodity_info: T::CommodityInfo,
+            royalty: Option<(T::AccountId, Permill)>,
         ) -> DispatchResultWithPostInfo {
             T::CommodityAdmin::ensure_origin(origin)?;
 
+            if let Some((_, rate)) = &royalty {
+                ensure!(*rate <= T::MaxRoyalty::get(), Error::<T, I>::RoyaltyTooHigh);
+            }
+
             let commodity_id = <Self as UniqueAssets<_>>::mint(&owner_account, commodity_info)?;
             Self::deposit_event(Event::Minted(commodity_id, owner_account.clone()));
+
+            if let Some((beneficiary, rate)) = royalty {
+                RoyaltyOf::<T, I>::insert(commodity_id, (beneficiary.clone(), rate));
+                Self::deposit_event(Event::RoyaltySet(commodity_id, beneficiary, rate));
+            }
+            Ok(().into())
+        }
+
+        /// Create a new soulbound commodity from the provided commodity info and bind it to the
+        /// specified account. A soulbound commodity can never be transferred, only burned.
+        ///
+        /// The dispatch origin for this call must be the commodity admin.
+        ///
+        /// This function will throw an error in the same cases as `mint`.
+        ///
+        /// - `owner_account`: Receiver of the commodity.
+        /// - `commodity_info`: The information that defines the commodity.
+        #[pallet::weight(100_000)]
+        pub fn mint_soulbound(
+            origin: OriginFor<T>,
+            owner_account: T::AccountId,
+            commodity_info: T::CommodityInfo,
+        ) -> DispatchResultWithPostInfo {
+            T::CommodityAdmin::ensure_origin(origin)?;
+
+            Self::do_mint_soulbound(&owner_account, commodity_info)?;
+            Ok(().into())
+        }
+
+        /// Create a batch of new commodities from the provided commodity infos and identify the
+        /// specified account as their owner.
+        ///
+        /// The dispatch origin for this call must be the commodity admin.
+        ///
+        /// The whole batch is checked before anything is written, so either all commodities are
+        /// minted or none. This function will throw an error if the batch is larger than
+        /// `MaxBatchSize`, if any info describes an existing (duplicate) commodity, or if minting
+        /// the whole batch would exceed the commodity limit or the owner's limit.
+        ///
+        /// - `owner_account`: Receiver of the commodities.
+        /// - `infos`: The information that defines each commodity.
+        #[pallet::weight(100_000u64.saturating_mul(infos.len() as u64))]
+        pub fn mint_batch(
+            origin: OriginFor<T>,
+            owner_account: T::AccountId,
+            infos: Vec<T::CommodityInfo>,
+        ) -> DispatchResultWithPostInfo {
+            T::CommodityAdmin::ensure_origin(origin)?;
+
+            ensure!(
+                infos.len() <= T::MaxBatchSize::get() as usize,
+                Error::<T, I>::BatchTooLarge
+            );
+
+            let commodity_ids: Vec<CommodityId<T>> =
+                infos.iter().map(|info| T::Hashing::hash_of(info)).collect();
+
+            let mut sorted_ids = commodity_ids.clone();
+            sorted_ids.sort();
+            sorted_ids.dedup();
+            ensure!(
+                sorted_ids.len() == commodity_ids.len()
+                    && !sorted_ids
+                        .iter()
+                        .any(|id| AccountForCommodity::<T, I>::contains_key(id)),
+                Error::<T, I>::CommodityExists
+            );
+
+            let count = infos.len() as u64;
+            ensure!(
+                Self::get_total_for_account(&owner_account).saturating_add(count)
+                    <= Self::user_limit_of(&owner_account),
+                Error::<T, I>::TooManyCommoditiesForAccount
+            );
+            ensure!(
+                Self::get_total().saturating_add(count as u128) <= Self::commodity_limit(),
+                Error::<T, I>::TooManyCommodities
+            );
+
+            for info in infos {
+                <Self as UniqueAssets<_>>::mint(&owner_account, info)?;
+            }
+
+            Self::deposit_event(Event::MintedBatch(commodity_ids, owner_account));
             Ok(().into())
         }
 
@@ -197,9 +405,33 @@ pub mod pallet {
             Ok(().into())
         }
 
+        /// Destroy the specified commodity regardless of its owner, e.g. to remove a commodity
+        /// flagged as infringing.
+        ///
+        /// The dispatch origin for this call must be the commodity admin.
+        ///
+        /// - `commodity_id`: The hash (calculated by the runtime system's hashing algorithm)
+        ///   of the info that defines the commodity to destroy.
+        #[pallet::weight(100_000)]
+        pub fn admin_burn(
+            origin: OriginFor<T>,
+            commodity_id: CommodityId<T>,
+        ) -> DispatchResultWithPostInfo {
+            T::CommodityAdmin::ensure_origin(origin)?;
+            let owner = Self::account_for_commodity(&commodity_id)
+                .ok_or(Error::<T, I>::NonexistentCommodity)?;
+
+            <Self as UniqueAssets<_>>::burn(&commodity_id)?;
+            Self::deposit_event(Event::Burned(commodity_id));
+            Self::deposit_event(Event::AdminBurned(commodity_id, owner));
+            Ok(().into())
+        }
+
         /// Transfer a commodity to a new owner.
         ///
-        /// The dispatch origin for this call must be the commodity owner.
+        /// The dispatch origin for this call must be the commodity owner, the account approved
+        /// for the commodity or an operator approved for all of the owner's commodities.
+        /// Approval of the commodity is cleared on transfer.
         ///
         /// This function will throw an error if the new owner already owns the maximum
         /// number of this type of commodity.
@@ -214,8 +446,10 @@ pub mod pallet {
             commodity_id: CommodityId<T>,
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
+            let owner = Self::account_for_commodity(&commodity_id)
+                .ok_or(Error::<T, I>::NotCommodityOwner)?;
             ensure!(
-                Some(who) == Self::account_for_commodity(&commodity_id),
+                who == owner || Self::is_approved(&owner, &who, &commodity_id),
                 Error::<T, I>::NotCommodityOwner
             );
 
@@ -226,6 +460,177 @@ pub mod pallet {
             ));
             Ok(().into())
         }
+
+        /// Transfer a commodity from its recorded owner to a new owner.
+        ///
+        /// The dispatch origin for this call must be the commodity owner, the account approved
+        /// for the commodity or an operator approved for all of the owner's commodities.
+        /// Unlike `transfer`, the current owner is given explicitly so that escrow flows fail
+        /// when the owner has changed in the meantime. Approval of the commodity is cleared on
+        /// transfer.
+        ///
+        /// This function will throw an error if `from` is not the recorded owner, if the origin
+        /// is not approved, or if the new owner already owns the maximum number of this type
+        /// of commodity.
+        ///
+        /// - `from`: Current owner of the commodity.
+        /// - `to`: Receiver of the commodity.
+        /// - `commodity_id`: The hash (calculated by the runtime system's hashing algorithm)
+        ///   of the info that defines the commodity to transfer.
+        #[pallet::weight(100_000)]
+        pub fn transfer_from(
+            origin: OriginFor<T>,
+            from: T::AccountId,
+            to: T::AccountId,
+            commodity_id: CommodityId<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                Some(from.clone()) == Self::account_for_commodity(&commodity_id),
+                Error::<T, I>::NotCommodityOwner
+            );
+            ensure!(
+                who == from || Self::is_approved(&from, &who, &commodity_id),
+                Error::<T, I>::NotApproved
+            );
+
+            <Self as UniqueAssets<_>>::transfer(&to, &commodity_id)?;
+            Self::deposit_event(Event::Transferred(commodity_id, to));
+            Ok(().into())
+        }
+
+        /// Set the metadata URI of the commodity, replacing the previous one.
+        ///
+        /// The dispatch origin for this call must be the commodity owner.
+        ///
+        /// - `commodity_id`: The hash of the info that defines the commodity.
+        /// - `uri`: The metadata URI, at most `MaxUriLength` bytes long.
+        #[pallet::weight(100_000)]
+        pub fn set_token_uri(
+            origin: OriginFor<T>,
+            commodity_id: CommodityId<T>,
+            uri: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                Some(who) == Self::account_for_commodity(&commodity_id),
+                Error::<T, I>::NotCommodityOwner
+            );
+            ensure!(
+                uri.len() <= T::MaxUriLength::get() as usize,
+                Error::<T, I>::UriTooLong
+            );
+
+            TokenUri::<T, I>::insert(&commodity_id, &uri);
+            Self::deposit_event(Event::TokenUriSet(commodity_id, uri));
+            Ok(().into())
+        }
+
+        /// Approve the operator to transfer the commodity, replacing the previous approval.
+        ///
+        /// The dispatch origin for this call must be the commodity owner.
+        ///
+        /// - `operator`: The account allowed to transfer the commodity.
+        /// - `commodity_id`: The hash of the info that defines the commodity.
+        #[pallet::weight(100_000)]
+        pub fn approve(
+            origin: OriginFor<T>,
+            operator: T::AccountId,
+            commodity_id: CommodityId<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                Some(who.clone()) == Self::account_for_commodity(&commodity_id),
+                Error::<T, I>::NotCommodityOwner
+            );
+
+            Approved::<T, I>::insert(&commodity_id, &operator);
+            Self::deposit_event(Event::Approval(commodity_id, who, operator));
+            Ok(().into())
+        }
+
+        /// Approve or revoke the operator to transfer all commodities of the caller.
+        ///
+        /// The dispatch origin for this call must be _Signed_.
+        ///
+        /// - `operator`: The account allowed to transfer the caller's commodities.
+        /// - `approved`: Whether to approve or revoke the operator.
+        #[pallet::weight(100_000)]
+        pub fn set_approval_for_all(
+            origin: OriginFor<T>,
+            operator: T::AccountId,
+            approved: bool,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            if approved {
+                ApprovalForAll::<T, I>::insert(&who, &operator, true);
+            } else {
+                ApprovalForAll::<T, I>::remove(&who, &operator);
+            }
+            Self::deposit_event(Event::ApprovalForAll(who, operator, approved));
+            Ok(().into())
+        }
+
+        /// Override the maximum number of this type of commodity that the account may own.
+        ///
+        /// The dispatch origin for this call must be the commodity admin.
+        ///
+        /// - `account`: The account whose limit is overridden.
+        /// - `limit`: The maximum number of commodities the account may own.
+        #[pallet::weight(100_000)]
+        pub fn set_user_limit(
+            origin: OriginFor<T>,
+            account: T::AccountId,
+            limit: u64,
+        ) -> DispatchResultWithPostInfo {
+            T::CommodityAdmin::ensure_origin(origin)?;
+
+            UserLimitOverride::<T, I>::insert(&account, (limit, None::<T::BlockNumber>));
+            Self::deposit_event(Event::UserLimitSet(account, limit, None));
+            Ok(().into())
+        }
+
+        /// Temporarily override the maximum number of this type of commodity that the account
+        /// may own. Once the `until` block is reached the override is ignored and the account
+        /// falls back to `UserCommodityLimit`.
+        ///
+        /// The dispatch origin for this call must be the commodity admin.
+        ///
+        /// - `account`: The account whose limit is overridden.
+        /// - `limit`: The maximum number of commodities the account may own.
+        /// - `until`: The block number at which the override expires.
+        #[pallet::weight(100_000)]
+        pub fn set_user_limit_temp(
+            origin: OriginFor<T>,
+            account: T::AccountId,
+            limit: u64,
+            until: T::BlockNumber,
+        ) -> DispatchResultWithPostInfo {
+            T::CommodityAdmin::ensure_origin(origin)?;
+
+            UserLimitOverride::<T, I>::insert(&account, (limit, Some(until)));
+            Self::deposit_event(Event::UserLimitSet(account, limit, Some(until)));
+            Ok(().into())
+        }
+
+        /// Raise the maximum number of this type of commodity that may exist, superseding
+        /// `CommodityLimit`.
+        ///
+        /// The dispatch origin for this call must be the force origin.
+        ///
+        /// The limit can only be raised so existing commodities never exceed it.
+        ///
+        /// - `limit`: The new maximum number of commodities.
+        #[pallet::weight(100_000)]
+        pub fn set_limit(origin: OriginFor<T>, limit: u128) -> DispatchResultWithPostInfo {
+            T::ForceOrigin::ensure_origin(origin)?;
+            ensure!(limit >= Self::commodity_limit(), Error::<T, I>::LimitTooLow);
+
+            LimitOverride::<T, I>::put(limit);
+            Self::deposit_event(Event::LimitSet(limit));
+            Ok(().into())
+        }
     }
 
     // ----------------------------------------------------------------
@@ -238,6 +643,86 @@ pub mod pallet {
 pub use pallet::*;
 use sp_std::vec;
 
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+    /// The maximum number of this type of commodity that may exist,
+    /// taking the runtime override into account.
+    pub fn commodity_limit() -> u128 {
+        Self::limit_override().unwrap_or_else(T::CommodityLimit::get)
+    }
+
+    /// The maximum number of this type of commodity that the account may own,
+    /// taking unexpired per-account overrides into account.
+    pub fn user_limit_of(account: &T::AccountId) -> u64 {
+        match Self::user_limit_override(account) {
+            Some((limit, None)) => limit,
+            Some((limit, Some(until))) if <frame_system::Module<T>>::block_number() < until => {
+                limit
+            }
+            _ => T::UserCommodityLimit::get(),
+        }
+    }
+
+    /// Mint a soulbound commodity owned by the account, for pallets that issue commodities
+    /// on their own authority.
+    pub fn do_mint_soulbound(
+        owner_account: &T::AccountId,
+        commodity_info: T::CommodityInfo,
+    ) -> dispatch::result::Result<CommodityId<T>, dispatch::DispatchError> {
+        let commodity_id = <Self as UniqueAssets<_>>::mint(owner_account, commodity_info)?;
+        Soulbound::<T, I>::insert(commodity_id, true);
+        Self::deposit_event(Event::SoulboundMinted(commodity_id, owner_account.clone()));
+        Ok(commodity_id)
+    }
+
+    /// Whether the operator is approved to transfer the owner's commodity, either for this
+    /// commodity only or for all of the owner's commodities.
+    pub fn is_approved(
+        owner: &T::AccountId,
+        operator: &T::AccountId,
+        commodity_id: &CommodityId<T>,
+    ) -> bool {
+        Self::approved(commodity_id).as_ref() == Some(operator)
+            || Self::approval_for_all(owner, operator)
+    }
+
+    /// The number of commodities owned by the account.
+    pub fn asset_count_for_account(account: &T::AccountId) -> u64 {
+        <Self as UniqueAssets<_>>::get_total_for_account(account)
+    }
+
+    /// At most `count` commodities owned by the account, starting at `start_index`.
+    ///
+    /// Commodities are decoded straight from the raw storage value and decoding stops at the
+    /// end of the page, so the account's whole commodity list is never materialized.
+    pub fn assets_for_account_paged(
+        account: &T::AccountId,
+        start_index: u64,
+        count: u64,
+    ) -> Vec<Commodity<T, I>> {
+        let key = CommoditiesForAccount::<T, I>::hashed_key_for(account);
+        let raw = match frame_support::storage::unhashed::get_raw(&key) {
+            Some(raw) => raw,
+            None => return Vec::new(),
+        };
+        let input = &mut &raw[..];
+        let len = match Compact::<u32>::decode(input) {
+            Ok(len) => u64::from(len.0),
+            Err(_) => return Vec::new(),
+        };
+        let end = start_index.saturating_add(count).min(len);
+
+        let mut page = Vec::new();
+        for index in 0..end {
+            match Commodity::<T, I>::decode(input) {
+                Ok(commodity) if index >= start_index => page.push(commodity),
+                Ok(_) => {}
+                Err(_) => break,
+            }
+        }
+        page
+    }
+}
+
 impl<T: Config<I>, I: 'static> UniqueAssets<T::AccountId> for Pallet<T, I> {
     type AssetId = CommodityId<T>;
     type AssetInfo = T::CommodityInfo;
@@ -276,15 +761,16 @@ impl<T: Config<I>, I: 'static> UniqueAssets<T::AccountId> for Pallet<T, I> {
         );
 
         ensure!(
-            Self::get_total_for_account(owner_account) < T::UserCommodityLimit::get(),
+            Self::get_total_for_account(owner_account) < Self::user_limit_of(owner_account),
             Error::<T, I>::TooManyCommoditiesForAccount
         );
 
         ensure!(
-            Self::get_total() < T::CommodityLimit::get(),
+            Self::get_total() < Self::commodity_limit(),
             Error::<T, I>::TooManyCommodities
         );
 
+        CommodityInfoOf::<T, I>::insert(commodity_id, &commodity_info);
         let new_commodity = (commodity_id, commodity_info);
 
         Total::<T, I>::mutate(|total| *total = Some(total.unwrap_or(0).saturating_add(1)));
@@ -332,6 +818,11 @@ impl<T: Config<I>, I: 'static> UniqueAssets<T::AccountId> for Pallet<T, I> {
             }
         });
         AccountForCommodity::<T, I>::remove(&commodity_id);
+        CommodityInfoOf::<T, I>::remove(&commodity_id);
+        TokenUri::<T, I>::remove(&commodity_id);
+        RoyaltyOf::<T, I>::remove(&commodity_id);
+        Approved::<T, I>::remove(&commodity_id);
+        Soulbound::<T, I>::remove(&commodity_id);
 
         Ok(())
     }
@@ -350,7 +841,12 @@ impl<T: Config<I>, I: 'static> UniqueAssets<T::AccountId> for Pallet<T, I> {
         let owner = owner.unwrap(); // should never fail
 
         ensure!(
-            Self::get_total_for_account(dest_account) < T::UserCommodityLimit::get(),
+            !Self::is_soulbound(commodity_id),
+            Error::<T, I>::SoulboundCommodity
+        );
+
+        ensure!(
+            Self::get_total_for_account(dest_account) < Self::user_limit_of(dest_account),
             Error::<T, I>::TooManyCommoditiesForAccount
         );
 
@@ -384,6 +880,7 @@ impl<T: Config<I>, I: 'static> UniqueAssets<T::AccountId> for Pallet<T, I> {
             }
         });
         AccountForCommodity::<T, I>::insert(&commodity_id, &dest_account);
+        Approved::<T, I>::remove(&commodity_id);
 
         Ok(())
     }
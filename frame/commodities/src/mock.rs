@@ -1,13 +1,13 @@
 // Creating mock runtime here
 
 use crate::{self as pallet_nft, Config, Module};
-use frame_support::{parameter_types, weights::Weight};
+use frame_support::{instances::Instance1, parameter_types, weights::Weight};
 use frame_system as system;
 use sp_core::H256;
 use sp_runtime::{
     testing::Header,
     traits::{BlakeTwo256, IdentityLookup},
-    Perbill,
+    Perbill, Permill,
 };
 
 // impl_outer_origin! {
@@ -24,7 +24,9 @@ frame_support::construct_runtime!(
         UncheckedExtrinsic = UncheckedExtrinsic,
     {
         System: frame_system::{Module, Call, Config, Storage, Event<T>},
-        Nft: pallet_nft::{Module, Call, Storage, Event<T>}
+        Balances: pallet_balances::{Module, Call, Storage, Config<T>, Event<T>},
+        Nft: pallet_nft::{Module, Call, Storage, Event<T>},
+        NftB: pallet_nft::<Instance1>::{Module, Call, Storage, Event<T>}
     }
 );
 
@@ -81,16 +83,33 @@ impl frame_system::Config for Test {
     type BlockHashCount = BlockHashCount;
     type Version = ();
     type PalletInfo = PalletInfo;
-    type AccountData = ();
+    type AccountData = pallet_balances::AccountData<u64>;
     type OnNewAccount = ();
     type OnKilledAccount = ();
     type SystemWeightInfo = ();
     type SS58Prefix = ();
 }
 
+parameter_types! {
+    pub const ExistentialDeposit: u64 = 1;
+}
+
+impl pallet_balances::Config for Test {
+    type MaxLocks = ();
+    type Balance = u64;
+    type Event = Event;
+    type DustRemoval = ();
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type WeightInfo = ();
+}
+
 parameter_types! {
     pub const MaxCommodities: u128 = 5;
     pub const MaxCommoditiesPerUser: u64 = 2;
+    pub const MaxRoyalty: Permill = Permill::from_percent(10);
+    pub const MaxBatchSize: u32 = 3;
+    pub const MaxUriLength: u32 = 16;
 }
 
 // // For testing the pallet, we construct most of a mock runtime. This means
@@ -102,13 +121,33 @@ parameter_types! {
 impl Config for Test {
     type Event = Event;
     type CommodityAdmin = frame_system::EnsureRoot<Self::AccountId>;
+    type ForceOrigin = frame_system::EnsureRoot<Self::AccountId>;
+    type CommodityInfo = Vec<u8>;
+    type CommodityLimit = MaxCommodities;
+    type UserCommodityLimit = MaxCommoditiesPerUser;
+    type RoyaltyCurrency = Balances;
+    type MaxRoyalty = MaxRoyalty;
+    type MaxBatchSize = MaxBatchSize;
+    type MaxUriLength = MaxUriLength;
+}
+
+impl Config<Instance1> for Test {
+    type Event = Event;
+    type CommodityAdmin = frame_system::EnsureRoot<Self::AccountId>;
+    type ForceOrigin = frame_system::EnsureRoot<Self::AccountId>;
     type CommodityInfo = Vec<u8>;
     type CommodityLimit = MaxCommodities;
     type UserCommodityLimit = MaxCommoditiesPerUser;
+    type RoyaltyCurrency = Balances;
+    type MaxRoyalty = MaxRoyalty;
+    type MaxBatchSize = MaxBatchSize;
+    type MaxUriLength = MaxUriLength;
 }
 
 // system under test
 pub type SUT = Module<Test>;
+// second instance of the system under test
+pub type SUTB = Module<Test, Instance1>;
 
 // This function basically just builds a genesis storage key/value store according to
 // our desired mockup.
@@ -20,7 +20,7 @@ fn mint() {
             None
         );
 
-        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default(), None));
 
         assert_eq!(SUT::get_total(), 1);
         assert_eq!(<SUT as UniqueAssets<_>>::get_total(), 1);
@@ -39,6 +39,10 @@ fn mint() {
             SUT::account_for_commodity::<H256>(Vec::<u8>::default().blake2_256().into()),
             Some(1)
         );
+        assert_eq!(
+            SUT::info_of::<H256>(Vec::<u8>::default().blake2_256().into()),
+            Some(Vec::<u8>::default())
+        );
     });
 }
 
@@ -46,7 +50,7 @@ fn mint() {
 fn mint_err_non_admin() {
     new_test_ext().execute_with(|| {
         assert_err!(
-            SUT::mint(Origin::signed(1), 1, Vec::<u8>::default()),
+            SUT::mint(Origin::signed(1), 1, Vec::<u8>::default(), None),
             sp_runtime::DispatchError::BadOrigin
         );
     });
@@ -55,10 +59,10 @@ fn mint_err_non_admin() {
 #[test]
 fn mint_err_dupe() {
     new_test_ext().execute_with(|| {
-        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default(), None));
 
         assert_err!(
-            SUT::mint(Origin::root(), 2, Vec::<u8>::default()),
+            SUT::mint(Origin::root(), 2, Vec::<u8>::default(), None),
             Error::<Test, DefaultInstance>::CommodityExists
         );
     });
@@ -67,11 +71,11 @@ fn mint_err_dupe() {
 #[test]
 fn mint_err_max_user() {
     new_test_ext().execute_with(|| {
-        assert_ok!(SUT::mint(Origin::root(), 1, vec![]));
-        assert_ok!(SUT::mint(Origin::root(), 1, vec![0]));
+        assert_ok!(SUT::mint(Origin::root(), 1, vec![], None));
+        assert_ok!(SUT::mint(Origin::root(), 1, vec![0], None));
 
         assert_err!(
-            SUT::mint(Origin::root(), 1, vec![1]),
+            SUT::mint(Origin::root(), 1, vec![1], None),
             Error::<Test, DefaultInstance>::TooManyCommoditiesForAccount
         );
     });
@@ -80,14 +84,14 @@ fn mint_err_max_user() {
 #[test]
 fn mint_err_max() {
     new_test_ext().execute_with(|| {
-        assert_ok!(SUT::mint(Origin::root(), 1, vec![]));
-        assert_ok!(SUT::mint(Origin::root(), 2, vec![0]));
-        assert_ok!(SUT::mint(Origin::root(), 3, vec![1]));
-        assert_ok!(SUT::mint(Origin::root(), 4, vec![2]));
-        assert_ok!(SUT::mint(Origin::root(), 5, vec![3]));
+        assert_ok!(SUT::mint(Origin::root(), 1, vec![], None));
+        assert_ok!(SUT::mint(Origin::root(), 2, vec![0], None));
+        assert_ok!(SUT::mint(Origin::root(), 3, vec![1], None));
+        assert_ok!(SUT::mint(Origin::root(), 4, vec![2], None));
+        assert_ok!(SUT::mint(Origin::root(), 5, vec![3], None));
 
         assert_err!(
-            SUT::mint(Origin::root(), 6, vec![4]),
+            SUT::mint(Origin::root(), 6, vec![4], None),
             Error::<Test, DefaultInstance>::TooManyCommodities
         );
     });
@@ -96,7 +100,7 @@ fn mint_err_max() {
 #[test]
 fn burn() {
     new_test_ext().execute_with(|| {
-        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default(), None));
         assert_ok!(SUT::burn(
             Origin::signed(1),
             Vec::<u8>::default().blake2_256().into()
@@ -110,13 +114,17 @@ fn burn() {
             SUT::account_for_commodity::<H256>(Vec::<u8>::default().blake2_256().into()),
             None
         );
+        assert_eq!(
+            SUT::info_of::<H256>(Vec::<u8>::default().blake2_256().into()),
+            None
+        );
     });
 }
 
 #[test]
 fn burn_err_not_owner() {
     new_test_ext().execute_with(|| {
-        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default(), None));
 
         assert_err!(
             SUT::burn(Origin::signed(2), Vec::<u8>::default().blake2_256().into()),
@@ -138,7 +146,7 @@ fn burn_err_not_exist() {
 #[test]
 fn transfer() {
     new_test_ext().execute_with(|| {
-        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default(), None));
         assert_eq!(SUT::get_total(), 1);
         assert_ok!(SUT::transfer(
             Origin::signed(1),
@@ -168,7 +176,7 @@ fn transfer() {
 #[test]
 fn transfer_err_not_owner() {
     new_test_ext().execute_with(|| {
-        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default()));
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default(), None));
 
         assert_err!(
             SUT::transfer(
@@ -198,9 +206,9 @@ fn transfer_err_not_exist() {
 #[test]
 fn transfer_err_max_user() {
     new_test_ext().execute_with(|| {
-        assert_ok!(SUT::mint(Origin::root(), 1, vec![0]));
-        assert_ok!(SUT::mint(Origin::root(), 1, vec![1]));
-        assert_ok!(SUT::mint(Origin::root(), 2, Vec::<u8>::default()));
+        assert_ok!(SUT::mint(Origin::root(), 1, vec![0], None));
+        assert_ok!(SUT::mint(Origin::root(), 1, vec![1], None));
+        assert_ok!(SUT::mint(Origin::root(), 2, Vec::<u8>::default(), None));
         assert_eq!(
             SUT::account_for_commodity::<H256>(Vec::<u8>::default().blake2_256().into()),
             Some(2)
@@ -216,3 +224,611 @@ fn transfer_err_max_user() {
         );
     });
 }
+
+#[test]
+fn set_user_limit_err_non_admin() {
+    new_test_ext().execute_with(|| {
+        assert_err!(
+            SUT::set_user_limit(Origin::signed(1), 1, 3),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_err!(
+            SUT::set_user_limit_temp(Origin::signed(1), 1, 3, 10),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn mint_with_user_limit_override() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::set_user_limit(Origin::root(), 1, 3));
+        assert_eq!(SUT::user_limit_of(&1), 3);
+
+        assert_ok!(SUT::mint(Origin::root(), 1, vec![0], None));
+        assert_ok!(SUT::mint(Origin::root(), 1, vec![1], None));
+        assert_ok!(SUT::mint(Origin::root(), 1, vec![2], None));
+
+        assert_err!(
+            SUT::mint(Origin::root(), 1, vec![3], None),
+            Error::<Test, DefaultInstance>::TooManyCommoditiesForAccount
+        );
+    });
+}
+
+#[test]
+fn temp_user_limit_reverts_after_expiry() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::set_user_limit_temp(Origin::root(), 1, 4, 10));
+        assert_eq!(SUT::user_limit_of(&1), 4);
+
+        // elevated cap applies before expiry
+        assert_ok!(SUT::mint(Origin::root(), 1, vec![0], None));
+        assert_ok!(SUT::mint(Origin::root(), 1, vec![1], None));
+        assert_ok!(SUT::mint(Origin::root(), 1, vec![2], None));
+
+        System::set_block_number(10);
+        assert_eq!(SUT::user_limit_of(&1), MaxCommoditiesPerUser::get());
+
+        // falls back to default cap after expiry
+        assert_err!(
+            SUT::mint(Origin::root(), 1, vec![3], None),
+            Error::<Test, DefaultInstance>::TooManyCommoditiesForAccount
+        );
+        assert_ok!(SUT::mint(Origin::root(), 2, vec![4], None));
+        assert_err!(
+            SUT::transfer(Origin::signed(2), 1, vec![4u8].blake2_256().into()),
+            Error::<Test, DefaultInstance>::TooManyCommoditiesForAccount
+        );
+    });
+}
+
+#[test]
+fn mint_with_royalty() {
+    new_test_ext().execute_with(|| {
+        let commodity_id: H256 = Vec::<u8>::default().blake2_256().into();
+
+        assert_ok!(SUT::mint(
+            Origin::root(),
+            1,
+            Vec::<u8>::default(),
+            Some((3, Permill::from_percent(5)))
+        ));
+        assert_eq!(
+            SUT::royalty_of(commodity_id),
+            Some((3, Permill::from_percent(5)))
+        );
+        assert_eq!(
+            System::events().last().map(|r| r.event.clone()),
+            Some(mock::Event::pallet_nft(crate::Event::RoyaltySet(
+                commodity_id,
+                3,
+                Permill::from_percent(5)
+            )))
+        );
+
+        // royalty stays with the commodity across transfers
+        assert_ok!(SUT::transfer(Origin::signed(1), 2, commodity_id));
+        assert_eq!(
+            SUT::royalty_of(commodity_id),
+            Some((3, Permill::from_percent(5)))
+        );
+
+        assert_ok!(SUT::burn(Origin::signed(2), commodity_id));
+        assert_eq!(SUT::royalty_of(commodity_id), None);
+    });
+}
+
+#[test]
+fn mint_err_royalty_too_high() {
+    new_test_ext().execute_with(|| {
+        assert_err!(
+            SUT::mint(
+                Origin::root(),
+                1,
+                vec![0],
+                Some((3, Permill::from_percent(11)))
+            ),
+            Error::<Test, DefaultInstance>::RoyaltyTooHigh
+        );
+        assert_eq!(SUT::get_total(), 0);
+    });
+}
+
+#[test]
+fn transfer_by_approved_operator() {
+    new_test_ext().execute_with(|| {
+        let commodity_id: H256 = Vec::<u8>::default().blake2_256().into();
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default(), None));
+
+        assert_err!(
+            SUT::approve(Origin::signed(2), 3, commodity_id),
+            Error::<Test, DefaultInstance>::NotCommodityOwner
+        );
+        assert_err!(
+            SUT::transfer(Origin::signed(3), 2, commodity_id),
+            Error::<Test, DefaultInstance>::NotCommodityOwner
+        );
+
+        assert_ok!(SUT::approve(Origin::signed(1), 3, commodity_id));
+        assert_eq!(SUT::approved(commodity_id), Some(3));
+        assert_eq!(
+            System::events().last().map(|r| r.event.clone()),
+            Some(mock::Event::pallet_nft(crate::Event::Approval(
+                commodity_id,
+                1,
+                3
+            )))
+        );
+
+        assert_ok!(SUT::transfer(Origin::signed(3), 2, commodity_id));
+        assert_eq!(SUT::account_for_commodity(commodity_id), Some(2));
+
+        // approval is cleared on transfer
+        assert_eq!(SUT::approved(commodity_id), None);
+        assert_err!(
+            SUT::transfer(Origin::signed(3), 1, commodity_id),
+            Error::<Test, DefaultInstance>::NotCommodityOwner
+        );
+    });
+}
+
+#[test]
+fn transfer_by_operator_approved_for_all() {
+    new_test_ext().execute_with(|| {
+        let commodity_id: H256 = Vec::<u8>::default().blake2_256().into();
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default(), None));
+
+        assert_ok!(SUT::set_approval_for_all(Origin::signed(1), 3, true));
+        assert!(SUT::approval_for_all(1, 3));
+        assert_eq!(
+            System::events().last().map(|r| r.event.clone()),
+            Some(mock::Event::pallet_nft(crate::Event::ApprovalForAll(
+                1, 3, true
+            )))
+        );
+
+        assert_ok!(SUT::transfer(Origin::signed(3), 2, commodity_id));
+        assert_eq!(SUT::account_for_commodity(commodity_id), Some(2));
+
+        // operator is approved by the previous owner only
+        assert_err!(
+            SUT::transfer(Origin::signed(3), 1, commodity_id),
+            Error::<Test, DefaultInstance>::NotCommodityOwner
+        );
+
+        assert_ok!(SUT::set_approval_for_all(Origin::signed(1), 3, false));
+        assert!(!SUT::approval_for_all(1, 3));
+    });
+}
+
+#[test]
+fn burn_clears_approval() {
+    new_test_ext().execute_with(|| {
+        let commodity_id: H256 = Vec::<u8>::default().blake2_256().into();
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default(), None));
+        assert_ok!(SUT::approve(Origin::signed(1), 3, commodity_id));
+
+        assert_ok!(SUT::burn(Origin::signed(1), commodity_id));
+        assert_eq!(SUT::approved(commodity_id), None);
+    });
+}
+
+#[test]
+fn mint_batch() {
+    new_test_ext().execute_with(|| {
+        let infos = vec![vec![1u8], vec![2u8]];
+        let commodity_ids: Vec<H256> = infos.iter().map(|i| i.blake2_256().into()).collect();
+
+        assert_ok!(SUT::mint_batch(Origin::root(), 1, infos));
+        assert_eq!(SUT::total(), Some(2));
+        assert_eq!(SUT::total_for_account(1), Some(2));
+        assert_eq!(SUT::account_for_commodity(commodity_ids[0]), Some(1));
+        assert_eq!(SUT::account_for_commodity(commodity_ids[1]), Some(1));
+        assert_eq!(
+            System::events().last().map(|r| r.event.clone()),
+            Some(mock::Event::pallet_nft(crate::Event::MintedBatch(
+                commodity_ids,
+                1
+            )))
+        );
+    });
+}
+
+#[test]
+fn mint_batch_err_too_large() {
+    new_test_ext().execute_with(|| {
+        assert_err!(
+            SUT::mint_batch(
+                Origin::root(),
+                1,
+                vec![vec![1u8], vec![2u8], vec![3u8], vec![4u8]]
+            ),
+            Error::<Test, DefaultInstance>::BatchTooLarge
+        );
+    });
+}
+
+#[test]
+fn mint_batch_err_duplicate() {
+    new_test_ext().execute_with(|| {
+        assert_err!(
+            SUT::mint_batch(Origin::root(), 1, vec![vec![1u8], vec![1u8]]),
+            Error::<Test, DefaultInstance>::CommodityExists
+        );
+
+        assert_ok!(SUT::mint(Origin::root(), 1, vec![1u8], None));
+        assert_err!(
+            SUT::mint_batch(Origin::root(), 2, vec![vec![2u8], vec![1u8]]),
+            Error::<Test, DefaultInstance>::CommodityExists
+        );
+        assert_eq!(SUT::total_for_account(2), None);
+    });
+}
+
+#[test]
+fn mint_batch_err_max_user() {
+    new_test_ext().execute_with(|| {
+        assert_err!(
+            SUT::mint_batch(Origin::root(), 1, vec![vec![1u8], vec![2u8], vec![3u8]]),
+            Error::<Test, DefaultInstance>::TooManyCommoditiesForAccount
+        );
+
+        // nothing is written when the batch is rejected
+        assert_eq!(SUT::total(), None);
+        assert_eq!(SUT::total_for_account(1), None);
+    });
+}
+
+#[test]
+fn mint_batch_err_max() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint_batch(
+            Origin::root(),
+            1,
+            vec![vec![1u8], vec![2u8]]
+        ));
+        assert_ok!(SUT::mint_batch(
+            Origin::root(),
+            2,
+            vec![vec![3u8], vec![4u8]]
+        ));
+
+        assert_err!(
+            SUT::mint_batch(Origin::root(), 3, vec![vec![5u8], vec![6u8]]),
+            Error::<Test, DefaultInstance>::TooManyCommodities
+        );
+
+        assert_eq!(SUT::total(), Some(4));
+        assert_eq!(SUT::total_for_account(3), None);
+    });
+}
+
+#[test]
+fn assets_for_account_paged() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(SUT::asset_count_for_account(&1), 0);
+        assert_eq!(SUT::assets_for_account_paged(&1, 0, 10), vec![]);
+
+        assert_ok!(SUT::set_user_limit(Origin::root(), 1, 4));
+        assert_ok!(SUT::mint_batch(
+            Origin::root(),
+            1,
+            vec![vec![1u8], vec![2u8], vec![3u8]]
+        ));
+        assert_ok!(SUT::mint(Origin::root(), 1, vec![4u8], None));
+        assert_eq!(SUT::asset_count_for_account(&1), 4);
+
+        let all = SUT::commodities_for_account(1).unwrap();
+        assert_eq!(SUT::assets_for_account_paged(&1, 0, 4), all);
+        assert_eq!(SUT::assets_for_account_paged(&1, 0, 2), all[0..2].to_vec());
+        assert_eq!(SUT::assets_for_account_paged(&1, 2, 2), all[2..4].to_vec());
+        assert_eq!(SUT::assets_for_account_paged(&1, 3, 10), all[3..4].to_vec());
+        assert_eq!(SUT::assets_for_account_paged(&1, 4, 10), vec![]);
+        assert_eq!(SUT::assets_for_account_paged(&1, 0, 0), vec![]);
+    });
+}
+
+#[test]
+fn transfer_from_by_approved_operator() {
+    new_test_ext().execute_with(|| {
+        let commodity_id: H256 = Vec::<u8>::default().blake2_256().into();
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default(), None));
+
+        assert_err!(
+            SUT::transfer_from(Origin::signed(3), 1, 2, commodity_id),
+            Error::<Test, DefaultInstance>::NotApproved
+        );
+
+        assert_ok!(SUT::approve(Origin::signed(1), 3, commodity_id));
+        assert_ok!(SUT::transfer_from(Origin::signed(3), 1, 2, commodity_id));
+        assert_eq!(SUT::account_for_commodity(commodity_id), Some(2));
+        assert_eq!(SUT::approved(commodity_id), None);
+        assert_eq!(
+            System::events().last().map(|r| r.event.clone()),
+            Some(mock::Event::pallet_nft(crate::Event::Transferred(
+                commodity_id,
+                2
+            )))
+        );
+
+        // the owner may always transfer
+        assert_ok!(SUT::transfer_from(Origin::signed(2), 2, 1, commodity_id));
+        assert_eq!(SUT::account_for_commodity(commodity_id), Some(1));
+    });
+}
+
+#[test]
+fn transfer_from_err_stale_from() {
+    new_test_ext().execute_with(|| {
+        let commodity_id: H256 = Vec::<u8>::default().blake2_256().into();
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default(), None));
+        assert_ok!(SUT::set_approval_for_all(Origin::signed(1), 3, true));
+
+        // the seller already transferred the commodity away
+        assert_ok!(SUT::transfer(Origin::signed(1), 2, commodity_id));
+
+        assert_err!(
+            SUT::transfer_from(Origin::signed(3), 1, 3, commodity_id),
+            Error::<Test, DefaultInstance>::NotCommodityOwner
+        );
+        assert_err!(
+            SUT::transfer_from(Origin::signed(3), 2, 3, commodity_id),
+            Error::<Test, DefaultInstance>::NotApproved
+        );
+        assert_eq!(SUT::account_for_commodity(commodity_id), Some(2));
+    });
+}
+
+#[test]
+fn set_token_uri() {
+    new_test_ext().execute_with(|| {
+        let commodity_id: H256 = Vec::<u8>::default().blake2_256().into();
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default(), None));
+        assert_eq!(SUT::token_uri(commodity_id), None);
+
+        let uri = b"ipfs://Qm1234567".to_vec();
+        assert_ok!(SUT::set_token_uri(
+            Origin::signed(1),
+            commodity_id,
+            uri.clone()
+        ));
+        assert_eq!(SUT::token_uri(commodity_id), Some(uri.clone()));
+        assert_eq!(
+            System::events().last().map(|r| r.event.clone()),
+            Some(mock::Event::pallet_nft(crate::Event::TokenUriSet(
+                commodity_id,
+                uri
+            )))
+        );
+
+        assert_ok!(SUT::burn(Origin::signed(1), commodity_id));
+        assert_eq!(SUT::token_uri(commodity_id), None);
+    });
+}
+
+#[test]
+fn set_token_uri_err_not_owner() {
+    new_test_ext().execute_with(|| {
+        let commodity_id: H256 = Vec::<u8>::default().blake2_256().into();
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default(), None));
+
+        assert_err!(
+            SUT::set_token_uri(Origin::signed(2), commodity_id, b"ipfs://Qm".to_vec()),
+            Error::<Test, DefaultInstance>::NotCommodityOwner
+        );
+    });
+}
+
+#[test]
+fn set_token_uri_err_too_long() {
+    new_test_ext().execute_with(|| {
+        let commodity_id: H256 = Vec::<u8>::default().blake2_256().into();
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default(), None));
+
+        assert_err!(
+            SUT::set_token_uri(
+                Origin::signed(1),
+                commodity_id,
+                b"ipfs://Qm12345678".to_vec()
+            ),
+            Error::<Test, DefaultInstance>::UriTooLong
+        );
+        assert_eq!(SUT::token_uri(commodity_id), None);
+    });
+}
+
+#[test]
+fn set_limit_mint_past_original_cap() {
+    new_test_ext().execute_with(|| {
+        for i in 0..5u8 {
+            assert_ok!(SUT::mint(Origin::root(), u64::from(i), vec![i], None));
+        }
+        assert_err!(
+            SUT::mint(Origin::root(), 6, vec![5u8], None),
+            Error::<Test, DefaultInstance>::TooManyCommodities
+        );
+
+        assert_ok!(SUT::set_limit(Origin::root(), 6));
+        assert_eq!(SUT::limit_override(), Some(6));
+        assert_eq!(SUT::commodity_limit(), 6);
+        assert_eq!(
+            System::events().last().map(|r| r.event.clone()),
+            Some(mock::Event::pallet_nft(crate::Event::LimitSet(6)))
+        );
+
+        assert_ok!(SUT::mint(Origin::root(), 6, vec![5u8], None));
+        assert_eq!(SUT::get_total(), 6);
+        assert_err!(
+            SUT::mint(Origin::root(), 7, vec![6u8], None),
+            Error::<Test, DefaultInstance>::TooManyCommodities
+        );
+    });
+}
+
+#[test]
+fn set_limit_err() {
+    new_test_ext().execute_with(|| {
+        assert_err!(
+            SUT::set_limit(Origin::signed(1), 10),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        // cannot go below the compile-time limit
+        assert_err!(
+            SUT::set_limit(Origin::root(), 4),
+            Error::<Test, DefaultInstance>::LimitTooLow
+        );
+
+        // nor below a previously raised one
+        assert_ok!(SUT::set_limit(Origin::root(), 10));
+        assert_err!(
+            SUT::set_limit(Origin::root(), 8),
+            Error::<Test, DefaultInstance>::LimitTooLow
+        );
+        assert_eq!(SUT::commodity_limit(), 10);
+    });
+}
+
+#[test]
+fn admin_burn() {
+    new_test_ext().execute_with(|| {
+        let commodity_id: H256 = Vec::<u8>::default().blake2_256().into();
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default(), None));
+
+        assert_err!(
+            SUT::admin_burn(Origin::signed(1), commodity_id),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        assert_ok!(SUT::admin_burn(Origin::root(), commodity_id));
+        assert_eq!(SUT::get_total(), 0);
+        assert_eq!(SUT::get_burned(), 1);
+        assert_eq!(SUT::get_total_for_account(&1), 0);
+        assert_eq!(SUT::account_for_commodity(commodity_id), None);
+
+        let events: Vec<_> = System::events().into_iter().map(|r| r.event).collect();
+        assert_eq!(
+            events[events.len() - 2..].to_vec(),
+            vec![
+                mock::Event::pallet_nft(crate::Event::Burned(commodity_id)),
+                mock::Event::pallet_nft(crate::Event::AdminBurned(commodity_id, 1)),
+            ]
+        );
+    });
+}
+
+#[test]
+fn admin_burn_err_nonexistent() {
+    new_test_ext().execute_with(|| {
+        assert_err!(
+            SUT::admin_burn(Origin::root(), Vec::<u8>::default().blake2_256().into()),
+            Error::<Test, DefaultInstance>::NonexistentCommodity
+        );
+    });
+}
+
+#[test]
+fn instances_are_independent() {
+    new_test_ext().execute_with(|| {
+        let commodity_id: H256 = Vec::<u8>::default().blake2_256().into();
+
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default(), None));
+        assert_eq!(SUT::get_total(), 1);
+        assert_eq!(SUT::get_total_for_account(&1), 1);
+        assert_eq!(SUT::account_for_commodity(commodity_id), Some(1));
+
+        assert_eq!(SUTB::get_total(), 0);
+        assert_eq!(SUTB::get_total_for_account(&1), 0);
+        assert_eq!(SUTB::account_for_commodity(commodity_id), None);
+
+        // the same info can be minted in the other instance, to another owner
+        assert_ok!(SUTB::mint(Origin::root(), 2, Vec::<u8>::default(), None));
+        assert_ok!(SUTB::mint(Origin::root(), 2, vec![2u8], None));
+        assert_eq!(SUTB::get_total(), 2);
+        assert_eq!(SUTB::get_total_for_account(&2), 2);
+        assert_eq!(SUTB::account_for_commodity(commodity_id), Some(2));
+
+        assert_eq!(SUT::get_total(), 1);
+        assert_eq!(SUT::get_total_for_account(&2), 0);
+        assert_eq!(SUT::account_for_commodity(commodity_id), Some(1));
+
+        assert_ok!(SUT::burn(Origin::signed(1), commodity_id));
+        assert_eq!(SUT::get_total(), 0);
+        assert_eq!(SUT::get_burned(), 1);
+        assert_eq!(SUTB::get_total(), 2);
+        assert_eq!(SUTB::get_burned(), 0);
+        assert_eq!(SUTB::account_for_commodity(commodity_id), Some(2));
+    });
+}
+
+#[test]
+fn instance_limits_are_independent() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(SUT::mint(Origin::root(), 1, vec![1u8], None));
+        assert_ok!(SUT::mint(Origin::root(), 1, vec![2u8], None));
+        assert_err!(
+            SUT::mint(Origin::root(), 1, vec![3u8], None),
+            Error::<Test, DefaultInstance>::TooManyCommoditiesForAccount
+        );
+
+        // account 1 is still below its limit in the other instance
+        assert_ok!(SUTB::mint(Origin::root(), 1, vec![3u8], None));
+        assert_eq!(SUTB::get_total_for_account(&1), 1);
+
+        assert_ok!(SUTB::set_limit(Origin::root(), 10));
+        assert_eq!(SUTB::commodity_limit(), 10);
+        assert_eq!(SUT::commodity_limit(), 5);
+    });
+}
+
+#[test]
+fn mint_soulbound() {
+    new_test_ext().execute_with(|| {
+        let commodity_id: H256 = Vec::<u8>::default().blake2_256().into();
+
+        assert_err!(
+            SUT::mint_soulbound(Origin::signed(1), 1, Vec::<u8>::default()),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        assert_ok!(SUT::mint_soulbound(Origin::root(), 1, Vec::<u8>::default()));
+        assert_eq!(SUT::get_total_for_account(&1), 1);
+        assert_eq!(SUT::account_for_commodity(commodity_id), Some(1));
+        assert!(SUT::is_soulbound(commodity_id));
+        assert_eq!(
+            System::events().last().map(|r| r.event.clone()),
+            Some(mock::Event::pallet_nft(crate::Event::SoulboundMinted(
+                commodity_id,
+                1
+            )))
+        );
+    });
+}
+
+#[test]
+fn soulbound_is_not_transferable() {
+    new_test_ext().execute_with(|| {
+        let commodity_id: H256 = Vec::<u8>::default().blake2_256().into();
+        assert_ok!(SUT::do_mint_soulbound(&1, Vec::<u8>::default()));
+
+        assert_err!(
+            SUT::transfer(Origin::signed(1), 2, commodity_id),
+            Error::<Test, DefaultInstance>::SoulboundCommodity
+        );
+
+        // approvals do not help either
+        assert_ok!(SUT::set_approval_for_all(Origin::signed(1), 2, true));
+        assert_err!(
+            SUT::transfer_from(Origin::signed(2), 1, 2, commodity_id),
+            Error::<Test, DefaultInstance>::SoulboundCommodity
+        );
+        assert_eq!(SUT::account_for_commodity(commodity_id), Some(1));
+
+        // burning clears the flag, the same info can be minted transferable again
+        assert_ok!(SUT::burn(Origin::signed(1), commodity_id));
+        assert!(!SUT::is_soulbound(commodity_id));
+        assert_ok!(SUT::mint(Origin::root(), 1, Vec::<u8>::default(), None));
+        assert_ok!(SUT::transfer(Origin::signed(1), 2, commodity_id));
+    });
+}
@@ -1,6 +1,30 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use codec::Codec;
+use codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+/// Service endpoint of a DID document.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct DidService {
+	pub id: Vec<u8>,
+	pub service_type: Vec<u8>,
+	pub endpoint: Vec<u8>,
+}
+
+/// Resolved DID document of an identity.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct DidDocument<AccountId> {
+	/// The identity.
+	pub id: AccountId,
+	/// Current owner (controller) of the identity.
+	pub owner: AccountId,
+	/// Currently valid delegates as pairs of delegate type and delegate.
+	pub delegates: Vec<(Vec<u8>, AccountId)>,
+	/// Service endpoints.
+	pub services: Vec<DidService>,
+}
 
 sp_api::decl_runtime_apis! {
 	pub trait DidApi<AccountId> 
@@ -11,5 +35,20 @@ sp_api::decl_runtime_apis! {
 		/// this returns:
 		/// owner of the object id `AccountId`.
 		fn get_owner(id: AccountId) -> Option<AccountId>;
+
+		/// Get DID document of the identity `id`, assembled from its
+		/// owner, valid delegates and services.
+		fn get_document(id: AccountId) -> DidDocument<AccountId>;
+
+		/// Whether `delegate` is the owner or a valid delegate of type `delegate_type`
+		/// of the identity `identity` at the current block.
+		fn is_valid_delegate(
+			identity: AccountId,
+			delegate_type: Vec<u8>,
+			delegate: AccountId,
+		) -> bool;
+
+		/// Whether the identity `identity` has an attribute `name` valid at the current block.
+		fn has_valid_attribute(identity: AccountId, name: Vec<u8>) -> bool;
 	}
 }
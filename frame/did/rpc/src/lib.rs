@@ -5,6 +5,7 @@ use jsonrpsee::{
 };
 use sc_client_api::{BlockBackend, HeaderBackend};
 use sc_rpc_api::DenyUnsafe;
+use serde::{Deserialize, Serialize};
 use sp_api::{ProvideRuntimeApi, BlockId};
 use sp_runtime::traits::Block as BlockT;
 use std::{
@@ -19,6 +20,79 @@ pub trait DidApi<BlockHash, AccountId> {
 	/// owner of the object id `AccountId`.
 	#[method(name = "did_getOwner")]
 	fn get_owner(&self, id: AccountId) -> RpcResult<Option<AccountId>>;
+
+	/// Get W3C style DID document of the identity `AccountId`.
+	#[method(name = "did_getDocument")]
+	fn get_document(&self, id: AccountId) -> RpcResult<DidDocument<AccountId>>;
+
+	/// Check whether `delegate` is the owner or a valid delegate of type `delegate_type`
+	/// of the identity `identity` at the best block.
+	#[method(name = "did_isValidDelegate")]
+	fn is_valid_delegate(
+		&self,
+		identity: AccountId,
+		delegate_type: String,
+		delegate: AccountId,
+	) -> RpcResult<bool>;
+
+	/// Check whether the identity `identity` has an attribute `name` valid at the best block.
+	#[method(name = "did_hasValidAttribute")]
+	fn has_valid_attribute(&self, identity: AccountId, name: String) -> RpcResult<bool>;
+}
+
+/// Delegate entry of a DID document.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidDelegate<AccountId> {
+	pub delegate_type: String,
+	pub delegate: AccountId,
+}
+
+/// Service entry of a DID document.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidService {
+	pub id: String,
+	#[serde(rename = "type")]
+	pub service_type: String,
+	pub service_endpoint: String,
+}
+
+/// DID document as served over RPC.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidDocument<AccountId> {
+	pub id: AccountId,
+	pub controller: AccountId,
+	pub delegates: Vec<DidDelegate<AccountId>>,
+	pub service: Vec<DidService>,
+}
+
+impl<AccountId> From<pallet_did_runtime_api::DidDocument<AccountId>> for DidDocument<AccountId> {
+	fn from(doc: pallet_did_runtime_api::DidDocument<AccountId>) -> Self {
+		let text = |b: Vec<u8>| String::from_utf8_lossy(&b).into_owned();
+		Self {
+			id: doc.id,
+			controller: doc.owner,
+			delegates: doc
+				.delegates
+				.into_iter()
+				.map(|(delegate_type, delegate)| DidDelegate {
+					delegate_type: text(delegate_type),
+					delegate,
+				})
+				.collect(),
+			service: doc
+				.services
+				.into_iter()
+				.map(|s| DidService {
+					id: text(s.id),
+					service_type: text(s.service_type),
+					service_endpoint: text(s.endpoint),
+				})
+				.collect(),
+		}
+	}
 }
 
 pub struct Did<Block: BlockT, Client> {
@@ -47,7 +121,7 @@ where
 		+ Send
 		+ Sync
 		+ 'static,
-    AccountId: Codec + Send + Sync + Clone,
+    AccountId: Codec + Send + Sync + Clone + Serialize + for<'de> Deserialize<'de>,
     Client::Api: pallet_did_runtime_api::DidApi<Block, AccountId>,
 {
 	fn get_owner(&self, id: AccountId) -> RpcResult<Option<AccountId>> {
@@ -61,6 +135,39 @@ where
             Ok(r) => Ok(r),
         }
 	}
+
+	fn get_document(&self, id: AccountId) -> RpcResult<DidDocument<AccountId>> {
+		self.deny_unsafe.check_if_safe()?;
+		let api = self.client.runtime_api();
+		let block_id = BlockId::hash(self.client.info().best_hash);
+
+		api.get_document(&block_id, id)
+			.map(Into::into)
+			.map_err(JsonRpseeError::to_call_error)
+	}
+
+	fn is_valid_delegate(
+		&self,
+		identity: AccountId,
+		delegate_type: String,
+		delegate: AccountId,
+	) -> RpcResult<bool> {
+		self.deny_unsafe.check_if_safe()?;
+		let api = self.client.runtime_api();
+		let block_id = BlockId::hash(self.client.info().best_hash);
+
+		api.is_valid_delegate(&block_id, identity, delegate_type.into_bytes(), delegate)
+			.map_err(JsonRpseeError::to_call_error)
+	}
+
+	fn has_valid_attribute(&self, identity: AccountId, name: String) -> RpcResult<bool> {
+		self.deny_unsafe.check_if_safe()?;
+		let api = self.client.runtime_api();
+		let block_id = BlockId::hash(self.client.info().best_hash);
+
+		api.has_valid_attribute(&block_id, identity, name.into_bytes())
+			.map_err(JsonRpseeError::to_call_error)
+	}
 }
 
 #[cfg(test)]
@@ -22,7 +22,7 @@ fn assert_last_event<T: Config>(generic_event: <T as Config>::Event) {
 macro_rules! make_caller {
     ($T: ident) => {{
         let caller:$T::AccountId = whitelisted_caller();
-        // let _ = $T::Currency::make_free_balance_be(&caller, BalanceOf::<$T>::max_value());
+        let _ = $T::Currency::make_free_balance_be(&caller, BalanceOf::<$T>::max_value());
         caller
     }}
 }
@@ -45,6 +45,19 @@ benchmarks! {
         let _ = Did::<T>::add_delegate(RawOrigin::Signed(caller.clone()).into(), caller.clone(), delegate.clone(), Vec::new(), None);
     }: _(RawOrigin::Signed(caller.clone()), caller.clone(), Vec::new(), delegate.clone())
 
+    revoke_all_delegates {
+        let d in 1 .. 50;
+        let caller = make_caller!(T);
+        let delegate_type = b"OrgAdmin".to_vec();
+        for i in 0 .. d {
+            let delegate:T::AccountId = account("delegate", i, 0);
+            let _ = Did::<T>::add_delegate(RawOrigin::Signed(caller.clone()).into(), caller.clone(), delegate, delegate_type.clone(), None);
+        }
+    }: _(RawOrigin::Signed(caller.clone()), caller.clone(), delegate_type.clone(), d)
+    verify {
+        assert!(Did::<T>::delegates_of(&caller, &delegate_type).is_empty());
+    }
+
     add_attribute {
         let caller = make_caller!(T);
         let name = b"name1".to_vec();
@@ -64,4 +77,15 @@ benchmarks! {
         let value = b"value1".to_vec();
         let _ = Did::<T>::add_attribute(RawOrigin::Signed(caller.clone()).into(), caller.clone(), name.clone(), value.clone(), None);
     }: _(RawOrigin::Signed(caller.clone()), caller.clone(), name.clone())
+
+    add_service {
+        let caller = make_caller!(T);
+        let id = b"#linked-domain".to_vec();
+    }: _(RawOrigin::Signed(caller.clone()), caller.clone(), id.clone(), b"LinkedDomains".to_vec(), b"https://nuchain.network".to_vec())
+
+    remove_service {
+        let caller = make_caller!(T);
+        let id = b"#linked-domain".to_vec();
+        let _ = Did::<T>::add_service(RawOrigin::Signed(caller.clone()).into(), caller.clone(), id.clone(), b"LinkedDomains".to_vec(), b"https://nuchain.network".to_vec());
+    }: _(RawOrigin::Signed(caller.clone()), caller.clone(), id.clone())
 }
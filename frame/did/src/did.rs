@@ -1,6 +1,6 @@
 use crate::types::AttributedId;
 
-use frame_support::dispatch::DispatchResult;
+use frame_support::dispatch::{DispatchError, DispatchResult};
 use scale_info::prelude::vec::Vec;
 
 pub trait Did<AccountId, BlockNumber, Moment, Signature, BoundedString> {
@@ -30,6 +30,10 @@ pub trait Did<AccountId, BlockNumber, Moment, Signature, BoundedString> {
 		delegate_type: &Vec<u8>,
 		delegate: &AccountId,
 	) -> DispatchResult;
+    /// Removes the owner, attributes, services and listed delegates of `identity`
+    /// and unreserves their deposits, fails if more than `max_delegates` delegates
+    /// are listed. Returns the number of delegates removed.
+    fn remove_identity(identity: &AccountId, max_delegates: u32) -> Result<u32, DispatchError>;
     fn check_signature(signature: &Signature, msg: &Vec<u8>, signer: &AccountId) -> DispatchResult;
     fn valid_signer(
         identity: &AccountId,
@@ -37,8 +41,11 @@ pub trait Did<AccountId, BlockNumber, Moment, Signature, BoundedString> {
         msg: &Vec<u8>,
         signer: &AccountId,
     ) -> DispatchResult;
+    /// Adds attribute `name` to `identity` owned by `who`,
+    /// the attribute deposit is reserved from `depositor`.
     fn create_attribute(
         who: &AccountId,
+        depositor: &AccountId,
         identity: &AccountId,
         name: &Vec<u8>,
         value: &Vec<u8>,
@@ -13,26 +13,41 @@
 //! * `create_delegate` -
 //! * `valid_delegate` -
 //! * `is_owner` -
+//! * `revoke_all_delegates` - Revoke every delegate of a delegate type of an identity.
+//! * `add_service` - Add service endpoint to the DID document of an identity.
+//! * `remove_service` - Remove service endpoint from the DID document of an identity.
+//!
+//! ## Deposits
+//!
+//! Adding an attribute reserves `AttributeDeposit` and adding a delegate reserves
+//! `DelegateDeposit` from the caller. The deposit is unreserved back to the caller when the
+//! attribute is deleted or the delegate is revoked. A zero deposit disables the reserve.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use frame_support::{dispatch::DispatchResult, ensure, traits::UnixTime, BoundedVec};
+use frame_support::{
+	dispatch::{DispatchError, DispatchResult},
+	ensure,
+	traits::{Currency, ReservableCurrency, StorageVersion, UnixTime},
+	BoundedVec,
+};
 use frame_system::ensure_signed;
 pub use pallet::*;
 use sp_io::hashing::blake2_256;
-use sp_runtime::traits::{IdentifyAccount, SaturatedConversion, Verify};
+use sp_runtime::traits::{IdentifyAccount, SaturatedConversion, Verify, Zero};
 use sp_std::prelude::*;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
 // use crate::did::Did;
-use crate::types::{Attribute, AttributeTransaction, AttributedId};
+use crate::types::{Attribute, AttributeTransaction, AttributedId, Service};
 use codec::{Decode, Encode};
 pub use did::Did;
 pub use weights::WeightInfo;
 
 mod did;
+pub mod migrations;
 mod types;
 pub mod weights;
 
@@ -42,6 +57,9 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+/// The current storage version.
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
 macro_rules! to_bounded {
 	(*$name:ident, $error:expr) => {
 		let $name: BoundedVec<_, _> = $name.clone().try_into().map_err(|()| $error)?;
@@ -60,6 +78,7 @@ pub mod pallet {
 
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
@@ -80,8 +99,50 @@ pub mod pallet {
 		/// The maximum length a name may be.
 		#[pallet::constant]
 		type MaxLength: Get<u32>;
+
+		/// The maximum length of a delegate type, also bounds the stored delegate types.
+		#[pallet::constant]
+		type MaxDelegateTypeLength: Get<u32>;
+
+		/// The maximum length of an attribute name, must not exceed `MaxLength`.
+		#[pallet::constant]
+		type MaxAttributeNameLength: Get<u32>;
+
+		/// The maximum length of a service id.
+		#[pallet::constant]
+		type MaxServiceIdLength: Get<u32>;
+
+		/// The maximum length of a service type.
+		#[pallet::constant]
+		type MaxServiceTypeLength: Get<u32>;
+
+		/// The maximum length of a service endpoint.
+		#[pallet::constant]
+		type MaxServiceEndpointLength: Get<u32>;
+
+		/// The maximum number of services of an identity.
+		#[pallet::constant]
+		type MaxServicePerDid: Get<u32>;
+
+		/// The maximum number of attributes of an identity.
+		#[pallet::constant]
+		type MaxAttributes: Get<u32>;
+
+		/// The currency in which attribute and delegate deposits are reserved.
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// Deposit reserved for an attribute until it is deleted, zero disables the deposit.
+		#[pallet::constant]
+		type AttributeDeposit: Get<BalanceOf<Self>>;
+
+		/// Deposit reserved for a delegate until it is revoked, zero disables the deposit.
+		#[pallet::constant]
+		type DelegateDeposit: Get<BalanceOf<Self>>;
 	}
 
+	pub(crate) type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
 	#[pallet::error]
 	pub enum Error<T> {
 		NotOwner,
@@ -99,6 +160,14 @@ pub mod pallet {
 		Overflow,
 		BadTransaction,
 		TransactionNameTooLong,
+		ServiceIdTooLong,
+		ServiceTypeTooLong,
+		ServiceEndpointTooLong,
+		TooManyServices,
+		ServiceAlreadyExists,
+		ServiceNotFound,
+		TooManyDelegates,
+		TooManyAttributes,
 	}
 
 	#[pallet::event]
@@ -115,6 +184,9 @@ pub mod pallet {
 		AttributeTransactionExecuted(
 			AttributeTransaction<T::Signature, T::AccountId, BoundedVec<u8, T::MaxLength>>,
 		),
+		ServiceAdded(T::AccountId, Vec<u8>),
+		ServiceRemoved(T::AccountId, Vec<u8>),
+		IdentityRemoved(T::AccountId),
 	}
 
 	/// Delegates are only valid for a specific period defined as blocks number.
@@ -123,10 +195,25 @@ pub mod pallet {
 	pub type DelegateOf<T: Config> = StorageMap<
 		_,
 		Blake2_128Concat,
-		(T::AccountId, BoundedVec<u8, T::MaxLength>, T::AccountId),
+		(T::AccountId, BoundedVec<u8, T::MaxDelegateTypeLength>, T::AccountId),
 		T::BlockNumber,
 	>;
 
+	/// Index of delegates by identity and delegate type, identity -> delegate type -> delegate.
+	///
+	/// Revoked delegates are removed, expired ones are kept until revoked,
+	/// use [`Pallet::delegates_of`] to get the valid ones.
+	#[pallet::storage]
+	pub type DelegatesOf<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::AccountId>,
+			NMapKey<Blake2_128Concat, BoundedVec<u8, T::MaxDelegateTypeLength>>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+		),
+		(),
+	>;
+
 	// Attributes are only valid for a specific period defined as blocks number.
 	#[pallet::storage]
 	#[pallet::getter(fn attribute_of)]
@@ -144,6 +231,42 @@ pub mod pallet {
 	pub type AttributeNonce<T: Config> =
 		StorageMap<_, Twox64Concat, (T::AccountId, BoundedVec<u8, T::MaxLength>), u64>;
 
+	/// Names of the attributes of an identity, used to list attributes without knowing
+	/// the names, see [`Pallet::attributes_of`].
+	#[pallet::storage]
+	#[pallet::getter(fn attribute_names_of)]
+	pub type AttributeNamesOf<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<BoundedVec<u8, T::MaxLength>, T::MaxAttributes>,
+		ValueQuery,
+	>;
+
+	/// Deposit reserved for an attribute, (identity, name) -> (depositor, amount).
+	///
+	/// Attributes created while the deposit was disabled have no entry.
+	#[pallet::storage]
+	#[pallet::getter(fn attribute_deposit)]
+	pub type AttributeDeposits<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		(T::AccountId, BoundedVec<u8, T::MaxLength>),
+		(T::AccountId, BalanceOf<T>),
+	>;
+
+	/// Deposit reserved for a delegate, (identity, delegate type, delegate) -> (depositor, amount).
+	///
+	/// Delegates created while the deposit was disabled have no entry.
+	#[pallet::storage]
+	#[pallet::getter(fn delegate_deposit)]
+	pub type DelegateDeposits<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		(T::AccountId, BoundedVec<u8, T::MaxDelegateTypeLength>, T::AccountId),
+		(T::AccountId, BalanceOf<T>),
+	>;
+
 	/// Identity owner.
 	#[pallet::storage]
 	#[pallet::getter(fn owner_of)]
@@ -155,6 +278,17 @@ pub mod pallet {
 	pub type UpdatedBy<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AccountId, (T::AccountId, T::BlockNumber, u64)>;
 
+	/// Service endpoints of the identity DID document.
+	#[pallet::storage]
+	#[pallet::getter(fn services_of)]
+	pub type ServicesOf<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<ServiceOf<T>, T::MaxServicePerDid>,
+		ValueQuery,
+	>;
+
 	/// Did module declaration.
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -173,7 +307,7 @@ pub mod pallet {
 			valid_for: Option<T::BlockNumber>,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
-			ensure!(delegate_type.len() <= 64, Error::<T>::InvalidDelegate);
+			ensure!(Self::valid_delegate_type_len(&delegate_type), Error::<T>::InvalidDelegate);
 
 			Self::create_delegate(&who, &identity, &delegate, &delegate_type, valid_for)?;
 
@@ -201,6 +335,8 @@ pub mod pallet {
 		}
 
 		/// Revokes an identity's delegate by setting its expiration to the current block number.
+		///
+		/// Expired delegates can be revoked as well, releasing their deposit.
 		#[pallet::weight(T::WeightInfo::revoke_delegate())]
 		pub fn revoke_delegate(
 			origin: OriginFor<T>,
@@ -210,8 +346,7 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 			Self::is_owner(&identity, &who)?;
-			Self::valid_listed_delegate(&identity, &delegate_type, &delegate)?;
-			ensure!(delegate_type.len() <= 64, Error::<T>::InvalidDelegate);
+			Self::listed_delegate(&identity, &delegate_type, &delegate)?;
 
 			Self::revoke_delegate_nocheck(&who, &identity, &delegate_type, &delegate)?;
 
@@ -219,6 +354,39 @@ pub mod pallet {
 			Ok(().into())
 		}
 
+		/// Revokes all delegates of `delegate_type` of an identity.
+		///
+		/// `max_delegates` is the witness of the number of delegates listed for the type,
+		/// see [`Pallet::delegates_of`], the call fails if there are more.
+		#[pallet::weight(T::WeightInfo::revoke_all_delegates(*max_delegates))]
+		pub fn revoke_all_delegates(
+			origin: OriginFor<T>,
+			identity: T::AccountId,
+			delegate_type: Vec<u8>,
+			max_delegates: u32,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::is_owner(&identity, &who)?;
+			ensure!(Self::valid_delegate_type_len(&delegate_type), Error::<T>::InvalidDelegate);
+
+			let bounded_type: BoundedVec<u8, T::MaxDelegateTypeLength> =
+				delegate_type.clone().try_into().map_err(|()| Error::<T>::DelegateTypeTooLong)?;
+			let delegates: Vec<T::AccountId> =
+				<DelegatesOf<T>>::iter_key_prefix((&identity, bounded_type)).collect();
+			ensure!(delegates.len() as u32 <= max_delegates, Error::<T>::TooManyDelegates);
+
+			for delegate in delegates.iter() {
+				Self::revoke_delegate_nocheck(&who, &identity, &delegate_type, delegate)?;
+				Self::deposit_event(Event::DelegateRevoked(
+					identity.clone(),
+					delegate_type.clone(),
+					delegate.clone(),
+				));
+			}
+
+			Ok(Some(T::WeightInfo::revoke_all_delegates(delegates.len() as u32)).into())
+		}
+
 		/// Creates a new attribute as part of an identity.
 		/// Sets its expiration period.
 		#[pallet::weight(T::WeightInfo::add_attribute())]
@@ -230,9 +398,9 @@ pub mod pallet {
 			valid_for: Option<T::BlockNumber>,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
-			ensure!(name.len() <= 64, Error::<T>::AttributeNameTooLong);
+			ensure!(Self::valid_attribute_name_len(&name), Error::<T>::AttributeNameTooLong);
 
-			Self::create_attribute(&who, &identity, &name, &value, valid_for)?;
+			Self::create_attribute(&who, &who, &identity, &name, &value, valid_for)?;
 			Self::deposit_event(Event::AttributeAdded(identity, name, valid_for));
 			Ok(().into())
 		}
@@ -246,7 +414,7 @@ pub mod pallet {
 			name: Vec<u8>,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
-			ensure!(name.len() <= 64, Error::<T>::AttributeRemovalFailed);
+			ensure!(Self::valid_attribute_name_len(&name), Error::<T>::AttributeRemovalFailed);
 
             to_bounded!(name, Error::<T>::AttributeNameTooLong);
 
@@ -268,7 +436,7 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 			Self::is_owner(&identity, &who)?;
-			ensure!(name.len() <= 64, Error::<T>::AttributeRemovalFailed);
+			ensure!(Self::valid_attribute_name_len(&name), Error::<T>::AttributeRemovalFailed);
 
             to_bounded!(name, Error::<T>::AttributeNameTooLong);
 
@@ -279,6 +447,10 @@ pub mod pallet {
 				Some((_, id)) => <AttributeOf<T>>::remove((&identity, &id)),
 				None => return Err(Error::<T>::AttributeRemovalFailed.into()),
 			}
+			<AttributeNamesOf<T>>::mutate(&identity, |names| names.retain(|n| n != &name));
+			if let Some((depositor, deposit)) = <AttributeDeposits<T>>::take((&identity, &name)) {
+				T::Currency::unreserve(&depositor, deposit);
+			}
 
 			let now = T::Time::now().as_millis().saturated_into::<u64>();
 
@@ -310,6 +482,75 @@ pub mod pallet {
 			Self::deposit_event(Event::AttributeTransactionExecuted(transaction));
 			Ok(().into())
 		}
+
+		/// Adds a service endpoint to the DID document of an identity,
+		/// `id` must be unique within the identity.
+		#[pallet::weight(T::WeightInfo::add_service())]
+		pub fn add_service(
+			origin: OriginFor<T>,
+			identity: T::AccountId,
+			id: Vec<u8>,
+			service_type: Vec<u8>,
+			endpoint: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::is_owner(&identity, &who)?;
+
+			let service = Service {
+				id: id.clone().try_into().map_err(|()| Error::<T>::ServiceIdTooLong)?,
+				service_type: service_type
+					.try_into()
+					.map_err(|()| Error::<T>::ServiceTypeTooLong)?,
+				endpoint: endpoint.try_into().map_err(|()| Error::<T>::ServiceEndpointTooLong)?,
+			};
+
+			<ServicesOf<T>>::try_mutate(&identity, |services| -> DispatchResult {
+				ensure!(
+					!services.iter().any(|s| s.id == service.id),
+					Error::<T>::ServiceAlreadyExists
+				);
+				services.try_push(service).map_err(|_| Error::<T>::TooManyServices)?;
+				Ok(())
+			})?;
+
+			let now_timestamp = T::Time::now().as_millis().saturated_into::<u64>();
+			let now_block_number = <frame_system::Pallet<T>>::block_number();
+			<UpdatedBy<T>>::insert(&identity, (who, now_block_number, now_timestamp));
+
+			Self::deposit_event(Event::ServiceAdded(identity, id));
+			Ok(().into())
+		}
+
+		/// Removes a service endpoint from the DID document of an identity.
+		#[pallet::weight(T::WeightInfo::remove_service())]
+		pub fn remove_service(
+			origin: OriginFor<T>,
+			identity: T::AccountId,
+			id: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::is_owner(&identity, &who)?;
+
+			<ServicesOf<T>>::try_mutate_exists(&identity, |maybe_services| -> DispatchResult {
+				let services = maybe_services.as_mut().ok_or(Error::<T>::ServiceNotFound)?;
+				let index = services
+					.iter()
+					.position(|s| s.id[..] == id[..])
+					.ok_or(Error::<T>::ServiceNotFound)?;
+				services.remove(index);
+				if services.is_empty() {
+					*maybe_services = None;
+				}
+				Ok(())
+			})?;
+
+			let now_timestamp = T::Time::now().as_millis().saturated_into::<u64>();
+			let now_block_number = <frame_system::Pallet<T>>::block_number();
+			<UpdatedBy<T>>::insert(&identity, (who, now_block_number, now_timestamp));
+
+			Self::deposit_event(Event::ServiceRemoved(identity, id));
+			Ok(().into())
+		}
 	}
 
 	// ----------------------------------------------------------------
@@ -317,6 +558,12 @@ pub mod pallet {
 	// ----------------------------------------------------------------
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn integrity_test() {
+			// Attribute names are stored bounded by `MaxLength`,
+			// a longer limit would pass validation and fail the conversion.
+			assert!(T::MaxAttributeNameLength::get() <= T::MaxLength::get());
+		}
+
 		// fn offchain_worker(n: T::BlockNumber){
 		//     // @TODO(you): Your off-chain logic here
 		// }
@@ -347,8 +594,101 @@ pub mod pallet {
 	}
 }
 
+/// Service endpoint of a DID document, bounded by the pallet config.
+pub type ServiceOf<T> = Service<
+	BoundedVec<u8, <T as Config>::MaxServiceIdLength>,
+	BoundedVec<u8, <T as Config>::MaxServiceTypeLength>,
+	BoundedVec<u8, <T as Config>::MaxServiceEndpointLength>,
+>;
+
 /// The main implementation of this Did pallet.
 impl<T: Config> Pallet<T> {
+	/// Delegates of the identity for the delegate type that are valid at the current block,
+	/// with the block number until which they are valid.
+	pub fn delegates_of(
+		identity: &T::AccountId,
+		delegate_type: &[u8],
+	) -> Vec<(T::AccountId, T::BlockNumber)> {
+		let delegate_type: BoundedVec<u8, T::MaxDelegateTypeLength> =
+			match delegate_type.to_vec().try_into() {
+				Ok(delegate_type) => delegate_type,
+				Err(_) => return Vec::new(),
+			};
+		let now = <frame_system::Pallet<T>>::block_number();
+		<DelegatesOf<T>>::iter_key_prefix((identity, &delegate_type))
+			.filter_map(|delegate| {
+				Self::delegate_of((identity, &delegate_type, &delegate))
+					.filter(|validity| *validity > now)
+					.map(|validity| (delegate, validity))
+			})
+			.collect()
+	}
+
+	/// Delegates of the identity that are valid at the current block,
+	/// as pairs of delegate type and delegate.
+	pub fn valid_delegates_of(identity: &T::AccountId) -> Vec<(Vec<u8>, T::AccountId)> {
+		let now = <frame_system::Pallet<T>>::block_number();
+		<DelegatesOf<T>>::iter_key_prefix((identity,))
+			.filter(|(delegate_type, delegate)| {
+				Self::delegate_of((identity, delegate_type, delegate))
+					.map_or(false, |validity| validity > now)
+			})
+			.map(|(delegate_type, delegate)| (delegate_type.into_inner(), delegate))
+			.collect()
+	}
+
+	/// Attributes of the identity with their names, including expired and revoked ones.
+	pub fn attributes_of(
+		identity: &T::AccountId,
+	) -> Vec<(Vec<u8>, Attribute<T::BlockNumber, BoundedVec<u8, T::MaxLength>>)> {
+		Self::attribute_names_of(identity)
+			.into_iter()
+			.filter_map(|name| {
+				Self::attribute_and_id(identity, &name)
+					.map(|(attribute, _)| (name.into_inner(), attribute))
+			})
+			.collect()
+	}
+
+	/// Whether the identity has an attribute `name` that is valid at the current block,
+	/// regardless of its value.
+	pub fn has_valid_attribute(identity: &T::AccountId, name: &[u8]) -> bool {
+		if !Self::valid_attribute_name_len(name) {
+			return false
+		}
+		let name: BoundedVec<u8, T::MaxLength> = match name.to_vec().try_into() {
+			Ok(name) => name,
+			Err(_) => return false,
+		};
+		Self::attribute_and_id(identity, &name)
+			.map(|(attribute, _)| attribute.validity > <frame_system::Pallet<T>>::block_number())
+			.unwrap_or(false)
+	}
+
+	fn valid_delegate_type_len(delegate_type: &[u8]) -> bool {
+		delegate_type.len() <= T::MaxDelegateTypeLength::get() as usize
+	}
+
+	fn valid_attribute_name_len(name: &[u8]) -> bool {
+		name.len() <= T::MaxAttributeNameLength::get() as usize
+	}
+
+	/// Validates that a delegate is listed for specific purpose, whether still valid or expired.
+	fn listed_delegate(
+		identity: &T::AccountId,
+		delegate_type: &Vec<u8>,
+		delegate: &T::AccountId,
+	) -> DispatchResult {
+		ensure!(Self::valid_delegate_type_len(delegate_type), Error::<T>::InvalidDelegate);
+		to_bounded!(*delegate_type, Error::<T>::DelegateTypeTooLong);
+
+		ensure!(
+			<DelegatesOf<T>>::contains_key((identity, &delegate_type, delegate)),
+			Error::<T>::InvalidDelegate
+		);
+		Ok(())
+	}
+
 	/// Get nonce for _identity_ and _name_.
 	fn get_nonce(identity: &T::AccountId, name: &BoundedVec<u8, T::MaxLength>) -> u64 {
 		match Self::nonce_of((&identity, name)) {
@@ -374,7 +714,10 @@ impl<T: Config> Pallet<T> {
 			&transaction.signer,
 		)?;
 		Self::is_owner(&transaction.identity, &transaction.signer)?;
-		ensure!(transaction.name.len() <= 64, Error::<T>::TransactionNameTooLong);
+		ensure!(
+			Self::valid_attribute_name_len(&transaction.name),
+			Error::<T>::TransactionNameTooLong
+		);
 
 		let now_block_number = <frame_system::Pallet<T>>::block_number();
 		let validity = now_block_number + transaction.validity.into();
@@ -383,6 +726,7 @@ impl<T: Config> Pallet<T> {
 		// it will set the attribute latest valid block to the actual block.
 		if validity > now_block_number {
 			Self::create_attribute(
+				&who,
 				&who,
 				&transaction.identity,
 				&transaction.name,
@@ -451,7 +795,7 @@ impl<T: Config>
 		delegate_type: &Vec<u8>,
 		delegate: &T::AccountId,
 	) -> DispatchResult {
-		ensure!(delegate_type.len() <= 64, Error::<T>::InvalidDelegate);
+		ensure!(Self::valid_delegate_type_len(&delegate_type), Error::<T>::InvalidDelegate);
 		ensure!(
 			Self::valid_listed_delegate(identity, delegate_type, delegate).is_ok() ||
 				Self::is_owner(identity, delegate).is_ok(),
@@ -467,6 +811,7 @@ impl<T: Config>
 		delegate_type: &Vec<u8>,
 		delegate: &T::AccountId,
 	) -> DispatchResult {
+		ensure!(Self::valid_delegate_type_len(delegate_type), Error::<T>::InvalidDelegate);
 		to_bounded!(*delegate_type, Error::<T>::DelegateTypeTooLong);
 
 		ensure!(
@@ -504,7 +849,20 @@ impl<T: Config>
 
 		to_bounded!(*delegate_type, Error::<T>::DelegateTypeTooLong);
 
-		<DelegateOf<T>>::insert((&identity, delegate_type, delegate), &validity);
+		// An expired delegate added again keeps its deposit.
+		let deposit = T::DelegateDeposit::get();
+		if !deposit.is_zero() &&
+			!<DelegateDeposits<T>>::contains_key((identity, &delegate_type, delegate))
+		{
+			T::Currency::reserve(who, deposit)?;
+			<DelegateDeposits<T>>::insert(
+				(identity, &delegate_type, delegate),
+				(who.clone(), deposit),
+			);
+		}
+
+		<DelegateOf<T>>::insert((&identity, &delegate_type, delegate), &validity);
+		<DelegatesOf<T>>::insert((identity, &delegate_type, delegate), ());
 		Ok(())
 	}
 
@@ -521,14 +879,53 @@ impl<T: Config>
 		to_bounded!(*delegate_type, Error::<T>::DelegateTypeTooLong);
 
 		// Update only the validity period to revoke the delegate.
-		<DelegateOf<T>>::mutate((&identity, delegate_type, &delegate), |b| {
+		<DelegateOf<T>>::mutate((&identity, &delegate_type, &delegate), |b| {
 			*b = Some(now_block_number)
 		});
+		if let Some((depositor, deposit)) =
+			<DelegateDeposits<T>>::take((identity, &delegate_type, delegate))
+		{
+			T::Currency::unreserve(&depositor, deposit);
+		}
+		<DelegatesOf<T>>::remove((identity, &delegate_type, delegate));
 		<UpdatedBy<T>>::insert(&identity, (who, now_block_number, now_timestamp));
 
 		Ok(())
 	}
 
+	/// Removes the DID state of an identity, the attribute nonces are kept
+	/// so a recreated attribute still gets a new identifier.
+	fn remove_identity(identity: &T::AccountId, max_delegates: u32) -> Result<u32, DispatchError> {
+		let delegates: Vec<(BoundedVec<u8, T::MaxDelegateTypeLength>, T::AccountId)> =
+			<DelegatesOf<T>>::iter_key_prefix((identity,)).collect();
+		ensure!(delegates.len() as u32 <= max_delegates, Error::<T>::TooManyDelegates);
+
+		for (delegate_type, delegate) in delegates.iter() {
+			<DelegateOf<T>>::remove((identity, delegate_type, delegate));
+			<DelegatesOf<T>>::remove((identity, delegate_type, delegate));
+			if let Some((depositor, deposit)) =
+				<DelegateDeposits<T>>::take((identity, delegate_type, delegate))
+			{
+				T::Currency::unreserve(&depositor, deposit);
+			}
+		}
+
+		for name in <AttributeNamesOf<T>>::take(identity) {
+			if let Some((_, id)) = Self::attribute_and_id(identity, &name) {
+				<AttributeOf<T>>::remove((identity, id));
+			}
+			if let Some((depositor, deposit)) = <AttributeDeposits<T>>::take((identity, &name)) {
+				T::Currency::unreserve(&depositor, deposit);
+			}
+		}
+
+		<ServicesOf<T>>::remove(identity);
+		<OwnerOf<T>>::remove(identity);
+		<UpdatedBy<T>>::remove(identity);
+
+		Self::deposit_event(Event::IdentityRemoved(identity.clone()));
+		Ok(delegates.len() as u32)
+	}
 
 	/// Checks if a signature is valid. Used to validate off-chain transactions.
 	fn check_signature(
@@ -555,9 +952,10 @@ impl<T: Config>
 		Self::check_signature(&signature, &msg, &signer)
 	}
 
-	/// Adds a new attribute to an identity and colects the storage fee.
+	/// Adds a new attribute to an identity and reserves the attribute deposit from `depositor`.
 	fn create_attribute(
 		who: &T::AccountId,
+		depositor: &T::AccountId,
 		identity: &T::AccountId,
 		name: &Vec<u8>,
 		value: &Vec<u8>,
@@ -594,6 +992,26 @@ impl<T: Config>
 
 			// Prevent panic overflow
 			nonce = nonce.checked_add(1).ok_or(Error::<T>::Overflow)?;
+
+			// Deleted attributes are removed from the names, a recreated one is added once.
+			<AttributeNamesOf<T>>::try_mutate(identity, |names| -> DispatchResult {
+				if !names.contains(&bounded_name) {
+					names
+						.try_push(bounded_name.clone())
+						.map_err(|_| Error::<T>::TooManyAttributes)?;
+				}
+				Ok(())
+			})?;
+
+			let deposit = T::AttributeDeposit::get();
+			if !deposit.is_zero() {
+				T::Currency::reserve(depositor, deposit)?;
+				<AttributeDeposits<T>>::insert(
+					(identity, &bounded_name),
+					(depositor.clone(), deposit),
+				);
+			}
+
 			<AttributeOf<T>>::insert((identity, &id), new_attribute);
 
 			// update nonce
@@ -635,7 +1053,7 @@ impl<T: Config>
 
 	/// Validates if an attribute belongs to an identity and it has not expired.
 	fn valid_attribute(identity: &T::AccountId, name: &BoundedVec<u8, T::MaxLength>, value: &BoundedVec<u8, T::MaxLength>) -> DispatchResult {
-		ensure!(name.len() <= 64, Error::<T>::InvalidAttribute);
+		ensure!(Self::valid_attribute_name_len(name), Error::<T>::InvalidAttribute);
 		let result = Self::attribute_and_id(identity, name);
 
 		let (attr, _) = match result {
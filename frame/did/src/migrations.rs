@@ -0,0 +1,116 @@
+// This file is part of Nuchain.
+//
+// Copyright (C) 2021-2022 Rantai Nusantara Foundation..
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Storage migrations for the did pallet.
+
+use super::*;
+use frame_support::{
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
+
+pub mod v1 {
+	use super::*;
+
+	/// Build the `DelegatesOf` index from existing `DelegateOf` entries.
+	///
+	/// Only delegates valid after the current block are indexed, revoked delegates keep
+	/// their `DelegateOf` entry with the revocation block as validity and are skipped.
+	pub struct MigrateToV1<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain = Pallet::<T>::on_chain_storage_version();
+			if onchain >= 1 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			let mut reads: Weight = 2;
+			let mut indexed: Weight = 0;
+			for ((identity, delegate_type, delegate), validity) in <DelegateOf<T>>::iter() {
+				reads = reads.saturating_add(1);
+				if validity > now {
+					indexed = indexed.saturating_add(1);
+					<DelegatesOf<T>>::insert((identity, delegate_type, delegate), ());
+				}
+			}
+
+			StorageVersion::new(1).put::<Pallet<T>>();
+			T::DbWeight::get().reads_writes(reads, indexed.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			let now = <frame_system::Pallet<T>>::block_number();
+			ensure!(
+				<DelegatesOf<T>>::iter_keys().count() ==
+					<DelegateOf<T>>::iter_values().filter(|validity| *validity > now).count(),
+				"delegates not indexed by migration"
+			);
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 1,
+				"storage version not updated by migration"
+			);
+			Ok(())
+		}
+	}
+}
+
+pub mod v2 {
+	use super::*;
+
+	/// Build the `AttributeNamesOf` index from existing `AttributeOf` entries.
+	///
+	/// Names beyond `MaxAttributes` of an identity are not indexed.
+	pub struct MigrateToV2<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain = Pallet::<T>::on_chain_storage_version();
+			if onchain >= 2 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut indexed: Weight = 0;
+			for ((identity, _), attribute) in <AttributeOf<T>>::iter() {
+				indexed = indexed.saturating_add(1);
+				<AttributeNamesOf<T>>::mutate(&identity, |names| {
+					if !names.contains(&attribute.name) {
+						let _ = names.try_push(attribute.name);
+					}
+				});
+			}
+
+			StorageVersion::new(2).put::<Pallet<T>>();
+			T::DbWeight::get().reads_writes(
+				indexed.saturating_mul(2).saturating_add(1),
+				indexed.saturating_add(1),
+			)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 2,
+				"storage version not updated by migration"
+			);
+			Ok(())
+		}
+	}
+}
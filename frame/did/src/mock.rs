@@ -85,6 +85,12 @@ impl pallet_balances::Config for Test {
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	// Deposits are disabled unless a test sets them.
+	pub static AttributeDeposit: u64 = 0;
+	pub static DelegateDeposit: u64 = 0;
+}
+
 impl Config for Test {
 	type Event = Event;
 	type Public = sr25519::Public;
@@ -92,6 +98,16 @@ impl Config for Test {
 	type Time = Timestamp;
 	type WeightInfo = pallet_did::weights::SubstrateWeight<Self>;
     type MaxLength = ConstU32<64>;
+    type MaxDelegateTypeLength = ConstU32<32>;
+    type MaxAttributeNameLength = ConstU32<64>;
+    type MaxServiceIdLength = ConstU32<32>;
+    type MaxServiceTypeLength = ConstU32<32>;
+    type MaxServiceEndpointLength = ConstU32<64>;
+    type MaxServicePerDid = ConstU32<2>;
+    type MaxAttributes = ConstU32<4>;
+    type Currency = Balances;
+    type AttributeDeposit = AttributeDeposit;
+    type DelegateDeposit = DelegateDeposit;
 }
 
 pub type DID = Module<Test>;
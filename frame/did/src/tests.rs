@@ -1,6 +1,10 @@
 use crate::{did::Did, mock::*, AttributeTransaction, Error};
 use codec::Encode;
-use frame_support::{BoundedVec, assert_noop, assert_ok};
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{Currency, ReservableCurrency},
+    BoundedVec,
+};
 use sp_core::Pair;
 use std::convert::TryInto;
 
@@ -342,3 +346,605 @@ fn add_remove_add_remove_attr() {
         ));
     });
 }
+
+#[test]
+fn add_and_remove_service() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let satoshi_public = account_key("Satoshi");
+        let bob_public = account_key("Bob");
+
+        // Only the owner can add services.
+        assert_noop!(
+            DID::add_service(
+                Origin::signed(bob_public),
+                satoshi_public,
+                b"#domain".to_vec(),
+                b"LinkedDomains".to_vec(),
+                b"https://nuchain.network".to_vec()
+            ),
+            Error::<Test>::NotOwner
+        );
+
+        assert_ok!(DID::add_service(
+            Origin::signed(satoshi_public),
+            satoshi_public,
+            b"#domain".to_vec(),
+            b"LinkedDomains".to_vec(),
+            b"https://nuchain.network".to_vec()
+        ));
+        assert_noop!(
+            DID::add_service(
+                Origin::signed(satoshi_public),
+                satoshi_public,
+                b"#domain".to_vec(),
+                b"LinkedDomains".to_vec(),
+                b"https://example.com".to_vec()
+            ),
+            Error::<Test>::ServiceAlreadyExists
+        );
+        assert_noop!(
+            DID::add_service(
+                Origin::signed(satoshi_public),
+                satoshi_public,
+                b"#hub".to_vec(),
+                b"IdentityHub".to_vec(),
+                vec![b'x'; 65]
+            ),
+            Error::<Test>::ServiceEndpointTooLong
+        );
+        assert_ok!(DID::add_service(
+            Origin::signed(satoshi_public),
+            satoshi_public,
+            b"#hub".to_vec(),
+            b"IdentityHub".to_vec(),
+            b"https://hub.nuchain.network".to_vec()
+        ));
+
+        // MaxServicePerDid is 2
+        assert_noop!(
+            DID::add_service(
+                Origin::signed(satoshi_public),
+                satoshi_public,
+                b"#other".to_vec(),
+                b"Other".to_vec(),
+                b"https://other.network".to_vec()
+            ),
+            Error::<Test>::TooManyServices
+        );
+
+        let services = DID::services_of(&satoshi_public);
+        assert_eq!(services.len(), 2);
+        assert_eq!(services[0].id.to_vec(), b"#domain".to_vec());
+        assert_eq!(services[0].service_type.to_vec(), b"LinkedDomains".to_vec());
+        assert_eq!(services[0].endpoint.to_vec(), b"https://nuchain.network".to_vec());
+
+        assert_noop!(
+            DID::remove_service(Origin::signed(bob_public), satoshi_public, b"#domain".to_vec()),
+            Error::<Test>::NotOwner
+        );
+        assert_ok!(DID::remove_service(
+            Origin::signed(satoshi_public),
+            satoshi_public,
+            b"#domain".to_vec()
+        ));
+        assert_noop!(
+            DID::remove_service(Origin::signed(satoshi_public), satoshi_public, b"#domain".to_vec()),
+            Error::<Test>::ServiceNotFound
+        );
+
+        let services = DID::services_of(&satoshi_public);
+        assert_eq!(services.len(), 1);
+        assert_eq!(services[0].id.to_vec(), b"#hub".to_vec());
+    });
+}
+
+#[test]
+fn valid_delegates_of_skips_expired() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let satoshi_public = account_key("Satoshi");
+        let nakamoto_public = account_key("Nakamoto");
+        let bob_public = account_key("Bob");
+
+        assert_ok!(DID::add_delegate(
+            Origin::signed(satoshi_public),
+            satoshi_public,
+            nakamoto_public,
+            b"OrgAdmin".to_vec(),
+            Some(5)
+        ));
+        assert_ok!(DID::add_delegate(
+            Origin::signed(satoshi_public),
+            satoshi_public,
+            bob_public,
+            b"Signer".to_vec(),
+            None
+        ));
+
+        let mut delegates = DID::valid_delegates_of(&satoshi_public);
+        delegates.sort();
+        let mut expected =
+            vec![(b"OrgAdmin".to_vec(), nakamoto_public), (b"Signer".to_vec(), bob_public)];
+        expected.sort();
+        assert_eq!(delegates, expected);
+
+        System::set_block_number(6);
+
+        assert_eq!(DID::valid_delegates_of(&satoshi_public), vec![(b"Signer".to_vec(), bob_public)]);
+        assert_eq!(DID::valid_delegates_of(&bob_public), vec![]);
+    });
+}
+
+#[test]
+fn delegates_of_lists_valid_delegates_by_type() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let org_admin = b"OrgAdmin".to_vec();
+        let satoshi_public = account_key("Satoshi");
+        let nakamoto_public = account_key("Nakamoto");
+        let bob_public = account_key("Bob");
+        let alice_public = account_key("Alice");
+
+        assert_eq!(DID::delegates_of(&satoshi_public, &org_admin), vec![]);
+
+        assert_ok!(DID::add_delegate(
+            Origin::signed(satoshi_public),
+            satoshi_public,
+            nakamoto_public,
+            org_admin.clone(),
+            Some(5)
+        ));
+        assert_ok!(DID::add_delegate(
+            Origin::signed(satoshi_public),
+            satoshi_public,
+            bob_public,
+            org_admin.clone(),
+            None
+        ));
+        assert_ok!(DID::add_delegate(
+            Origin::signed(satoshi_public),
+            satoshi_public,
+            alice_public,
+            b"Signer".to_vec(),
+            None
+        ));
+
+        let mut delegates = DID::delegates_of(&satoshi_public, &org_admin);
+        delegates.sort();
+        let mut expected = vec![(nakamoto_public, 6), (bob_public, u32::max_value() as u64)];
+        expected.sort();
+        assert_eq!(delegates, expected);
+        assert_eq!(
+            DID::delegates_of(&satoshi_public, b"Signer"),
+            vec![(alice_public, u32::max_value() as u64)]
+        );
+
+        // expired delegate is filtered out
+        System::set_block_number(6);
+        assert_eq!(
+            DID::delegates_of(&satoshi_public, &org_admin),
+            vec![(bob_public, u32::max_value() as u64)]
+        );
+
+        // revoked delegate is removed from the index
+        assert_ok!(DID::revoke_delegate(
+            Origin::signed(satoshi_public),
+            satoshi_public,
+            org_admin.clone(),
+            bob_public
+        ));
+        assert_eq!(DID::delegates_of(&satoshi_public, &org_admin), vec![]);
+    });
+}
+
+#[test]
+fn revoke_all_delegates_of_type() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let org_admin = b"OrgAdmin".to_vec();
+        let satoshi_public = account_key("Satoshi");
+        let nakamoto_public = account_key("Nakamoto");
+        let bob_public = account_key("Bob");
+        let alice_public = account_key("Alice");
+
+        for delegate in [nakamoto_public, bob_public] {
+            assert_ok!(DID::add_delegate(
+                Origin::signed(satoshi_public),
+                satoshi_public,
+                delegate,
+                org_admin.clone(),
+                None
+            ));
+        }
+        assert_ok!(DID::add_delegate(
+            Origin::signed(satoshi_public),
+            satoshi_public,
+            alice_public,
+            b"Signer".to_vec(),
+            None
+        ));
+
+        assert_noop!(
+            DID::revoke_all_delegates(
+                Origin::signed(bob_public),
+                satoshi_public,
+                org_admin.clone(),
+                2
+            ),
+            Error::<Test>::NotOwner
+        );
+        assert_noop!(
+            DID::revoke_all_delegates(
+                Origin::signed(satoshi_public),
+                satoshi_public,
+                org_admin.clone(),
+                1
+            ),
+            Error::<Test>::TooManyDelegates
+        );
+
+        System::set_block_number(2);
+        let events = System::events().len();
+
+        assert_ok!(DID::revoke_all_delegates(
+            Origin::signed(satoshi_public),
+            satoshi_public,
+            org_admin.clone(),
+            2
+        ));
+
+        // one DelegateRevoked per delegate
+        assert_eq!(System::events().len(), events + 2);
+        assert_noop!(
+            DID::valid_delegate(&satoshi_public, &org_admin, &nakamoto_public),
+            Error::<Test>::InvalidDelegate
+        );
+        assert_noop!(
+            DID::valid_delegate(&satoshi_public, &org_admin, &bob_public),
+            Error::<Test>::InvalidDelegate
+        );
+        assert_eq!(DID::delegates_of(&satoshi_public, &org_admin), vec![]);
+
+        // other delegate types are kept
+        assert_ok!(DID::valid_delegate(&satoshi_public, &b"Signer".to_vec(), &alice_public));
+    });
+}
+
+#[test]
+fn attributes_of_lists_identity_attributes() {
+    new_test_ext().execute_with(|| {
+        let alice_public = account_key("Alice");
+        let names: Vec<Vec<u8>> = vec![b"name".to_vec(), b"email".to_vec()];
+
+        for name in names.iter() {
+            assert_ok!(DID::add_attribute(
+                Origin::signed(alice_public),
+                alice_public,
+                name.clone(),
+                b"value".to_vec(),
+                None
+            ));
+        }
+        let listed: Vec<Vec<u8>> =
+            DID::attributes_of(&alice_public).into_iter().map(|(name, _)| name).collect();
+        assert_eq!(listed, names);
+
+        // hapus lalu buat ulang, nama tidak boleh duplikat
+        assert_ok!(DID::delete_attribute(
+            Origin::signed(alice_public),
+            alice_public,
+            b"name".to_vec()
+        ));
+        assert_eq!(DID::attributes_of(&alice_public).len(), 1);
+        assert_ok!(DID::add_attribute(
+            Origin::signed(alice_public),
+            alice_public,
+            b"name".to_vec(),
+            b"new value".to_vec(),
+            None
+        ));
+
+        let attributes = DID::attributes_of(&alice_public);
+        assert_eq!(attributes.len(), 2);
+        assert_eq!(attributes[1].0, b"name".to_vec());
+        assert_eq!(attributes[1].1.value.to_vec(), b"new value".to_vec());
+        assert_eq!(attributes[1].1.nonce, 1);
+    });
+}
+
+#[test]
+fn add_attribute_over_max_attributes_fails() {
+    new_test_ext().execute_with(|| {
+        let alice_public = account_key("Alice");
+
+        for i in 0..4u8 {
+            assert_ok!(DID::add_attribute(
+                Origin::signed(alice_public),
+                alice_public,
+                vec![i],
+                b"value".to_vec(),
+                None
+            ));
+        }
+        assert_noop!(
+            DID::add_attribute(
+                Origin::signed(alice_public),
+                alice_public,
+                vec![4],
+                b"value".to_vec(),
+                None
+            ),
+            Error::<Test>::TooManyAttributes
+        );
+    });
+}
+
+#[test]
+fn delegate_type_longer_than_max_delegate_type_length_fails() {
+    new_test_ext().execute_with(|| {
+        let satoshi_public = account_key("Satoshi");
+        let nakamoto_public = account_key("Nakamoto");
+        // 32 bytes, same as MaxDelegateTypeLength in the mock
+        let long_type = b"credentialIssuanceAuthorityOfOrg".to_vec();
+
+        assert_ok!(DID::add_delegate(
+            Origin::signed(satoshi_public),
+            satoshi_public,
+            nakamoto_public,
+            long_type.clone(),
+            None
+        ));
+        assert_ok!(DID::valid_delegate(&satoshi_public, &long_type, &nakamoto_public));
+
+        // still shorter than MaxLength, but over MaxDelegateTypeLength
+        let too_long_type = b"credentialIssuanceAuthorityOfOrgs".to_vec();
+        assert_noop!(
+            DID::add_delegate(
+                Origin::signed(satoshi_public),
+                satoshi_public,
+                nakamoto_public,
+                too_long_type.clone(),
+                None
+            ),
+            Error::<Test>::InvalidDelegate
+        );
+        assert_noop!(
+            DID::valid_delegate(&satoshi_public, &too_long_type, &nakamoto_public),
+            Error::<Test>::InvalidDelegate
+        );
+    });
+}
+
+#[test]
+fn delegate_type_at_max_delegate_type_length_is_stored() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let satoshi_public = account_key("Satoshi");
+        let nakamoto_public = account_key("Nakamoto");
+        // 32 bytes, same as MaxDelegateTypeLength in the mock
+        let long_type = b"credentialIssuanceAuthorityOfOrg".to_vec();
+        let too_long_type = b"credentialIssuanceAuthorityOfOrgs".to_vec();
+
+        assert_ok!(DID::add_delegate(
+            Origin::signed(satoshi_public),
+            satoshi_public,
+            nakamoto_public,
+            long_type.clone(),
+            None
+        ));
+        assert_eq!(
+            DID::delegates_of(&satoshi_public, &long_type),
+            vec![(nakamoto_public, u32::max_value().into())]
+        );
+        assert_eq!(DID::delegates_of(&satoshi_public, &too_long_type), vec![]);
+
+        assert_noop!(
+            DID::revoke_all_delegates(
+                Origin::signed(satoshi_public),
+                satoshi_public,
+                too_long_type,
+                1
+            ),
+            Error::<Test>::InvalidDelegate
+        );
+        assert_ok!(DID::revoke_all_delegates(
+            Origin::signed(satoshi_public),
+            satoshi_public,
+            long_type.clone(),
+            1
+        ));
+        assert_eq!(DID::delegates_of(&satoshi_public, &long_type), vec![]);
+    });
+}
+
+#[test]
+fn has_valid_attribute_checks_validity() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let alice_public = account_key("Alice");
+
+        assert_eq!(DID::has_valid_attribute(&alice_public, b"email"), false);
+        assert_ok!(DID::add_attribute(
+            Origin::signed(alice_public),
+            alice_public,
+            b"email".to_vec(),
+            b"alice@nuchain.network".to_vec(),
+            Some(5)
+        ));
+        assert_eq!(DID::has_valid_attribute(&alice_public, b"email"), true);
+
+        // expired at block 6
+        System::set_block_number(6);
+        assert_eq!(DID::has_valid_attribute(&alice_public, b"email"), false);
+    });
+}
+
+#[test]
+fn attribute_deposit_reserved_on_add_and_released_on_delete() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        AttributeDeposit::set(10);
+        let alice_public = account_key("Alice");
+        let _ = Balances::deposit_creating(&alice_public, 100);
+
+        assert_ok!(DID::add_attribute(
+            Origin::signed(alice_public),
+            alice_public,
+            b"name".to_vec(),
+            b"Alice".to_vec(),
+            None
+        ));
+        assert_eq!(Balances::reserved_balance(&alice_public), 10);
+
+        // revoke keeps the attribute and its deposit
+        assert_ok!(DID::revoke_attribute(
+            Origin::signed(alice_public),
+            alice_public,
+            b"name".to_vec()
+        ));
+        assert_eq!(Balances::reserved_balance(&alice_public), 10);
+
+        assert_ok!(DID::delete_attribute(
+            Origin::signed(alice_public),
+            alice_public,
+            b"name".to_vec()
+        ));
+        assert_eq!(Balances::reserved_balance(&alice_public), 0);
+        assert_eq!(Balances::free_balance(&alice_public), 100);
+    });
+}
+
+#[test]
+fn attribute_deposit_requires_balance() {
+    new_test_ext().execute_with(|| {
+        AttributeDeposit::set(10);
+        let alice_public = account_key("Alice");
+
+        assert_noop!(
+            DID::add_attribute(
+                Origin::signed(alice_public),
+                alice_public,
+                b"name".to_vec(),
+                b"Alice".to_vec(),
+                None
+            ),
+            pallet_balances::Error::<Test>::InsufficientBalance
+        );
+    });
+}
+
+#[test]
+fn delegate_deposit_reserved_on_add_and_released_on_revoke() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        DelegateDeposit::set(10);
+        let satoshi_public = account_key("Satoshi");
+        let nakamoto_public = account_key("Nakamoto");
+        let _ = Balances::deposit_creating(&satoshi_public, 100);
+
+        assert_ok!(DID::add_delegate(
+            Origin::signed(satoshi_public),
+            satoshi_public,
+            nakamoto_public,
+            b"Sr25519VerificationKey2018".to_vec(),
+            None
+        ));
+        assert_eq!(Balances::reserved_balance(&satoshi_public), 10);
+
+        assert_ok!(DID::revoke_delegate(
+            Origin::signed(satoshi_public),
+            satoshi_public,
+            b"Sr25519VerificationKey2018".to_vec(),
+            nakamoto_public
+        ));
+        assert_eq!(Balances::reserved_balance(&satoshi_public), 0);
+        assert_eq!(Balances::free_balance(&satoshi_public), 100);
+    });
+}
+
+#[test]
+fn expired_delegate_can_be_revoked_to_release_deposit() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        DelegateDeposit::set(10);
+        let satoshi_public = account_key("Satoshi");
+        let nakamoto_public = account_key("Nakamoto");
+        let _ = Balances::deposit_creating(&satoshi_public, 100);
+
+        assert_ok!(DID::add_delegate(
+            Origin::signed(satoshi_public),
+            satoshi_public,
+            nakamoto_public,
+            b"Sr25519VerificationKey2018".to_vec(),
+            Some(5)
+        ));
+        assert_eq!(Balances::reserved_balance(&satoshi_public), 10);
+
+        // delegate expired, deposit still reserved
+        System::set_block_number(10);
+        assert_noop!(
+            DID::valid_delegate(
+                &satoshi_public,
+                &b"Sr25519VerificationKey2018".to_vec(),
+                &nakamoto_public
+            ),
+            Error::<Test>::InvalidDelegate
+        );
+        assert_eq!(Balances::reserved_balance(&satoshi_public), 10);
+
+        assert_ok!(DID::revoke_delegate(
+            Origin::signed(satoshi_public),
+            satoshi_public,
+            b"Sr25519VerificationKey2018".to_vec(),
+            nakamoto_public
+        ));
+        assert_eq!(Balances::reserved_balance(&satoshi_public), 0);
+        assert_eq!(Balances::free_balance(&satoshi_public), 100);
+
+        // already revoked
+        assert_noop!(
+            DID::revoke_delegate(
+                Origin::signed(satoshi_public),
+                satoshi_public,
+                b"Sr25519VerificationKey2018".to_vec(),
+                nakamoto_public
+            ),
+            Error::<Test>::InvalidDelegate
+        );
+    });
+}
+
+#[test]
+fn migrate_to_v1_indexes_only_valid_delegates() {
+    use crate::{migrations, DelegateOf, DelegatesOf, Pallet};
+    use frame_support::traits::{ConstU32, GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(5);
+
+        let identity = account_key("Satoshi");
+        let delegate_type: BoundedVec<u8, ConstU32<32>> =
+            b"Sr25519VerificationKey2018".to_vec().try_into().unwrap();
+        let valid = account_key("Valid");
+        let revoked = account_key("Revoked");
+        let expired = account_key("Expired");
+
+        // Revoked delegates keep the revocation block as validity.
+        DelegateOf::<Test>::insert((identity, &delegate_type, valid), 10);
+        DelegateOf::<Test>::insert((identity, &delegate_type, revoked), 5);
+        DelegateOf::<Test>::insert((identity, &delegate_type, expired), 3);
+        StorageVersion::new(0).put::<Pallet<Test>>();
+
+        migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+        assert_eq!(Pallet::<Test>::on_chain_storage_version(), 1);
+        assert!(DelegatesOf::<Test>::contains_key((identity, &delegate_type, valid)));
+        assert!(!DelegatesOf::<Test>::contains_key((identity, &delegate_type, revoked)));
+        assert!(!DelegatesOf::<Test>::contains_key((identity, &delegate_type, expired)));
+    });
+}
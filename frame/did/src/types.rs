@@ -49,3 +49,16 @@ pub struct AttributeTransaction<Signature, AccountId, BoundedString> {
 	pub signer: AccountId,
 	pub identity: AccountId,
 }
+
+/// Service endpoint of a DID document.
+#[derive(
+	PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo, MaxEncodedLen,
+)]
+pub struct Service<BoundedId, BoundedType, BoundedEndpoint> {
+	/// Service id, unique within the identity e.g. `#linked-domain`.
+	pub id: BoundedId,
+	/// Service type e.g. `LinkedDomains`.
+	pub service_type: BoundedType,
+	/// Service endpoint e.g. `https://nuchain.network`.
+	pub endpoint: BoundedEndpoint,
+}
@@ -37,6 +37,9 @@
 // frame/did/src/weights.rs
 // --template
 // .maintain/frame-weight-template.hbs
+//
+// Weights marked as estimated were written by hand and must be
+// regenerated with the command above before the next release.
 
 
 #![allow(unused_parens)]
@@ -50,76 +53,129 @@ pub trait WeightInfo {
 	fn add_delegate() -> Weight;
 	fn change_owner() -> Weight;
 	fn revoke_delegate() -> Weight;
+	fn revoke_all_delegates(d: u32, ) -> Weight;
 	fn add_attribute() -> Weight;
 	fn revoke_attribute() -> Weight;
 	fn delete_attribute() -> Weight;
+	fn add_service() -> Weight;
+	fn remove_service() -> Weight;
 }
 
 /// Weights for pallet_did using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Read and write counts adjusted by hand for deposits and indexes.
 	fn add_delegate() -> Weight {
 		(43_300_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(3 as Weight))
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
 	}
 	fn change_owner() -> Weight {
 		(36_700_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
+	// Read and write counts adjusted by hand for deposits and indexes.
 	fn revoke_delegate() -> Weight {
 		(49_900_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(3 as Weight))
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
 	}
+	// Estimated by hand, not benchmarked yet.
+	fn revoke_all_delegates(d: u32, ) -> Weight {
+		(21_400_000 as Weight)
+			.saturating_add((18_700_000 as Weight).saturating_mul(d as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(d as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((4 as Weight).saturating_mul(d as Weight)))
+	}
+	// Read and write counts adjusted by hand for deposits and indexes.
 	fn add_attribute() -> Weight {
 		(59_100_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(4 as Weight))
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
 	}
 	fn revoke_attribute() -> Weight {
 		(61_600_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(4 as Weight))
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
+	// Read and write counts adjusted by hand for deposits and indexes.
 	fn delete_attribute() -> Weight {
 		(56_700_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Estimated by hand, not benchmarked yet.
+	fn add_service() -> Weight {
+		(41_200_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Estimated by hand, not benchmarked yet.
+	fn remove_service() -> Weight {
+		(38_500_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
+	// Read and write counts adjusted by hand for deposits and indexes.
 	fn add_delegate() -> Weight {
 		(43_300_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
 	}
 	fn change_owner() -> Weight {
 		(36_700_000 as Weight)
 			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
 	}
+	// Read and write counts adjusted by hand for deposits and indexes.
 	fn revoke_delegate() -> Weight {
 		(49_900_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Estimated by hand, not benchmarked yet.
+	fn revoke_all_delegates(d: u32, ) -> Weight {
+		(21_400_000 as Weight)
+			.saturating_add((18_700_000 as Weight).saturating_mul(d as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(d as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((4 as Weight).saturating_mul(d as Weight)))
 	}
+	// Read and write counts adjusted by hand for deposits and indexes.
 	fn add_attribute() -> Weight {
 		(59_100_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
 	}
 	fn revoke_attribute() -> Weight {
 		(61_600_000 as Weight)
 			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
 	}
+	// Read and write counts adjusted by hand for deposits and indexes.
 	fn delete_attribute() -> Weight {
 		(56_700_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	// Estimated by hand, not benchmarked yet.
+	fn add_service() -> Weight {
+		(41_200_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Estimated by hand, not benchmarked yet.
+	fn remove_service() -> Weight {
+		(38_500_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
 	}
 }
@@ -6,8 +6,10 @@
 use super::*;
 
 use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::BoundedVec;
 use frame_system::{EventRecord, RawOrigin};
-use sp_runtime::traits::{Bounded, Saturating};
+use sp_core::{crypto::KeyTypeId, sr25519};
+use sp_runtime::traits::{Bounded, IdentifyAccount, Saturating};
 
 use crate::Pallet as Liquidity;
 // use crate::pallet::BalanceOf;
@@ -23,13 +25,21 @@ fn assert_last_event<T: Config>(generic_event: <T as Config>::Event) {
 }
 
 const NETWORK_1: u32 = 1;
+const PROOF_KEY_TYPE: KeyTypeId = KeyTypeId(*b"liqd");
 
 benchmarks! {
+    where_clause { where
+        T::Public: From<sr25519::Public>,
+        T::Signature: From<sr25519::Signature>,
+    }
+
+    // worst case, the network has a key and the proof signature is verified
     transfer_in {
       let caller: T::AccountId = whitelisted_caller();
 
       pallet::OperatorKey::<T>::put(caller.clone());
       pallet::Locked::<T>::put(false);
+      pallet::Networks::<T>::insert(NETWORK_1, BoundedVec::default());
 
       let owner:T::AccountId = account("owner", 0, 0);
       let owner_lookup = T::Lookup::unlookup(owner.clone());
@@ -40,13 +50,21 @@ benchmarks! {
       let id:u64 = Liquidity::<T>::next_txin_index().unwrap() + 10001u64;
       let amount = T::Currency::minimum_balance().saturating_add(10u32.into());
 
-    }: _(RawOrigin::Signed(caller.clone()), id, amount, owner_lookup, NETWORK_1)
+      let public = sp_io::crypto::sr25519_generate(PROOF_KEY_TYPE, None);
+      let key: T::AccountId = T::Public::from(public).into_account();
+      pallet::NetworkKeys::<T>::insert(NETWORK_1, key);
+      let msg = (NETWORK_1, id, amount, owner.clone()).encode();
+      let proof: T::Signature =
+        sp_io::crypto::sr25519_sign(PROOF_KEY_TYPE, &public, &msg).unwrap().into();
+
+    }: _(RawOrigin::Signed(caller.clone()), id, amount, owner_lookup, NETWORK_1, Some(proof))
     verify {
-      assert_last_event::<T>(Event::TransferIn(id, amount, owner.clone(), NETWORK_1).into());
+      assert_last_event::<T>(Event::TransferIn(id, amount, amount, owner.clone(), NETWORK_1, Some(Vec::new())).into());
     }
 
     transfer_out {
       pallet::Locked::<T>::put(false);
+      pallet::Networks::<T>::insert(NETWORK_1, BoundedVec::default());
 
       let caller: T::AccountId = whitelisted_caller();
       // let owner:T::AccountId = account("owner", 0, 0);
@@ -54,10 +72,57 @@ benchmarks! {
       let _ = T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
 
       let id:u64 = Liquidity::<T>::next_txout_index().unwrap() + 10001u64;
-      let amount = T::Currency::minimum_balance().saturating_add(10u32.into());
+      let fee = T::BridgeFee::get();
+      let amount = T::Currency::minimum_balance().saturating_add(10u32.into()).saturating_add(fee);
     }: _(RawOrigin::Signed(caller.clone()), id, amount, NETWORK_1)
     verify {
-      assert_last_event::<T>(Event::TransferOut(id, amount, caller.clone(), NETWORK_1).into());
+      assert_last_event::<T>(Event::TransferOut(id, amount, amount, fee, caller.clone(), NETWORK_1, Some(Vec::new())).into());
+    }
+
+    confirm_transfer_out {
+      let caller: T::AccountId = whitelisted_caller();
+
+      pallet::OperatorKey::<T>::put(caller.clone());
+
+      let id:u64 = 10001u64;
+      let amount = T::Currency::minimum_balance().saturating_add(10u32.into());
+
+      pallet::ProofTxOuts::<T>::insert(id, ProofTx {
+        id,
+        block: <frame_system::Pallet<T>>::block_number(),
+        network: NETWORK_1,
+        amount,
+        owner: caller.clone(),
+      });
+    }: _(RawOrigin::Signed(caller.clone()), id)
+    verify {
+      assert_last_event::<T>(Event::TransferOutRelayed(id).into());
+    }
+
+    expire_proof {
+      let caller: T::AccountId = whitelisted_caller();
+
+      pallet::OperatorKey::<T>::put(caller.clone());
+
+      let id:u64 = 10001u64;
+      let amount = T::Currency::minimum_balance().saturating_add(10u32.into());
+
+      pallet::ProofTxOuts::<T>::insert(id, ProofTx {
+        id,
+        block: <frame_system::Pallet<T>>::block_number(),
+        network: NETWORK_1,
+        amount,
+        owner: caller.clone(),
+      });
+      pallet::TxOutProofLink::<T>::insert(1, id);
+      pallet::TxOutIndexOf::<T>::insert(id, 1);
+
+      frame_system::Pallet::<T>::set_block_number(
+        <frame_system::Pallet<T>>::block_number().saturating_add(T::ProofExpiryBlocks::get())
+      );
+    }: _(RawOrigin::Signed(caller.clone()), id, NETWORK_1)
+    verify {
+      assert_last_event::<T>(Event::ProofExpired(id, amount, caller.clone(), NETWORK_1).into());
     }
 
 //     lock {
@@ -10,21 +10,77 @@
 //!
 //! ### Dispatchable Functions
 //!
-//! * `transfer_in` - Transfer in tokens from external network.
+//! * `transfer_in` - Transfer in tokens from registered external network.
 //! * `transfer_out` - Transfer out tokens to external network.
+//! * `confirm_transfer_out` - Mark transfer out proof as relayed to external network.
+//! * `expire_proof` - Refund and remove unrelayed transfer out proof after expiry, by the operator.
+//! * `register_network` - Register external network name.
+//! * `remove_network` - Remove registered external network that has no key set.
+//! * `set_network_scaling` - Set decimals scaling of registered external network.
+//! * `set_network_key` - Set key that signs transfer in proofs of registered external network.
 //! * `set_operator` - Set operator key.
-//! * `lock` - Lock pallet to prevent any further transfers.
+//! * `lock` - Lock pallet with a reason to prevent any further transfers.
 //! * `unlock` - Unlock pallet to allow transfers.
+//! * `lock_network` - Lock transfers of a single external network.
+//! * `unlock_network` - Unlock transfers of a single external network.
+//!
+//! ## Operator
+//!
+//! `OperatorKey` set by `set_operator` is the source of truth for the operator,
+//! a signed origin matching it is always accepted by operator calls
+//! (`transfer_in`, `confirm_transfer_out`, `expire_proof`). `OperatorOrigin` is only a fallback
+//! for other origins, e.g. a council, wire it to [`EnsureOperator`] when no other
+//! origin should operate the bridge.
+//!
+//! ## Proof id
+//!
+//! Proof ids are unique across all networks, separately for transfer in and transfer out.
+//! Reusing an id is rejected with `AlreadyExists` when the existing proof is of the same
+//! network (a replay) and with `ProofIdUsedOnOtherNetwork` when it is of another network.
+//! Ids of expired transfer out proofs are kept in `ExpiredTxOuts` and cannot be reused.
+//!
+//! ## Locking
+//!
+//! `lock` is a master switch that halts transfers of every network, `lock_network`
+//! halts only transfers of the given network. A transfer requires both to be unlocked.
+//! `lock` takes a reason that is kept in `LockReason` until `unlock`, so users can learn
+//! why the bridge is down.
+//!
+//! ## Network scaling
+//!
+//! Transfer amounts are given in the external network's precision, a registered network
+//! may have a power of ten scaling exponent so that the locally credited or debited amount
+//! is `amount * 10^exponent`. Proofs store the local (scaled) amount.
+//!
+//! ## Bridge fee
+//!
+//! `transfer_out` withdraws the whole (scaled) amount, the `BridgeFee` portion is handed to
+//! `FeeHandler` and only the remainder is bridged and recorded in the proof. Expired proofs
+//! refund the bridged amount, the fee is kept.
+//!
+//! ## Proof signature
+//!
+//! A registered network may have a key set, `transfer_in` from that network must then
+//! carry a signature by the key over SCALE encoded `(network, id, amount, owner)`,
+//! so that the operator alone cannot forge deposits.
+//!
+//! ## KYC
+//!
+//! When `RequireKyc` is set, `transfer_in` and `transfer_out` only accept owners
+//! that pass the configured `KycCheck`. With the `did` feature enabled, `DidKyc`
+//! checks that a trusted attester identity in `pallet_did` lists the owner as a valid
+//! `kyc` delegate.
 //!
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use frame_support::{
+    dispatch::DispatchResult,
     ensure,
-    traits::{Currency, EnsureOrigin, Get, ReservableCurrency},
+    traits::{Currency, EnsureOrigin, Get, OnUnbalanced, ReservableCurrency},
 };
-use frame_system::ensure_signed;
-use sp_runtime::traits::StaticLookup;
+use frame_system::{ensure_signed, pallet_prelude::OriginFor};
+use sp_runtime::traits::{CheckedMul, IdentifyAccount, Saturating, StaticLookup, Verify, Zero};
 use sp_runtime::RuntimeDebug;
 use sp_std::prelude::*;
 
@@ -58,13 +114,55 @@ pub struct ProofTx<BlockNumber, Balance, AccountId> {
     /// Network source/destination ID
     pub network: u32,
 
-    /// Transfered amount
+    /// Transfered amount, in local precision,
+    /// for transfer out it excludes the bridge fee
     pub amount: Balance,
 
     /// Owner of the token
     pub owner: AccountId,
 }
 
+/// Check whether an account passed KYC.
+pub trait KycCheck<AccountId> {
+    fn is_kyc(who: &AccountId) -> bool;
+}
+
+/// No KYC provider, nobody passes the check.
+impl<AccountId> KycCheck<AccountId> for () {
+    fn is_kyc(_who: &AccountId) -> bool {
+        false
+    }
+}
+
+/// KYC check backed by `pallet_did`, an account passes when the `Attester` identity
+/// lists it as a `kyc` delegate that is not yet expired.
+///
+/// Identities can add attributes to themselves, so a `kyc` attribute on the account's own
+/// identity is not trusted. Only the `Attester` owner can add its delegates.
+#[cfg(feature = "did")]
+pub struct DidKyc<T, Attester>(sp_std::marker::PhantomData<(T, Attester)>);
+
+#[cfg(feature = "did")]
+impl<T, Attester> KycCheck<T::AccountId> for DidKyc<T, Attester>
+where
+    T: pallet_did::Config,
+    Attester: Get<T::AccountId>,
+{
+    fn is_kyc(who: &T::AccountId) -> bool {
+        use frame_support::BoundedVec;
+        use pallet_did::Did;
+
+        <pallet_did::Pallet<T> as Did<
+            T::AccountId,
+            T::BlockNumber,
+            T::Time,
+            T::Signature,
+            BoundedVec<u8, T::MaxLength>,
+        >>::valid_listed_delegate(&Attester::get(), &b"kyc".to_vec(), who)
+        .is_ok()
+    }
+}
+
 type ProofTxT<T> = ProofTx<<T as frame_system::Config>::BlockNumber, BalanceOf<T>, <T as frame_system::Config>::AccountId>;
 
 
@@ -88,9 +186,42 @@ pub mod pallet {
         /// The currency trait.
         type Currency: ReservableCurrency<Self::AccountId>;
 
-        /// The origin which authorized to manage liquidity.
+        /// The origin which authorized to manage liquidity besides the `OperatorKey` account.
         type OperatorOrigin: EnsureOrigin<Self::Origin>;
 
+        /// Number of blocks after which unrelayed transfer out proof can be expired,
+        /// zero means never expire.
+        #[pallet::constant]
+        type ProofExpiryBlocks: Get<Self::BlockNumber>;
+
+        /// Maximum length of external network name.
+        #[pallet::constant]
+        type MaxNetworkNameLength: Get<u32>;
+
+        /// Maximum length of lock reason.
+        #[pallet::constant]
+        type MaxReasonLen: Get<u32>;
+
+        /// Public key type of network keys.
+        type Public: IdentifyAccount<AccountId = Self::AccountId>;
+
+        /// Signature of transfer in proof by the network key.
+        type Signature: Verify<Signer = Self::Public> + Parameter;
+
+        /// KYC provider consulted when `RequireKyc` is set.
+        type Kyc: KycCheck<Self::AccountId>;
+
+        /// Whether transfer owners must pass the `Kyc` check.
+        #[pallet::constant]
+        type RequireKyc: Get<bool>;
+
+        /// Fee taken from every transfer out, in local precision.
+        #[pallet::constant]
+        type BridgeFee: Get<BalanceOf<Self>>;
+
+        /// Handler of the transfer out fee, e.g. the treasury.
+        type FeeHandler: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
         /// Weight information
         type WeightInfo: WeightInfo;
     }
@@ -109,9 +240,42 @@ pub mod pallet {
         /// Pallet locked
         Locked,
 
+        /// Network locked
+        NetworkLocked,
+
         /// Overflow
         Overflow,
 
+        /// Proof already relayed to external network
+        AlreadyRelayed,
+
+        /// Proof not yet expired
+        NotExpired,
+
+        /// Network name too long
+        NetworkNameTooLong,
+
+        /// Network is not registered
+        NetworkNotRegistered,
+
+        /// Owner has not passed KYC
+        KycRequired,
+
+        /// Proof signature missing or invalid
+        BadSignature,
+
+        /// Transfer out amount does not exceed the bridge fee
+        AmountBelowFee,
+
+        /// The proof id already exists for another network
+        ProofIdUsedOnOtherNetwork,
+
+        /// Lock reason too long
+        ReasonTooLong,
+
+        /// Network still has a key set
+        NetworkKeyStillSet,
+
         /// Unknown error occurred
         Unknown,
     }
@@ -119,20 +283,59 @@ pub mod pallet {
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
-        /// New transfer in \[id, amount, owner, network id\]
-        TransferIn(ProofId, BalanceOf<T>, T::AccountId, u32),
+        /// New transfer in \[id, amount, scaled amount, owner, network id, network name\]
+        TransferIn(
+            ProofId,
+            BalanceOf<T>,
+            BalanceOf<T>,
+            T::AccountId,
+            u32,
+            Option<Vec<u8>>,
+        ),
+
+        /// New transfer out \[id, amount, scaled amount, fee, owner, network id, network name\]
+        TransferOut(
+            ProofId,
+            BalanceOf<T>,
+            BalanceOf<T>,
+            BalanceOf<T>,
+            T::AccountId,
+            u32,
+            Option<Vec<u8>>,
+        ),
+
+        /// Transfer out relayed to external network \[id\]
+        TransferOutRelayed(ProofId),
+
+        /// Unrelayed transfer out expired and refunded \[id, amount, owner, network id\]
+        ProofExpired(ProofId, BalanceOf<T>, T::AccountId, u32),
+
+        /// External network registered \[network id, name\]
+        NetworkRegistered(u32, Vec<u8>),
+
+        /// External network removed \[network id\]
+        NetworkRemoved(u32),
+
+        /// External network scaling set \[network id, exponent\]
+        NetworkScalingSet(u32, u32),
+
+        /// External network proof key set \[network id, key\]
+        NetworkKeySet(u32, Option<T::AccountId>),
+
+        /// Operator key changed \[operator\]
+        OperatorChanged(T::AccountId),
 
-        /// New transfer out \[id, amount, owner, network id\]
-        TransferOut(ProofId, BalanceOf<T>, T::AccountId, u32),
+        /// Bridge is locked \[reason\]
+        BridgeLocked(Vec<u8>),
 
-        /// Operator set \[operator\]
-        OperatorChanged(T::AccountId),
+        /// Bridge is unlocked
+        BridgeUnlocked,
 
-        /// Pallet is locked
-        PalletLock(),
+        /// External network is locked \[network id\]
+        NetworkLocked(u32),
 
-        /// Pallet is unlocked
-        PalletUnlock(),
+        /// External network is unlocked \[network id\]
+        NetworkUnlocked(u32),
     }
 
     /// Index of id -> data
@@ -151,6 +354,44 @@ pub mod pallet {
     #[pallet::storage]
     pub type TxOutProofLink<T: Config> = StorageMap<_, Blake2_128Concat, u64, ProofId>;
 
+    /// Transfer in proofs of owner, owner -> proof id
+    #[pallet::storage]
+    pub type ProofsInByOwner<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, ProofId, ()>;
+
+    /// Map transfer out ProofId to its index in `TxOutProofLink`
+    #[pallet::storage]
+    pub type TxOutIndexOf<T: Config> = StorageMap<_, Blake2_128Concat, ProofId, u64>;
+
+    /// Ids of expired transfer out proofs, id -> network id
+    #[pallet::storage]
+    pub type ExpiredTxOuts<T: Config> = StorageMap<_, Blake2_128Concat, ProofId, u32>;
+
+    /// Transfer out proofs of owner, owner -> proof id
+    #[pallet::storage]
+    pub type ProofsOutByOwner<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, ProofId, ()>;
+
+    /// Transfer out proofs that already relayed to external network
+    #[pallet::storage]
+    #[pallet::getter(fn is_relayed)]
+    pub type RelayedTxOuts<T: Config> = StorageMap<_, Blake2_128Concat, ProofId, bool, ValueQuery>;
+
+    /// Registered external networks, network id -> name
+    #[pallet::storage]
+    pub type Networks<T: Config> =
+        StorageMap<_, Twox64Concat, u32, BoundedVec<u8, T::MaxNetworkNameLength>>;
+
+    /// Power of ten scaling exponent of registered external networks, network id -> exponent
+    #[pallet::storage]
+    #[pallet::getter(fn network_scaling)]
+    pub type NetworkScaling<T: Config> = StorageMap<_, Twox64Concat, u32, u32, ValueQuery>;
+
+    /// Keys that sign transfer in proofs of registered external networks, network id -> key
+    #[pallet::storage]
+    #[pallet::getter(fn network_key)]
+    pub type NetworkKeys<T: Config> = StorageMap<_, Twox64Concat, u32, T::AccountId>;
+
     #[pallet::storage]
     #[pallet::getter(fn proof_txin_index)]
     pub type ProofTxInIndex<T> = StorageValue<_, u64>;
@@ -166,6 +407,16 @@ pub mod pallet {
     #[pallet::getter(fn is_locked)]
     pub type Locked<T: Config> = StorageValue<_, bool, ValueQuery>;
 
+    /// Reason given for the current lock
+    #[pallet::storage]
+    #[pallet::getter(fn lock_reason)]
+    pub type LockReason<T: Config> = StorageValue<_, BoundedVec<u8, T::MaxReasonLen>>;
+
+    /// Locked external networks, network id -> locked
+    #[pallet::storage]
+    #[pallet::getter(fn is_network_locked)]
+    pub type NetworkLocked<T: Config> = StorageMap<_, Twox64Concat, u32, bool, ValueQuery>;
+
     /// Liquidity module declaration.
     #[pallet::call]
     impl<T: Config> Pallet<T> {
@@ -173,6 +424,9 @@ pub mod pallet {
         ///
         /// The dispatch origin for this call must be _Operator_.
         ///
+        /// The network must be registered, `proof` is required when the network has a key set,
+        /// see [`Pallet::verify_proof`].
+        ///
         #[pallet::weight(T::WeightInfo::transfer_in())]
         pub fn transfer_in(
             origin: OriginFor<T>,
@@ -180,17 +434,23 @@ pub mod pallet {
             amount: BalanceOf<T>,
             owner: <T::Lookup as StaticLookup>::Source,
             network: u32,
+            proof: Option<T::Signature>,
         ) -> DispatchResultWithPostInfo {
-            let _origin = T::OperatorOrigin::ensure_origin(origin)?;
+            Self::ensure_operator(origin)?;
 
-            Self::ensure_not_locked()?;
+            Self::ensure_network_not_locked(network)?;
 
             ensure!(
-                !ProofTxIns::<T>::contains_key(id),
-                Error::<T>::AlreadyExists
+                Networks::<T>::contains_key(network),
+                Error::<T>::NetworkNotRegistered
             );
 
+            Self::ensure_new_proof_id(ProofTxIns::<T>::get(id).map(|p| p.network), network)?;
+
             let owner = T::Lookup::lookup(owner)?;
+            Self::verify_proof(network, id, amount, &owner, proof.as_ref())?;
+            Self::ensure_kyc(&owner)?;
+            let scaled = Self::scale_amount(network, amount)?;
             let index = Self::next_txin_index()?;
 
             ProofTxIns::<T>::insert(
@@ -199,18 +459,26 @@ pub mod pallet {
                     id,
                     block: <frame_system::Pallet<T>>::block_number(),
                     network,
-                    amount,
+                    amount: scaled,
                     owner: owner.clone(),
                 },
             );
 
             let mut imbalance = <PositiveImbalanceOf<T>>::zero();
 
-            imbalance.subsume(T::Currency::deposit_creating(&owner, amount));
+            imbalance.subsume(T::Currency::deposit_creating(&owner, scaled));
 
             TxInProofLink::<T>::insert(index, id);
+            ProofsInByOwner::<T>::insert(&owner, id, ());
 
-            Self::deposit_event(Event::TransferIn(id, amount, owner, network));
+            Self::deposit_event(Event::TransferIn(
+                id,
+                amount,
+                scaled,
+                owner,
+                network,
+                Self::network_name(network),
+            ));
 
             Ok(().into())
         }
@@ -228,13 +496,21 @@ pub mod pallet {
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
 
-            Self::ensure_not_locked()?;
+            Self::ensure_network_not_locked(network)?;
 
-            ensure!(
-                !ProofTxOuts::<T>::contains_key(id),
-                Error::<T>::AlreadyExists
-            );
+            ensure!(Networks::<T>::contains_key(network), Error::<T>::NetworkNotRegistered);
+
+            let used = ProofTxOuts::<T>::get(id)
+                .map(|p| p.network)
+                .or_else(|| ExpiredTxOuts::<T>::get(id));
+            Self::ensure_new_proof_id(used, network)?;
+
+            Self::ensure_kyc(&who)?;
 
+            let scaled = Self::scale_amount(network, amount)?;
+            let fee = T::BridgeFee::get();
+            ensure!(scaled > fee, Error::<T>::AmountBelowFee);
+            let bridged = scaled.saturating_sub(fee);
             let index = Self::next_txout_index()?;
 
             ProofTxOuts::<T>::insert(
@@ -243,23 +519,194 @@ pub mod pallet {
                     id,
                     block: <frame_system::Pallet<T>>::block_number(),
                     network,
-                    amount,
+                    amount: bridged,
                     owner: who.clone(),
                 },
             );
 
-            let mut imbalance = <NegativeImbalanceOf<T>>::zero();
-
-            imbalance.subsume(T::Currency::withdraw(
+            let imbalance = T::Currency::withdraw(
                 &who,
-                amount,
+                scaled,
                 WithdrawReasons::TRANSFER,
                 ExistenceRequirement::AllowDeath,
-            )?);
+            )?;
+
+            // fee goes to the handler, the bridged remainder is burned
+            let (fee_imbalance, _bridged) = imbalance.split(fee);
+            T::FeeHandler::on_unbalanced(fee_imbalance);
 
             TxOutProofLink::<T>::insert(index, id);
+            TxOutIndexOf::<T>::insert(id, index);
+            ProofsOutByOwner::<T>::insert(&who, id, ());
+
+            Self::deposit_event(Event::TransferOut(
+                id,
+                amount,
+                scaled,
+                fee,
+                who,
+                network,
+                Self::network_name(network),
+            ));
+
+            Ok(().into())
+        }
+
+        /// Mark transfer out proof as relayed to external network,
+        /// relayed proof cannot be expired.
+        ///
+        /// The dispatch origin for this call must be _Operator_.
+        ///
+        #[pallet::weight(T::WeightInfo::confirm_transfer_out())]
+        pub fn confirm_transfer_out(origin: OriginFor<T>, id: ProofId) -> DispatchResultWithPostInfo {
+            Self::ensure_operator(origin)?;
+
+            ensure!(ProofTxOuts::<T>::contains_key(id), Error::<T>::NotExists);
+            ensure!(!Self::is_relayed(id), Error::<T>::AlreadyRelayed);
+
+            RelayedTxOuts::<T>::insert(id, true);
+
+            Self::deposit_event(Event::TransferOutRelayed(id));
+
+            Ok(().into())
+        }
+
+        /// Expire unrelayed transfer out proof, refund the amount to the owner
+        /// and remove the proof, the id cannot be reused afterwards.
+        ///
+        /// The dispatch origin for this call must be _Operator_, which knows
+        /// whether the proof was relayed even if it was never confirmed.
+        ///
+        #[pallet::weight(T::WeightInfo::expire_proof())]
+        pub fn expire_proof(
+            origin: OriginFor<T>,
+            id: ProofId,
+            network: u32,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_operator(origin)?;
+
+            let proof = ProofTxOuts::<T>::get(id).ok_or(Error::<T>::NotExists)?;
+
+            ensure!(proof.network == network, Error::<T>::InvalidNetwork);
+            ensure!(!Self::is_relayed(id), Error::<T>::AlreadyRelayed);
+            ensure!(Self::is_proof_expired(&proof), Error::<T>::NotExpired);
+
+            let mut imbalance = <PositiveImbalanceOf<T>>::zero();
+
+            imbalance.subsume(T::Currency::deposit_creating(&proof.owner, proof.amount));
+
+            ProofTxOuts::<T>::remove(id);
+            ProofsOutByOwner::<T>::remove(&proof.owner, id);
+            if let Some(index) = TxOutIndexOf::<T>::take(id) {
+                TxOutProofLink::<T>::remove(index);
+            }
+            ExpiredTxOuts::<T>::insert(id, network);
+
+            Self::deposit_event(Event::ProofExpired(id, proof.amount, proof.owner, network));
+
+            Ok(().into())
+        }
+
+        /// Register external network name, overwrite the name if already registered.
+        ///
+        /// The dispatch origin for this call must be _Root_.
+        ///
+        #[pallet::weight(T::DbWeight::get().reads_writes(0, 1))]
+        pub fn register_network(
+            origin: OriginFor<T>,
+            network: u32,
+            name: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+
+            let bounded_name: BoundedVec<u8, T::MaxNetworkNameLength> =
+                name.clone().try_into().map_err(|_| Error::<T>::NetworkNameTooLong)?;
+
+            Networks::<T>::insert(network, bounded_name);
+
+            Self::deposit_event(Event::NetworkRegistered(network, name));
+
+            Ok(().into())
+        }
+
+        /// Remove registered external network, the network key must be removed first
+        /// by [`Pallet::set_network_key`].
+        ///
+        /// The dispatch origin for this call must be _Root_.
+        ///
+        #[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
+        pub fn remove_network(origin: OriginFor<T>, network: u32) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+
+            ensure!(
+                Networks::<T>::contains_key(network),
+                Error::<T>::NetworkNotRegistered
+            );
+            ensure!(
+                !NetworkKeys::<T>::contains_key(network),
+                Error::<T>::NetworkKeyStillSet
+            );
+
+            Networks::<T>::remove(network);
+            NetworkScaling::<T>::remove(network);
+
+            Self::deposit_event(Event::NetworkRemoved(network));
+
+            Ok(().into())
+        }
+
+        /// Set power of ten scaling exponent of registered external network,
+        /// transfer amounts of the network are multiplied by `10^exponent`.
+        ///
+        /// The dispatch origin for this call must be _Root_.
+        ///
+        #[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
+        pub fn set_network_scaling(
+            origin: OriginFor<T>,
+            network: u32,
+            exponent: u32,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+
+            ensure!(
+                Networks::<T>::contains_key(network),
+                Error::<T>::NetworkNotRegistered
+            );
+
+            // make sure the factor fits into the balance type
+            Self::scaling_factor(exponent)?;
+
+            NetworkScaling::<T>::insert(network, exponent);
+
+            Self::deposit_event(Event::NetworkScalingSet(network, exponent));
+
+            Ok(().into())
+        }
+
+        /// Set key that signs transfer in proofs of registered external network,
+        /// `None` removes the key and transfer in proofs are no longer checked.
+        ///
+        /// The dispatch origin for this call must be _Root_.
+        ///
+        #[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
+        pub fn set_network_key(
+            origin: OriginFor<T>,
+            network: u32,
+            key: Option<T::AccountId>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+
+            ensure!(
+                Networks::<T>::contains_key(network),
+                Error::<T>::NetworkNotRegistered
+            );
+
+            match &key {
+                Some(key) => NetworkKeys::<T>::insert(network, key),
+                None => NetworkKeys::<T>::remove(network),
+            }
 
-            Self::deposit_event(Event::TransferOut(id, amount, who, network));
+            Self::deposit_event(Event::NetworkKeySet(network, key));
 
             Ok(().into())
         }
@@ -282,17 +729,22 @@ pub mod pallet {
             Ok(().into())
         }
 
-        /// Lock this pallet and make sure that no more transfers can be made.
+        /// Lock this pallet and make sure that no more transfers can be made,
+        /// `reason` is kept until unlocked.
         ///
         /// The dispatch origin for this call must be _Root_.
         ///
-        #[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
-        pub fn lock(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+        #[pallet::weight(T::DbWeight::get().reads_writes(1, 2))]
+        pub fn lock(origin: OriginFor<T>, reason: Vec<u8>) -> DispatchResultWithPostInfo {
             ensure_root(origin)?;
 
+            let bounded_reason: BoundedVec<u8, T::MaxReasonLen> =
+                reason.clone().try_into().map_err(|_| Error::<T>::ReasonTooLong)?;
+
             Locked::<T>::put(true);
+            LockReason::<T>::put(bounded_reason);
 
-            Self::deposit_event(Event::PalletLock());
+            Self::deposit_event(Event::BridgeLocked(reason));
 
             Ok(().into())
         }
@@ -301,13 +753,46 @@ pub mod pallet {
         ///
         /// The dispatch origin for this call must be _Root_.
         ///
-        #[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
+        #[pallet::weight(T::DbWeight::get().reads_writes(1, 2))]
         pub fn unlock(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
             ensure_root(origin.clone())?;
 
             Locked::<T>::put(false);
+            LockReason::<T>::kill();
+
+            Self::deposit_event(Event::BridgeUnlocked);
+
+            Ok(().into())
+        }
+
+        /// Lock transfers of a single external network,
+        /// other networks keep working.
+        ///
+        /// The dispatch origin for this call must be _Root_.
+        ///
+        #[pallet::weight(T::DbWeight::get().reads_writes(0, 1))]
+        pub fn lock_network(origin: OriginFor<T>, network: u32) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+
+            NetworkLocked::<T>::insert(network, true);
+
+            Self::deposit_event(Event::NetworkLocked(network));
+
+            Ok(().into())
+        }
+
+        /// Unlock transfers of a single external network,
+        /// transfers are still blocked while the pallet is locked.
+        ///
+        /// The dispatch origin for this call must be _Root_.
+        ///
+        #[pallet::weight(T::DbWeight::get().reads_writes(0, 1))]
+        pub fn unlock_network(origin: OriginFor<T>, network: u32) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+
+            NetworkLocked::<T>::remove(network);
 
-            Self::deposit_event(Event::PalletUnlock());
+            Self::deposit_event(Event::NetworkUnlocked(network));
 
             Ok(().into())
         }
@@ -346,6 +831,7 @@ pub mod pallet {
     }
 }
 
+/// Ensure origin is signed by the `OperatorKey` account.
 pub struct EnsureOperator<T>(sp_std::marker::PhantomData<T>);
 
 impl<T: Config> EnsureOrigin<T::Origin> for EnsureOperator<T> {
@@ -383,6 +869,16 @@ impl<T: Config> Pallet<T> {
         ProofTxOuts::<T>::get(id)
     }
 
+    /// Get ids of transfer in proofs owned by `owner`
+    pub fn proofs_in_for(owner: &T::AccountId) -> Vec<ProofId> {
+        ProofsInByOwner::<T>::iter_key_prefix(owner).collect()
+    }
+
+    /// Get ids of transfer out proofs owned by `owner`
+    pub fn proofs_out_for(owner: &T::AccountId) -> Vec<ProofId> {
+        ProofsOutByOwner::<T>::iter_key_prefix(owner).collect()
+    }
+
     /// Get next txin index
     pub fn next_txin_index() -> Result<u64, Error<T>> {
         let index = <ProofTxInIndex<T>>::try_get()
@@ -403,11 +899,71 @@ impl<T: Config> Pallet<T> {
         Ok(index)
     }
 
+    /// Get registered external network name
+    pub fn network_name(network: u32) -> Option<Vec<u8>> {
+        Networks::<T>::get(network).map(|name| name.into_inner())
+    }
+
+    /// Get `10^exponent` in balance type
+    pub fn scaling_factor(exponent: u32) -> Result<BalanceOf<T>, Error<T>> {
+        let ten: BalanceOf<T> = 10u32.into();
+        (0..exponent).try_fold(BalanceOf::<T>::from(1u32), |factor, _| {
+            factor.checked_mul(&ten).ok_or(Error::<T>::Overflow)
+        })
+    }
+
+    /// Scale network amount into local precision
+    pub fn scale_amount(network: u32, amount: BalanceOf<T>) -> Result<BalanceOf<T>, Error<T>> {
+        Self::scaling_factor(Self::network_scaling(network))?
+            .checked_mul(&amount)
+            .ok_or(Error::<T>::Overflow)
+    }
+
+    /// Verify transfer in proof signature over `(network, id, amount, owner)`
+    /// when the network has a key set
+    pub fn verify_proof(
+        network: u32,
+        id: ProofId,
+        amount: BalanceOf<T>,
+        owner: &T::AccountId,
+        proof: Option<&T::Signature>,
+    ) -> Result<(), Error<T>> {
+        let key = match Self::network_key(network) {
+            Some(key) => key,
+            None => return Ok(()),
+        };
+        let proof = proof.ok_or(Error::<T>::BadSignature)?;
+        let msg = (network, id, amount, owner).encode();
+        ensure!(proof.verify(&msg[..], &key), Error::<T>::BadSignature);
+        Ok(())
+    }
+
     /// Get current operator
     pub fn operator() -> Option<T::AccountId> {
         OperatorKey::<T>::get()
     }
 
+    /// Ensure origin is signed by the `OperatorKey` account,
+    /// falling back to `OperatorOrigin`.
+    pub fn ensure_operator(origin: OriginFor<T>) -> DispatchResult {
+        if let Ok(who) = ensure_signed(origin.clone()) {
+            if Self::operator().as_ref() == Some(&who) {
+                return Ok(());
+            }
+        }
+        T::OperatorOrigin::ensure_origin(origin)?;
+        Ok(())
+    }
+
+    /// Check whether transfer out proof already passed the expiry period
+    pub fn is_proof_expired(proof: &ProofTxT<T>) -> bool {
+        let expiry = T::ProofExpiryBlocks::get();
+        if expiry.is_zero() {
+            return false;
+        }
+        <frame_system::Pallet<T>>::block_number() >= proof.block.saturating_add(expiry)
+    }
+
     /// Get current locked status, if locked will return error
     pub fn ensure_not_locked() -> Result<(), Error<T>> {
         match Self::is_locked() {
@@ -415,6 +971,32 @@ impl<T: Config> Pallet<T> {
             _ => Err(Error::<T>::Locked),
         }
     }
+
+    /// Ensure the id is not used yet, `used` is the network id it was used on,
+    /// rejecting ids used on another network
+    fn ensure_new_proof_id(used: Option<u32>, network: u32) -> Result<(), Error<T>> {
+        match used {
+            Some(used) if used != network => Err(Error::<T>::ProofIdUsedOnOtherNetwork),
+            Some(_) => Err(Error::<T>::AlreadyExists),
+            None => Ok(()),
+        }
+    }
+
+    /// Ensure neither the pallet nor the network is locked
+    pub fn ensure_network_not_locked(network: u32) -> Result<(), Error<T>> {
+        Self::ensure_not_locked()?;
+        ensure!(!Self::is_network_locked(network), Error::<T>::NetworkLocked);
+        Ok(())
+    }
+
+    /// Ensure the owner passed KYC when `RequireKyc` is set
+    pub fn ensure_kyc(who: &T::AccountId) -> Result<(), Error<T>> {
+        ensure!(
+            !T::RequireKyc::get() || T::Kyc::is_kyc(who),
+            Error::<T>::KycRequired
+        );
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -424,11 +1006,13 @@ mod tests {
 
     use frame_support::{
         assert_noop, assert_ok, dispatch::DispatchError, ord_parameter_types, parameter_types,
+        traits::{ConstU32, Everything},
+        BoundedVec,
     };
 
     use sp_core::H256;
     use sp_runtime::{
-        testing::Header,
+        testing::{Header, TestSignature, UintAuthorityId},
         traits::{BlakeTwo256, IdentityLookup},
     };
 
@@ -441,9 +1025,9 @@ mod tests {
             NodeBlock = Block,
             UncheckedExtrinsic = UncheckedExtrinsic,
         {
-            System: frame_system::{Module, Call, Config, Storage, Event<T>},
-            Balances: pallet_balances::{Module, Call, Storage, Config<T>, Event<T>},
-            Liquidity: pallet_liquidity::{Module, Call, Storage, Event<T>},
+            System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+            Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+            Liquidity: pallet_liquidity::{Pallet, Call, Storage, Event<T>},
         }
     );
 
@@ -453,7 +1037,7 @@ mod tests {
             frame_system::limits::BlockWeights::simple_max(1024);
     }
     impl frame_system::Config for Test {
-        type BaseCallFilter = ();
+        type BaseCallFilter = Everything;
         type BlockWeights = ();
         type BlockLength = ();
         type DbWeight = ();
@@ -475,12 +1059,16 @@ mod tests {
         type OnKilledAccount = ();
         type SystemWeightInfo = ();
         type SS58Prefix = ();
+        type OnSetCode = ();
+        type MaxConsumers = ConstU32<16>;
     }
     parameter_types! {
         pub const ExistentialDeposit: u64 = 1;
     }
     impl pallet_balances::Config for Test {
         type MaxLocks = ();
+        type MaxReserves = ();
+        type ReserveIdentifier = [u8; 8];
         type Balance = u64;
         type Event = Event;
         type DustRemoval = ();
@@ -491,6 +1079,26 @@ mod tests {
     parameter_types! {
         pub const MinProofNameLength: usize = 3;
         pub const MaxProofNameLength: usize = 16;
+        pub const ProofExpiryBlocks: u64 = 10;
+        pub const MaxNetworkNameLength: u32 = 16;
+        pub const MaxReasonLen: u32 = 32;
+        pub static RequireKyc: bool = false;
+        pub static KycAccounts: Vec<u64> = vec![];
+        pub static BridgeFee: u64 = 0;
+    }
+
+    pub struct TestTreasury;
+    impl OnUnbalanced<NegativeImbalanceOf<Test>> for TestTreasury {
+        fn on_nonzero_unbalanced(amount: NegativeImbalanceOf<Test>) {
+            Balances::resolve_creating(&TREASURY, amount);
+        }
+    }
+
+    pub struct TestKyc;
+    impl KycCheck<u64> for TestKyc {
+        fn is_kyc(who: &u64) -> bool {
+            KycAccounts::get().contains(who)
+        }
     }
     ord_parameter_types! {
         pub const One: u64 = 1;
@@ -498,18 +1106,29 @@ mod tests {
     impl Config for Test {
         type Event = Event;
         type Currency = Balances;
-        // type OperatorOrigin = EnsureSignedBy<One, u64>;
-        type OperatorOrigin = EnsureOperator<Test>;
+        // `OperatorKey` is checked by the pallet itself, root is the fallback operator.
+        type OperatorOrigin = frame_system::EnsureRoot<u64>;
+        type ProofExpiryBlocks = ProofExpiryBlocks;
+        type MaxNetworkNameLength = MaxNetworkNameLength;
+        type MaxReasonLen = MaxReasonLen;
+        type Public = UintAuthorityId;
+        type Signature = TestSignature;
+        type Kyc = TestKyc;
+        type RequireKyc = RequireKyc;
+        type BridgeFee = BridgeFee;
+        type FeeHandler = TestTreasury;
         type WeightInfo = weights::SubstrateWeight<Test>;
     }
 
     const NETWORK_1: u32 = 1;
-    // const NETWORK_2: u32 = 2;
-    // const NETWORK_3: u32 = 3;
+    const NETWORK_2: u32 = 2;
+    // never registered
+    const NETWORK_3: u32 = 3;
 
     // mock user
     const ONE: u64 = 1;
     const TWO: u64 = 2;
+    const TREASURY: u64 = 99;
 
     fn new_test_ext() -> sp_io::TestExternalities {
         let mut t = frame_system::GenesisConfig::default()
@@ -530,7 +1149,7 @@ mod tests {
             .into_iter()
             .map(|r| r.event)
             .filter_map(|e| {
-                if let Event::pallet_liquidity(inner) = e {
+                if let Event::Liquidity(inner) = e {
                     Some(inner)
                 } else {
                     None
@@ -542,7 +1161,7 @@ mod tests {
 
     fn ensure_no_event() {
         assert!(System::events().into_iter().map(|r| r.event).all(|e| {
-            if let Event::pallet_liquidity(_) = e {
+            if let Event::Liquidity(_) = e {
                 false
             } else {
                 true
@@ -550,6 +1169,10 @@ mod tests {
         }));
     }
 
+    fn to_bounded(name: &[u8]) -> BoundedVec<u8, MaxNetworkNameLength> {
+        name.to_vec().try_into().unwrap()
+    }
+
     fn ready<F>(func: F)
     where
         F: FnOnce(<Test as frame_system::Config>::AccountId) -> (),
@@ -562,6 +1185,9 @@ mod tests {
             // set operator
             OperatorKey::<Test>::put(ONE);
 
+            Networks::<Test>::insert(NETWORK_1, to_bounded(b"Ethereum"));
+            Networks::<Test>::insert(NETWORK_2, to_bounded(b"Polygon"));
+
             func(ONE);
         })
     }
@@ -578,7 +1204,8 @@ mod tests {
                 0x123,
                 2003,
                 TWO,
-                NETWORK_1
+                NETWORK_1,
+                None
             ));
             assert_eq!(Balances::total_balance(&TWO), 10 + 2003);
             assert_eq!(Balances::total_balance(&operator), 10); // dispatcher balance unchanged
@@ -592,7 +1219,10 @@ mod tests {
 
             // ensure event emited
             let event = last_event();
-            assert_eq!(event, LEvent::TransferIn(0x123, 2003, TWO, NETWORK_1));
+            assert_eq!(
+                event,
+                LEvent::TransferIn(0x123, 2003, 2003, TWO, NETWORK_1, Some(b"Ethereum".to_vec()))
+            );
         });
     }
 
@@ -605,12 +1235,13 @@ mod tests {
                 0x123,
                 2003,
                 TWO,
-                NETWORK_1
+                NETWORK_1,
+                None
             ));
 
             // ensure cannot transfer in again
             assert_noop!(
-                Liquidity::transfer_in(Origin::signed(operator), 0x123, 2003, TWO, NETWORK_1),
+                Liquidity::transfer_in(Origin::signed(operator), 0x123, 2003, TWO, NETWORK_1, None),
                 Error::<Test>::AlreadyExists
             );
         });
@@ -621,7 +1252,7 @@ mod tests {
         new_test_ext().execute_with(|| {
             Locked::<Test>::put(false);
             assert_noop!(
-                Liquidity::transfer_in(Origin::signed(ONE), 0x123, 2003, 2, NETWORK_1),
+                Liquidity::transfer_in(Origin::signed(ONE), 0x123, 2003, 2, NETWORK_1, None),
                 DispatchError::BadOrigin
             );
             assert_eq!(Balances::total_balance(&1), 10);
@@ -656,7 +1287,10 @@ mod tests {
 
             // ensure event emited
             let event = last_event();
-            assert_eq!(event, LEvent::TransferOut(0x123, 3, TWO, NETWORK_1));
+            assert_eq!(
+                event,
+                LEvent::TransferOut(0x123, 3, 3, 0, TWO, NETWORK_1, Some(b"Ethereum".to_vec()))
+            );
         });
     }
 
@@ -674,40 +1308,77 @@ mod tests {
                 3,
                 NETWORK_1
             ));
-            assert_eq!(System::events().len(), 1);
+            let events = System::events().len();
             assert_noop!(
                 Liquidity::transfer_out(Origin::signed(TWO), 0x123, 3, NETWORK_1),
                 Error::<Test>::AlreadyExists
             );
             assert_eq!(Balances::total_balance(&TWO), 10 - 3);
-            assert_eq!(System::events().len(), 1);
+            assert_eq!(System::events().len(), events);
         });
     }
 
-    // test transfer in increase index
+    // test proof id cannot be replayed on another network
     #[test]
-    fn transfer_in_increase_index() {
+    fn proof_id_replay_across_networks_rejected() {
         ready(|operator| {
             assert_ok!(Liquidity::transfer_in(
                 Origin::signed(operator),
                 0x123,
-                2003,
-                TWO,
-                NETWORK_1
-            ));
-
-            // ensure index increased
-            assert_eq!(Liquidity::proof_txin_index(), Some(1));
-            assert_ok!(Liquidity::transfer_in(
-                Origin::signed(operator),
-                0x124,
-                2003,
+                5,
                 TWO,
-                NETWORK_1
+                NETWORK_1,
+                None
             ));
+            assert_noop!(
+                Liquidity::transfer_in(Origin::signed(operator), 0x123, 5, TWO, NETWORK_2, None),
+                Error::<Test>::ProofIdUsedOnOtherNetwork
+            );
+            assert_noop!(
+                Liquidity::transfer_in(Origin::signed(operator), 0x123, 5, TWO, NETWORK_1, None),
+                Error::<Test>::AlreadyExists
+            );
 
-            // ensure index increased
-            assert_eq!(Liquidity::proof_txin_index(), Some(2));
+            // transfer in and transfer out ids are separate
+            assert_ok!(Liquidity::transfer_out(Origin::signed(TWO), 0x123, 3, NETWORK_2));
+            assert_noop!(
+                Liquidity::transfer_out(Origin::signed(TWO), 0x123, 3, NETWORK_1),
+                Error::<Test>::ProofIdUsedOnOtherNetwork
+            );
+            assert_noop!(
+                Liquidity::transfer_out(Origin::signed(TWO), 0x123, 3, NETWORK_2),
+                Error::<Test>::AlreadyExists
+            );
+            assert_eq!(Balances::total_balance(&TWO), 10 + 5 - 3);
+        });
+    }
+
+    // test transfer in increase index
+    #[test]
+    fn transfer_in_increase_index() {
+        ready(|operator| {
+            assert_ok!(Liquidity::transfer_in(
+                Origin::signed(operator),
+                0x123,
+                2003,
+                TWO,
+                NETWORK_1,
+                None
+            ));
+
+            // ensure index increased
+            assert_eq!(Liquidity::proof_txin_index(), Some(1));
+            assert_ok!(Liquidity::transfer_in(
+                Origin::signed(operator),
+                0x124,
+                2003,
+                TWO,
+                NETWORK_1,
+                None
+            ));
+
+            // ensure index increased
+            assert_eq!(Liquidity::proof_txin_index(), Some(2));
         });
     }
 
@@ -744,25 +1415,51 @@ mod tests {
         ready(|_operator| {
             assert_eq!(Liquidity::is_locked(), false);
 
-            assert_ok!(Liquidity::lock(Origin::root()));
+            assert_ok!(Liquidity::lock(Origin::root(), b"maintenance".to_vec()));
 
             // ensure locked
             assert_eq!(Liquidity::is_locked(), true);
 
             assert_noop!(
-                Liquidity::lock(Origin::signed(TWO)),
+                Liquidity::lock(Origin::signed(TWO), b"maintenance".to_vec()),
                 DispatchError::BadOrigin
             );
         });
     }
 
+    // test lock reason kept until unlock
+    #[test]
+    fn lock_with_reason() {
+        ready(|_operator| {
+            assert_eq!(Liquidity::lock_reason(), None);
+
+            assert_noop!(
+                Liquidity::lock(Origin::root(), vec![b'x'; 33]),
+                Error::<Test>::ReasonTooLong
+            );
+
+            assert_ok!(Liquidity::lock(Origin::root(), b"relayer incident".to_vec()));
+            assert_eq!(Liquidity::is_locked(), true);
+            assert_eq!(
+                Liquidity::lock_reason().map(|r| r.into_inner()),
+                Some(b"relayer incident".to_vec())
+            );
+            assert_eq!(last_event(), LEvent::BridgeLocked(b"relayer incident".to_vec()));
+
+            assert_ok!(Liquidity::unlock(Origin::root()));
+            assert_eq!(Liquidity::is_locked(), false);
+            assert_eq!(Liquidity::lock_reason(), None);
+            assert_eq!(last_event(), LEvent::BridgeUnlocked);
+        });
+    }
+
     // test only root can unlock
     #[test]
     fn only_root_can_unlock() {
         ready(|_operator| {
             assert_eq!(Liquidity::is_locked(), false);
 
-            assert_ok!(Liquidity::lock(Origin::root()));
+            assert_ok!(Liquidity::lock(Origin::root(), b"maintenance".to_vec()));
 
             // ensure locked
             assert_eq!(Liquidity::is_locked(), true);
@@ -808,7 +1505,8 @@ mod tests {
                 0x123,
                 2003,
                 TWO,
-                NETWORK_1
+                NETWORK_1,
+                None
             ));
 
             assert_eq!(Liquidity::proof_txin_index(), Some(1));
@@ -819,7 +1517,8 @@ mod tests {
                 0x124,
                 22,
                 TWO,
-                NETWORK_1
+                NETWORK_1,
+                None
             ));
 
             assert_eq!(Liquidity::proof_txin_index(), Some(2));
@@ -831,14 +1530,830 @@ mod tests {
     #[test]
     fn event_emits() {
         ready(|_operator| {
-            assert_ok!(Liquidity::lock(Origin::root()));
-            assert_eq!(last_event(), LEvent::PalletLock());
+            assert_ok!(Liquidity::lock(Origin::root(), b"maintenance".to_vec()));
+            assert_eq!(last_event(), LEvent::BridgeLocked(b"maintenance".to_vec()));
             assert_ok!(Liquidity::unlock(Origin::root()));
-            assert_eq!(last_event(), LEvent::PalletUnlock());
+            assert_eq!(last_event(), LEvent::BridgeUnlocked);
 
             // test operator set
             assert_ok!(Liquidity::set_operator(Origin::root(), TWO));
             assert_eq!(last_event(), LEvent::OperatorChanged(TWO));
         })
     }
+
+    // test operator key is checked regardless of OperatorOrigin wiring
+    #[test]
+    fn rotated_operator_key_takes_effect() {
+        ready(|operator| {
+            assert_ok!(Liquidity::set_operator(Origin::root(), TWO));
+            assert_eq!(last_event(), LEvent::OperatorChanged(TWO));
+
+            // previous operator no longer accepted
+            assert_noop!(
+                Liquidity::transfer_in(Origin::signed(operator), 0x123, 10, ONE, NETWORK_1, None),
+                DispatchError::BadOrigin
+            );
+
+            assert_ok!(Liquidity::transfer_in(
+                Origin::signed(TWO),
+                0x123,
+                10,
+                ONE,
+                NETWORK_1,
+                None
+            ));
+
+            // fallback to OperatorOrigin
+            assert_ok!(Liquidity::transfer_in(
+                Origin::root(),
+                0x124,
+                10,
+                ONE,
+                NETWORK_1,
+                None
+            ));
+            assert_eq!(Balances::free_balance(&ONE), 30);
+        })
+    }
+
+    // test unrelayed transfer out can be expired and refunded
+    #[test]
+    fn expire_unrelayed_proof_refunds_owner() {
+        ready(|operator| {
+            let issuance = Balances::total_issuance();
+
+            assert_ok!(Liquidity::transfer_out(
+                Origin::signed(TWO),
+                0x123,
+                3,
+                NETWORK_1
+            ));
+            assert_eq!(Balances::total_balance(&TWO), 10 - 3);
+
+            // not yet expired
+            System::set_block_number(10);
+            assert_noop!(
+                Liquidity::expire_proof(Origin::signed(operator), 0x123, NETWORK_1),
+                Error::<Test>::NotExpired
+            );
+
+            System::set_block_number(11);
+            assert_noop!(
+                Liquidity::expire_proof(Origin::signed(TWO), 0x123, NETWORK_1),
+                DispatchError::BadOrigin
+            );
+            assert_noop!(
+                Liquidity::expire_proof(Origin::signed(operator), 0x123, NETWORK_2),
+                Error::<Test>::InvalidNetwork
+            );
+            assert_ok!(Liquidity::expire_proof(
+                Origin::signed(operator),
+                0x123,
+                NETWORK_1
+            ));
+
+            // refunded to owner and proof removed
+            assert_eq!(Balances::total_balance(&TWO), 10);
+            assert_eq!(Balances::total_issuance(), issuance);
+            assert!(ProofTxOuts::<Test>::get(0x123).is_none());
+            assert!(TxOutProofLink::<Test>::get(1).is_none());
+            assert_eq!(last_event(), LEvent::ProofExpired(0x123, 3, TWO, NETWORK_1));
+
+            assert_noop!(
+                Liquidity::expire_proof(Origin::signed(operator), 0x123, NETWORK_1),
+                Error::<Test>::NotExists
+            );
+
+            // expired id cannot be reused
+            assert_noop!(
+                Liquidity::transfer_out(Origin::signed(TWO), 0x123, 3, NETWORK_1),
+                Error::<Test>::AlreadyExists
+            );
+            assert_noop!(
+                Liquidity::transfer_out(Origin::signed(TWO), 0x123, 3, NETWORK_2),
+                Error::<Test>::ProofIdUsedOnOtherNetwork
+            );
+        });
+    }
+
+    // test relayed transfer out cannot be expired
+    #[test]
+    fn relayed_proof_cannot_expire() {
+        ready(|operator| {
+            assert_ok!(Liquidity::transfer_out(
+                Origin::signed(TWO),
+                0x123,
+                3,
+                NETWORK_1
+            ));
+
+            assert_noop!(
+                Liquidity::confirm_transfer_out(Origin::signed(TWO), 0x123),
+                DispatchError::BadOrigin
+            );
+            assert_ok!(Liquidity::confirm_transfer_out(
+                Origin::signed(operator),
+                0x123
+            ));
+            assert!(Liquidity::is_relayed(0x123));
+            assert_eq!(last_event(), LEvent::TransferOutRelayed(0x123));
+
+            System::set_block_number(100);
+            assert_noop!(
+                Liquidity::expire_proof(Origin::signed(operator), 0x123, NETWORK_1),
+                Error::<Test>::AlreadyRelayed
+            );
+            assert_eq!(Balances::total_balance(&TWO), 10 - 3);
+        });
+    }
+
+    // test registered network name surfaces in transfer events
+    #[test]
+    fn registered_network_name_in_transfer_event() {
+        ready(|operator| {
+            assert_noop!(
+                Liquidity::register_network(Origin::signed(operator), NETWORK_1, b"Ethereum".to_vec()),
+                DispatchError::BadOrigin
+            );
+            assert_noop!(
+                Liquidity::register_network(Origin::root(), NETWORK_1, vec![b'x'; 17]),
+                Error::<Test>::NetworkNameTooLong
+            );
+            assert_ok!(Liquidity::register_network(
+                Origin::root(),
+                NETWORK_1,
+                b"Ethereum".to_vec()
+            ));
+            assert_eq!(
+                last_event(),
+                LEvent::NetworkRegistered(NETWORK_1, b"Ethereum".to_vec())
+            );
+            assert_eq!(Liquidity::network_name(NETWORK_1), Some(b"Ethereum".to_vec()));
+            assert_eq!(Liquidity::network_name(NETWORK_3), None);
+
+            assert_ok!(Liquidity::transfer_in(
+                Origin::signed(operator),
+                0x123,
+                2003,
+                TWO,
+                NETWORK_1,
+                None
+            ));
+            assert_eq!(
+                last_event(),
+                LEvent::TransferIn(
+                    0x123,
+                    2003,
+                    2003,
+                    TWO,
+                    NETWORK_1,
+                    Some(b"Ethereum".to_vec())
+                )
+            );
+
+            assert_ok!(Liquidity::transfer_out(
+                Origin::signed(TWO),
+                0x124,
+                3,
+                NETWORK_2
+            ));
+            assert_eq!(
+                last_event(),
+                LEvent::TransferOut(0x124, 3, 3, 0, TWO, NETWORK_2, Some(b"Polygon".to_vec()))
+            );
+
+            assert_ok!(Liquidity::remove_network(Origin::root(), NETWORK_1));
+            assert_eq!(last_event(), LEvent::NetworkRemoved(NETWORK_1));
+            assert_eq!(Liquidity::network_name(NETWORK_1), None);
+            assert_noop!(
+                Liquidity::remove_network(Origin::root(), NETWORK_1),
+                Error::<Test>::NetworkNotRegistered
+            );
+        });
+    }
+
+    // test transfers are gated by KYC when required
+    #[test]
+    fn require_kyc_gates_transfers() {
+        ready(|operator| {
+            RequireKyc::set(true);
+            KycAccounts::set(vec![]);
+
+            assert_noop!(
+                Liquidity::transfer_in(Origin::signed(operator), 0x123, 2003, TWO, NETWORK_1, None),
+                Error::<Test>::KycRequired
+            );
+            assert_noop!(
+                Liquidity::transfer_out(Origin::signed(TWO), 0x124, 3, NETWORK_1),
+                Error::<Test>::KycRequired
+            );
+            assert_eq!(Balances::total_balance(&TWO), 10);
+
+            KycAccounts::set(vec![TWO]);
+
+            assert_ok!(Liquidity::transfer_in(
+                Origin::signed(operator),
+                0x123,
+                2003,
+                TWO,
+                NETWORK_1,
+                None
+            ));
+            assert_ok!(Liquidity::transfer_out(
+                Origin::signed(TWO),
+                0x124,
+                3,
+                NETWORK_1
+            ));
+            assert_eq!(Balances::total_balance(&TWO), 10 + 2003 - 3);
+
+            RequireKyc::set(false);
+            KycAccounts::set(vec![]);
+        });
+    }
+
+    // test KYC is not consulted when not required
+    #[test]
+    fn kyc_not_checked_when_not_required() {
+        ready(|_operator| {
+            RequireKyc::set(false);
+            KycAccounts::set(vec![]);
+
+            assert_ok!(Liquidity::transfer_out(
+                Origin::signed(TWO),
+                0x123,
+                3,
+                NETWORK_1
+            ));
+        });
+    }
+
+    // test transfers are scaled by registered network scaling
+    #[test]
+    fn network_scaling_applies_to_transfers() {
+        ready(|operator| {
+            assert_noop!(
+                Liquidity::set_network_scaling(Origin::root(), NETWORK_3, 2),
+                Error::<Test>::NetworkNotRegistered
+            );
+            assert_ok!(Liquidity::register_network(
+                Origin::root(),
+                NETWORK_1,
+                b"Ethereum".to_vec()
+            ));
+            assert_noop!(
+                Liquidity::set_network_scaling(Origin::signed(operator), NETWORK_1, 2),
+                DispatchError::BadOrigin
+            );
+            assert_noop!(
+                Liquidity::set_network_scaling(Origin::root(), NETWORK_1, 20),
+                Error::<Test>::Overflow
+            );
+            assert_ok!(Liquidity::set_network_scaling(Origin::root(), NETWORK_1, 2));
+            assert_eq!(last_event(), LEvent::NetworkScalingSet(NETWORK_1, 2));
+            assert_eq!(Liquidity::network_scaling(NETWORK_1), 2);
+
+            let issuance = Balances::total_issuance();
+
+            assert_ok!(Liquidity::transfer_in(
+                Origin::signed(operator),
+                0x123,
+                7,
+                TWO,
+                NETWORK_1,
+                None
+            ));
+            assert_eq!(Balances::total_balance(&TWO), 10 + 700);
+            assert_eq!(Liquidity::proof_tx_ins(0x123).unwrap().amount, 700);
+            assert_eq!(
+                last_event(),
+                LEvent::TransferIn(0x123, 7, 700, TWO, NETWORK_1, Some(b"Ethereum".to_vec()))
+            );
+
+            assert_ok!(Liquidity::transfer_out(
+                Origin::signed(TWO),
+                0x124,
+                5,
+                NETWORK_1
+            ));
+            assert_eq!(Balances::total_balance(&TWO), 10 + 700 - 500);
+            assert_eq!(Liquidity::proof_tx_out(0x124).unwrap().amount, 500);
+            assert_eq!(
+                last_event(),
+                LEvent::TransferOut(
+                    0x124,
+                    5,
+                    500,
+                    0,
+                    TWO,
+                    NETWORK_1,
+                    Some(b"Ethereum".to_vec())
+                )
+            );
+            assert_eq!(Balances::total_issuance(), issuance + 700 - 500);
+
+            // unscaled network untouched
+            assert_ok!(Liquidity::transfer_in(
+                Origin::signed(operator),
+                0x125,
+                7,
+                TWO,
+                NETWORK_2,
+                None
+            ));
+            assert_eq!(Balances::total_balance(&TWO), 10 + 700 - 500 + 7);
+
+            assert_ok!(Liquidity::remove_network(Origin::root(), NETWORK_1));
+            assert_eq!(Liquidity::network_scaling(NETWORK_1), 0);
+        });
+    }
+
+    // test scaled amount overflow is rejected
+    #[test]
+    fn network_scaling_overflow_rejected() {
+        ready(|operator| {
+            assert_ok!(Liquidity::register_network(
+                Origin::root(),
+                NETWORK_1,
+                b"Ethereum".to_vec()
+            ));
+            assert_ok!(Liquidity::set_network_scaling(
+                Origin::root(),
+                NETWORK_1,
+                19
+            ));
+
+            assert_noop!(
+                Liquidity::transfer_in(Origin::signed(operator), 0x123, 2, TWO, NETWORK_1, None),
+                Error::<Test>::Overflow
+            );
+            assert!(ProofTxIns::<Test>::get(0x123).is_none());
+        });
+    }
+
+    fn proof_of(key: u64, network: u32, id: ProofId, amount: u64, owner: u64) -> TestSignature {
+        TestSignature(key, (network, id, amount, owner).encode())
+    }
+
+    // test transfer in proof signature is verified when network key is set
+    #[test]
+    fn transfer_in_requires_valid_proof_when_network_key_set() {
+        ready(|operator| {
+            const KEY: u64 = 99;
+
+            assert_noop!(
+                Liquidity::set_network_key(Origin::root(), NETWORK_3, Some(KEY)),
+                Error::<Test>::NetworkNotRegistered
+            );
+            assert_ok!(Liquidity::register_network(
+                Origin::root(),
+                NETWORK_1,
+                b"Ethereum".to_vec()
+            ));
+            assert_noop!(
+                Liquidity::set_network_key(Origin::signed(operator), NETWORK_1, Some(KEY)),
+                DispatchError::BadOrigin
+            );
+            assert_ok!(Liquidity::set_network_key(
+                Origin::root(),
+                NETWORK_1,
+                Some(KEY)
+            ));
+            assert_eq!(last_event(), LEvent::NetworkKeySet(NETWORK_1, Some(KEY)));
+            assert_eq!(Liquidity::network_key(NETWORK_1), Some(KEY));
+
+            // missing proof
+            assert_noop!(
+                Liquidity::transfer_in(Origin::signed(operator), 0x123, 2003, TWO, NETWORK_1, None),
+                Error::<Test>::BadSignature
+            );
+            // signed by other key
+            assert_noop!(
+                Liquidity::transfer_in(
+                    Origin::signed(operator),
+                    0x123,
+                    2003,
+                    TWO,
+                    NETWORK_1,
+                    Some(proof_of(operator, NETWORK_1, 0x123, 2003, TWO))
+                ),
+                Error::<Test>::BadSignature
+            );
+            // signed over other amount
+            assert_noop!(
+                Liquidity::transfer_in(
+                    Origin::signed(operator),
+                    0x123,
+                    2003,
+                    TWO,
+                    NETWORK_1,
+                    Some(proof_of(KEY, NETWORK_1, 0x123, 9999, TWO))
+                ),
+                Error::<Test>::BadSignature
+            );
+            assert_eq!(Balances::total_balance(&TWO), 10);
+
+            assert_ok!(Liquidity::transfer_in(
+                Origin::signed(operator),
+                0x123,
+                2003,
+                TWO,
+                NETWORK_1,
+                Some(proof_of(KEY, NETWORK_1, 0x123, 2003, TWO))
+            ));
+            assert_eq!(Balances::total_balance(&TWO), 10 + 2003);
+
+            // other network is not checked
+            assert_ok!(Liquidity::transfer_in(
+                Origin::signed(operator),
+                0x124,
+                1,
+                TWO,
+                NETWORK_2,
+                None
+            ));
+
+            // network with a key cannot be removed
+            assert_noop!(
+                Liquidity::remove_network(Origin::root(), NETWORK_1),
+                Error::<Test>::NetworkKeyStillSet
+            );
+
+            // removing key disables the check
+            assert_ok!(Liquidity::set_network_key(Origin::root(), NETWORK_1, None));
+            assert_eq!(Liquidity::network_key(NETWORK_1), None);
+            assert_ok!(Liquidity::transfer_in(
+                Origin::signed(operator),
+                0x125,
+                1,
+                TWO,
+                NETWORK_1,
+                None
+            ));
+        });
+    }
+
+    // test transfer in through an unknown network id is rejected
+    #[test]
+    fn transfer_in_rejects_unregistered_network() {
+        ready(|operator| {
+            const KEY: u64 = 99;
+
+            assert_ok!(Liquidity::set_network_key(Origin::root(), NETWORK_1, Some(KEY)));
+
+            // unknown network has no key to check the proof against
+            assert_noop!(
+                Liquidity::transfer_in(Origin::signed(operator), 0x123, 2003, TWO, NETWORK_3, None),
+                Error::<Test>::NetworkNotRegistered
+            );
+            assert_eq!(Balances::total_balance(&TWO), 10);
+
+            // removed network keeps rejecting transfers
+            assert_ok!(Liquidity::remove_network(Origin::root(), NETWORK_2));
+            assert_noop!(
+                Liquidity::transfer_in(Origin::signed(operator), 0x123, 2003, TWO, NETWORK_2, None),
+                Error::<Test>::NetworkNotRegistered
+            );
+            assert!(ProofTxIns::<Test>::get(0x123).is_none());
+        });
+    }
+
+    #[test]
+    fn transfer_out_rejects_unregistered_network() {
+        ready(|_operator| {
+            // nothing relays transfers to an unknown network
+            assert_noop!(
+                Liquidity::transfer_out(Origin::signed(TWO), 0x123, 3, NETWORK_3),
+                Error::<Test>::NetworkNotRegistered
+            );
+
+            assert_ok!(Liquidity::remove_network(Origin::root(), NETWORK_2));
+            assert_noop!(
+                Liquidity::transfer_out(Origin::signed(TWO), 0x123, 3, NETWORK_2),
+                Error::<Test>::NetworkNotRegistered
+            );
+            assert_eq!(Balances::total_balance(&TWO), 10);
+            assert!(ProofTxOuts::<Test>::get(0x123).is_none());
+        });
+    }
+
+    // test locking a network only blocks transfers of that network
+    #[test]
+    fn lock_network_blocks_only_that_network() {
+        ready(|operator| {
+            assert_noop!(
+                Liquidity::lock_network(Origin::signed(operator), NETWORK_1),
+                DispatchError::BadOrigin
+            );
+            assert_ok!(Liquidity::lock_network(Origin::root(), NETWORK_1));
+            assert!(Liquidity::is_network_locked(NETWORK_1));
+            assert_eq!(last_event(), LEvent::NetworkLocked(NETWORK_1));
+
+            assert_noop!(
+                Liquidity::transfer_in(Origin::signed(operator), 0x123, 2, TWO, NETWORK_1, None),
+                Error::<Test>::NetworkLocked
+            );
+            assert_noop!(
+                Liquidity::transfer_out(Origin::signed(TWO), 0x123, 2, NETWORK_1),
+                Error::<Test>::NetworkLocked
+            );
+
+            // network 2 still flows
+            assert_ok!(Liquidity::transfer_in(
+                Origin::signed(operator),
+                0x123,
+                2,
+                TWO,
+                NETWORK_2,
+                None
+            ));
+            assert_ok!(Liquidity::transfer_out(
+                Origin::signed(TWO),
+                0x123,
+                2,
+                NETWORK_2
+            ));
+
+            // global lock is a master switch
+            assert_ok!(Liquidity::lock(Origin::root(), b"maintenance".to_vec()));
+            assert_noop!(
+                Liquidity::transfer_out(Origin::signed(TWO), 0x124, 2, NETWORK_2),
+                Error::<Test>::Locked
+            );
+            assert_ok!(Liquidity::unlock(Origin::root()));
+
+            assert_noop!(
+                Liquidity::unlock_network(Origin::signed(operator), NETWORK_1),
+                DispatchError::BadOrigin
+            );
+            assert_ok!(Liquidity::unlock_network(Origin::root(), NETWORK_1));
+            assert!(!Liquidity::is_network_locked(NETWORK_1));
+            assert_eq!(last_event(), LEvent::NetworkUnlocked(NETWORK_1));
+            assert_ok!(Liquidity::transfer_out(
+                Origin::signed(TWO),
+                0x124,
+                2,
+                NETWORK_1
+            ));
+        });
+    }
+
+    // test bridge fee is taken from transfer out and handed to the fee handler
+    #[test]
+    fn transfer_out_takes_bridge_fee() {
+        ready(|operator| {
+            BridgeFee::set(2);
+            let issuance = Balances::total_issuance();
+
+            assert_noop!(
+                Liquidity::transfer_out(Origin::signed(TWO), 0x123, 2, NETWORK_1),
+                Error::<Test>::AmountBelowFee
+            );
+            assert_noop!(
+                Liquidity::transfer_out(Origin::signed(TWO), 0x123, 1, NETWORK_1),
+                Error::<Test>::AmountBelowFee
+            );
+
+            assert_ok!(Liquidity::transfer_out(
+                Origin::signed(TWO),
+                0x123,
+                5,
+                NETWORK_1
+            ));
+            assert_eq!(Balances::total_balance(&TWO), 10 - 5);
+            assert_eq!(Balances::total_balance(&TREASURY), 2);
+            assert_eq!(Balances::total_issuance(), issuance - 3);
+            assert_eq!(ProofTxOuts::<Test>::get(0x123).map(|p| p.amount), Some(3));
+            assert_eq!(
+                last_event(),
+                LEvent::TransferOut(0x123, 5, 5, 2, TWO, NETWORK_1, Some(b"Ethereum".to_vec()))
+            );
+
+            // expiry refunds the bridged amount only
+            System::set_block_number(11);
+            assert_ok!(Liquidity::expire_proof(
+                Origin::signed(operator),
+                0x123,
+                NETWORK_1
+            ));
+            assert_eq!(Balances::total_balance(&TWO), 10 - 2);
+            assert_eq!(Balances::total_balance(&TREASURY), 2);
+
+            BridgeFee::set(0);
+        });
+    }
+
+    // test proofs are indexed by owner
+    #[test]
+    fn proofs_indexed_by_owner() {
+        ready(|operator| {
+            assert_ok!(Liquidity::transfer_in(
+                Origin::signed(operator),
+                0x123,
+                2,
+                TWO,
+                NETWORK_1,
+                None
+            ));
+            assert_ok!(Liquidity::transfer_in(
+                Origin::signed(operator),
+                0x124,
+                2,
+                ONE,
+                NETWORK_2,
+                None
+            ));
+            assert_ok!(Liquidity::transfer_out(
+                Origin::signed(TWO),
+                0x125,
+                3,
+                NETWORK_1
+            ));
+            assert_ok!(Liquidity::transfer_out(
+                Origin::signed(TWO),
+                0x126,
+                3,
+                NETWORK_2
+            ));
+
+            assert_eq!(Liquidity::proofs_in_for(&TWO), vec![0x123]);
+            assert_eq!(Liquidity::proofs_in_for(&ONE), vec![0x124]);
+            let mut outs = Liquidity::proofs_out_for(&TWO);
+            outs.sort();
+            assert_eq!(outs, vec![0x125, 0x126]);
+            assert!(Liquidity::proofs_out_for(&ONE).is_empty());
+
+            // expired proof leaves the index
+            System::set_block_number(11);
+            assert_ok!(Liquidity::expire_proof(
+                Origin::signed(operator),
+                0x125,
+                NETWORK_1
+            ));
+            assert_eq!(Liquidity::proofs_out_for(&TWO), vec![0x126]);
+        });
+    }
+}
+
+#[cfg(all(test, feature = "did"))]
+mod did_kyc_tests {
+    use super::*;
+
+    use frame_support::{
+        assert_ok, parameter_types,
+        traits::{ConstU32, ConstU64, Everything},
+    };
+
+    use sp_core::{sr25519, H256};
+    use sp_runtime::{
+        testing::Header,
+        traits::{BlakeTwo256, IdentityLookup},
+    };
+
+    type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+    type Block = frame_system::mocking::MockBlock<Test>;
+
+    frame_support::construct_runtime!(
+        pub enum Test where
+            Block = Block,
+            NodeBlock = Block,
+            UncheckedExtrinsic = UncheckedExtrinsic,
+        {
+            System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+            Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
+            Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+            Did: pallet_did::{Pallet, Call, Storage, Event<T>},
+        }
+    );
+
+    impl frame_system::Config for Test {
+        type BaseCallFilter = Everything;
+        type BlockWeights = ();
+        type BlockLength = ();
+        type DbWeight = ();
+        type Origin = Origin;
+        type Index = u64;
+        type BlockNumber = u64;
+        type Hash = H256;
+        type Call = Call;
+        type Hashing = BlakeTwo256;
+        type AccountId = sr25519::Public;
+        type Lookup = IdentityLookup<Self::AccountId>;
+        type Header = Header;
+        type Event = Event;
+        type BlockHashCount = ConstU64<250>;
+        type Version = ();
+        type PalletInfo = PalletInfo;
+        type AccountData = pallet_balances::AccountData<u64>;
+        type OnNewAccount = ();
+        type OnKilledAccount = ();
+        type SystemWeightInfo = ();
+        type SS58Prefix = ();
+        type OnSetCode = ();
+        type MaxConsumers = ConstU32<16>;
+    }
+    impl pallet_timestamp::Config for Test {
+        type Moment = u64;
+        type OnTimestampSet = ();
+        type MinimumPeriod = ();
+        type WeightInfo = ();
+    }
+    impl pallet_balances::Config for Test {
+        type MaxLocks = ();
+        type MaxReserves = ();
+        type ReserveIdentifier = [u8; 8];
+        type Balance = u64;
+        type Event = Event;
+        type DustRemoval = ();
+        type ExistentialDeposit = ConstU64<1>;
+        type AccountStore = System;
+        type WeightInfo = ();
+    }
+    impl pallet_did::Config for Test {
+        type Event = Event;
+        type Public = sr25519::Public;
+        type Signature = sr25519::Signature;
+        type Time = Timestamp;
+        type WeightInfo = ();
+        type MaxLength = ConstU32<64>;
+        type MaxDelegateTypeLength = ConstU32<32>;
+        type MaxAttributeNameLength = ConstU32<64>;
+        type MaxServiceIdLength = ConstU32<32>;
+        type MaxServiceTypeLength = ConstU32<32>;
+        type MaxServiceEndpointLength = ConstU32<64>;
+        type MaxServicePerDid = ConstU32<2>;
+        type MaxAttributes = ConstU32<4>;
+        type Currency = Balances;
+        type AttributeDeposit = ConstU64<0>;
+        type DelegateDeposit = ConstU64<0>;
+    }
+
+    parameter_types! {
+        pub Attester: sr25519::Public = account(9);
+    }
+
+    type TestKyc = DidKyc<Test, Attester>;
+
+    fn new_test_ext() -> sp_io::TestExternalities {
+        frame_system::GenesisConfig::default().build_storage::<Test>().unwrap().into()
+    }
+
+    fn account(seed: u8) -> sr25519::Public {
+        sr25519::Public::from_raw([seed; 32])
+    }
+
+    #[test]
+    fn did_kyc_requires_unexpired_attester_delegate() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let alice = account(1);
+            let bob = account(2);
+
+            // valid until block 11
+            assert_ok!(Did::add_delegate(
+                Origin::signed(Attester::get()),
+                Attester::get(),
+                alice,
+                b"kyc".to_vec(),
+                Some(10)
+            ));
+            assert!(TestKyc::is_kyc(&alice));
+
+            // bob is not attested
+            assert!(!TestKyc::is_kyc(&bob));
+
+            // expired
+            System::set_block_number(11);
+            assert!(!TestKyc::is_kyc(&alice));
+        });
+    }
+
+    #[test]
+    fn did_kyc_rejects_self_attested_kyc() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let alice = account(1);
+
+            // anyone can add attributes to their own identity
+            assert_ok!(Did::add_attribute(
+                Origin::signed(alice),
+                alice,
+                b"kyc".to_vec(),
+                b"verified".to_vec(),
+                Some(10)
+            ));
+            assert!(!TestKyc::is_kyc(&alice));
+
+            // nor delegate `kyc` from their own identity
+            let bob = account(2);
+            assert_ok!(Did::add_delegate(
+                Origin::signed(bob),
+                bob,
+                alice,
+                b"kyc".to_vec(),
+                Some(10)
+            ));
+            assert!(!TestKyc::is_kyc(&alice));
+        });
+    }
 }
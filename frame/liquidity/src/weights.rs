@@ -46,53 +46,129 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn transfer_in() -> Weight;
 	fn transfer_out() -> Weight;
+	fn confirm_transfer_out() -> Weight;
+	fn expire_proof() -> Weight;
 }
 
 /// Weights for pallet_liquidity using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Estimated by hand, not benchmarked yet.
 	// Storage: Liquidity OperatorKey (r:1 w:0)
 	// Storage: Liquidity Locked (r:1 w:0)
+	// Storage: Liquidity NetworkLocked (r:1 w:0)
+	// Storage: Liquidity Networks (r:1 w:0)
 	// Storage: Liquidity ProofTxIns (r:1 w:1)
+	// Storage: Liquidity NetworkKeys (r:1 w:0)
+	// Storage: Did DelegateOf (r:1 w:0)
+	// Storage: Liquidity NetworkScaling (r:1 w:0)
 	// Storage: Liquidity ProofTxInIndex (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
 	// Storage: Liquidity TxInProofLink (r:0 w:1)
+	// Storage: Liquidity ProofsInByOwner (r:0 w:1)
 	fn transfer_in() -> Weight {
-		(70_000_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(5 as Weight))
-			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+		(120_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
 	}
+	// Estimated by hand, not benchmarked yet.
 	// Storage: Liquidity Locked (r:1 w:0)
+	// Storage: Liquidity NetworkLocked (r:1 w:0)
+	// Storage: Liquidity Networks (r:1 w:0)
 	// Storage: Liquidity ProofTxOuts (r:1 w:1)
+	// Storage: Liquidity ExpiredTxOuts (r:1 w:0)
+	// Storage: Did DelegateOf (r:1 w:0)
+	// Storage: Liquidity NetworkScaling (r:1 w:0)
 	// Storage: Liquidity ProofTxOutIndex (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
 	// Storage: Liquidity TxOutProofLink (r:0 w:1)
+	// Storage: Liquidity TxOutIndexOf (r:0 w:1)
+	// Storage: Liquidity ProofsOutByOwner (r:0 w:1)
 	fn transfer_out() -> Weight {
-		(73_000_000 as Weight)
+		(80_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	// Storage: Liquidity OperatorKey (r:1 w:0)
+	// Storage: Liquidity ProofTxOuts (r:1 w:0)
+	// Storage: Liquidity RelayedTxOuts (r:1 w:1)
+	fn confirm_transfer_out() -> Weight {
+		(35_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(3 as Weight))
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Liquidity OperatorKey (r:1 w:0)
+	// Storage: Liquidity ProofTxOuts (r:1 w:1)
+	// Storage: Liquidity RelayedTxOuts (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Liquidity TxOutIndexOf (r:1 w:1)
+	// Storage: Liquidity ProofsOutByOwner (r:0 w:1)
+	// Storage: Liquidity TxOutProofLink (r:0 w:1)
+	// Storage: Liquidity ExpiredTxOuts (r:0 w:1)
+	fn expire_proof() -> Weight {
+		(66_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
 	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
+	// Estimated by hand, not benchmarked yet.
 	// Storage: Liquidity OperatorKey (r:1 w:0)
 	// Storage: Liquidity Locked (r:1 w:0)
+	// Storage: Liquidity NetworkLocked (r:1 w:0)
+	// Storage: Liquidity Networks (r:1 w:0)
 	// Storage: Liquidity ProofTxIns (r:1 w:1)
+	// Storage: Liquidity NetworkKeys (r:1 w:0)
+	// Storage: Did DelegateOf (r:1 w:0)
+	// Storage: Liquidity NetworkScaling (r:1 w:0)
 	// Storage: Liquidity ProofTxInIndex (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
 	// Storage: Liquidity TxInProofLink (r:0 w:1)
+	// Storage: Liquidity ProofsInByOwner (r:0 w:1)
 	fn transfer_in() -> Weight {
-		(70_000_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+		(120_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
 	}
+	// Estimated by hand, not benchmarked yet.
 	// Storage: Liquidity Locked (r:1 w:0)
+	// Storage: Liquidity NetworkLocked (r:1 w:0)
+	// Storage: Liquidity Networks (r:1 w:0)
 	// Storage: Liquidity ProofTxOuts (r:1 w:1)
+	// Storage: Liquidity ExpiredTxOuts (r:1 w:0)
+	// Storage: Did DelegateOf (r:1 w:0)
+	// Storage: Liquidity NetworkScaling (r:1 w:0)
 	// Storage: Liquidity ProofTxOutIndex (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
 	// Storage: Liquidity TxOutProofLink (r:0 w:1)
+	// Storage: Liquidity TxOutIndexOf (r:0 w:1)
+	// Storage: Liquidity ProofsOutByOwner (r:0 w:1)
 	fn transfer_out() -> Weight {
-		(73_000_000 as Weight)
+		(80_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+	}
+	// Storage: Liquidity OperatorKey (r:1 w:0)
+	// Storage: Liquidity ProofTxOuts (r:1 w:0)
+	// Storage: Liquidity RelayedTxOuts (r:1 w:1)
+	fn confirm_transfer_out() -> Weight {
+		(35_000_000 as Weight)
 			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Liquidity OperatorKey (r:1 w:0)
+	// Storage: Liquidity ProofTxOuts (r:1 w:1)
+	// Storage: Liquidity RelayedTxOuts (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Liquidity TxOutIndexOf (r:1 w:1)
+	// Storage: Liquidity ProofsOutByOwner (r:0 w:1)
+	// Storage: Liquidity TxOutProofLink (r:0 w:1)
+	// Storage: Liquidity ExpiredTxOuts (r:0 w:1)
+	fn expire_proof() -> Weight {
+		(66_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
 	}
 }
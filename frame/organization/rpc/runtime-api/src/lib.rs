@@ -0,0 +1,24 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait OrganizationApi<AccountId>
+	where
+		AccountId: Codec + Send + Sync,
+	{
+		/// Get organization id registered at sequential `index`,
+		/// index starts from 1.
+		fn organization_by_index(index: u64) -> Option<AccountId>;
+
+		/// Get number of organizations ever registered.
+		fn organization_count() -> u64;
+
+		/// Check whether `who` is the admin or a valid admin delegate of organization `org_id`.
+		fn has_admin_access(org_id: AccountId, who: AccountId) -> bool;
+
+		/// Get organization id the next `create` with the given fields would produce.
+		fn predicted_org_id(name: Vec<u8>, description: Vec<u8>, website: Vec<u8>, email: Vec<u8>) -> AccountId;
+	}
+}
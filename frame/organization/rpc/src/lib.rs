@@ -0,0 +1,113 @@
+use codec::Codec;
+use jsonrpsee::{
+	core::{Error as JsonRpseeError, RpcResult},
+	proc_macros::rpc,
+};
+use sc_client_api::{BlockBackend, HeaderBackend};
+use sp_api::{BlockId, ProvideRuntimeApi};
+use sp_core::Bytes;
+use sp_runtime::traits::Block as BlockT;
+use std::{
+	marker::{PhantomData, Send, Sync},
+	sync::Arc,
+};
+
+#[rpc(client, server)]
+pub trait OrganizationApi<BlockHash, AccountId> {
+	/// Get organization id registered at sequential `index`,
+	/// index starts from 1.
+	#[method(name = "organization_byIndex")]
+	fn organization_by_index(&self, index: u64) -> RpcResult<Option<AccountId>>;
+
+	/// Get number of organizations ever registered,
+	/// useful to paginate organizations by index.
+	#[method(name = "organization_count")]
+	fn organization_count(&self) -> RpcResult<u64>;
+
+	/// Check whether `who` is the admin or a valid admin delegate of organization `org_id`,
+	/// returns false when the organization does not exist.
+	#[method(name = "organization_hasAdminAccess")]
+	fn has_admin_access(&self, org_id: AccountId, who: AccountId) -> RpcResult<bool>;
+
+	/// Get organization id the next `create` with the given fields would produce,
+	/// only holds as long as no other organization is created in between.
+	#[method(name = "organization_predictedId")]
+	fn predicted_org_id(
+		&self,
+		name: Bytes,
+		description: Bytes,
+		website: Bytes,
+		email: Bytes,
+	) -> RpcResult<AccountId>;
+}
+
+pub struct Organization<Block: BlockT, Client> {
+	client: Arc<Client>,
+	_marker: PhantomData<Block>,
+}
+
+impl<Block: BlockT, Client> Organization<Block, Client> {
+	/// Create a new Organization API.
+	pub fn new(client: Arc<Client>) -> Self {
+		Self { client, _marker: PhantomData::default() }
+	}
+}
+
+pub use pallet_organization_runtime_api::OrganizationApi as OrganizationRuntimeApi;
+
+impl<Block, Client, AccountId> OrganizationApiServer<Block::Hash, AccountId>
+	for Organization<Block, Client>
+where
+	Block: BlockT,
+	Client: BlockBackend<Block>
+		+ HeaderBackend<Block>
+		+ ProvideRuntimeApi<Block>
+		+ Send
+		+ Sync
+		+ 'static,
+	AccountId: Codec + Send + Sync + Clone,
+	Client::Api: pallet_organization_runtime_api::OrganizationApi<Block, AccountId>,
+{
+	fn organization_by_index(&self, index: u64) -> RpcResult<Option<AccountId>> {
+		let api = self.client.runtime_api();
+		let block_id = BlockId::hash(self.client.info().best_hash);
+
+		api.organization_by_index(&block_id, index)
+			.map_err(|e| JsonRpseeError::to_call_error(e))
+	}
+
+	fn organization_count(&self) -> RpcResult<u64> {
+		let api = self.client.runtime_api();
+		let block_id = BlockId::hash(self.client.info().best_hash);
+
+		api.organization_count(&block_id).map_err(|e| JsonRpseeError::to_call_error(e))
+	}
+
+	fn has_admin_access(&self, org_id: AccountId, who: AccountId) -> RpcResult<bool> {
+		let api = self.client.runtime_api();
+		let block_id = BlockId::hash(self.client.info().best_hash);
+
+		api.has_admin_access(&block_id, org_id, who)
+			.map_err(|e| JsonRpseeError::to_call_error(e))
+	}
+
+	fn predicted_org_id(
+		&self,
+		name: Bytes,
+		description: Bytes,
+		website: Bytes,
+		email: Bytes,
+	) -> RpcResult<AccountId> {
+		let api = self.client.runtime_api();
+		let block_id = BlockId::hash(self.client.info().best_hash);
+
+		api.predicted_org_id(
+			&block_id,
+			name.to_vec(),
+			description.to_vec(),
+			website.to_vec(),
+			email.to_vec(),
+		)
+		.map_err(|e| JsonRpseeError::to_call_error(e))
+	}
+}
@@ -133,12 +133,35 @@ benchmarks! {
 		let (org_id, member_id) = setup_org_with_members::<T>(&caller);
 	}: _(RawOrigin::Signed(caller.clone()), org_id, member_id)
 
+	remove_members {
+		let n in 1 .. T::MaxMemberCount::get() as u32 - 1;
+		let caller = whitelisted_caller();
+		let _ = T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+		let org_id = setup_org::<T>(&caller);
+		let members: Vec<T::AccountId> = (0..n).map(|a| account("any", 0, a)).collect();
+		let _ = Organization::<T>::add_members(
+			RawOrigin::Signed(caller.clone()).into(),
+			org_id.clone(),
+			members.clone(),
+		);
+	}: _(RawOrigin::Signed(caller.clone()), org_id, members)
+
 	set_admin {
 		let caller = whitelisted_caller();
 		let _ = T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
 		let (org_id, member_id) = setup_org_with_members::<T>(&caller);
 	}: _(RawOrigin::Signed(caller.clone()), org_id, member_id)
 
+	set_admin_adding_member {
+		let caller = whitelisted_caller();
+		let _ = T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+		let org_id = setup_org::<T>(&caller);
+		let new_admin: T::AccountId = account("new_admin", 0, 0);
+	}: _(RawOrigin::Signed(caller.clone()), org_id.clone(), new_admin.clone())
+	verify {
+		assert!(Organization::<T>::is_member(&org_id, &new_admin));
+	}
+
 	delegate_access {
 		let caller = whitelisted_caller();
 		let _ = T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
@@ -171,6 +194,43 @@ benchmarks! {
 		let dest = T::Lookup::unlookup(charlie.clone());
 	}: _(RawOrigin::Signed(caller.clone()), org_id, dest, T::Currency::minimum_balance())
 
+	set_spend_allowance {
+		let caller = whitelisted_caller();
+		let _ = T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+		let (org_id, member_id) = setup_org_with_members::<T>(&caller);
+	}: _(RawOrigin::Signed(caller.clone()), org_id.clone(), member_id.clone(), Some(T::Currency::minimum_balance()))
+	verify {
+		assert_eq!(Organization::<T>::spend_allowance(&org_id, &member_id), Some(T::Currency::minimum_balance()));
+	}
+
+	delete {
+		let n in 1 .. T::MaxMemberCount::get() as u32 - 1;
+		let d in 0 .. 100;
+		let caller = whitelisted_caller();
+		// leave room for the organization balance swept to the admin
+		let _ = T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let org_id = setup_org::<T>(&caller);
+		let _ = Organization::<T>::add_members(
+			RawOrigin::Signed(caller.clone()).into(),
+			org_id.clone(),
+			(0..n).map(|a| account("any", 0, a)).collect(),
+		);
+		for i in 0 .. d {
+			let _ = Organization::<T>::delegate_access(
+				RawOrigin::Signed(caller.clone()).into(),
+				org_id.clone(),
+				account("delegate", i, 0),
+				None,
+			);
+		}
+		let _ = T::Currency::make_free_balance_be(&org_id, T::Currency::minimum_balance() * 10u32.into());
+	}: _(RawOrigin::Signed(caller.clone()), org_id.clone(), d)
+	verify {
+		assert!(!Organizations::<T>::contains_key(&org_id));
+		assert!(T::Currency::free_balance(&org_id).is_zero());
+		assert_eq!(T::Did::identity_owner(&org_id), org_id);
+	}
+
 }
 
 impl_benchmark_test_suite!(Organization, crate::tests::new_test_ext(), crate::tests::Test,);
@@ -0,0 +1,51 @@
+// This file is part of Nuchain.
+//
+// Copyright (C) 2021-2022 Rantai Nusantara Foundation..
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Organization creation fee handlers.
+
+use super::*;
+use sp_std::marker::PhantomData;
+
+/// Charge the organization creation fee to the creator.
+pub trait CreationFeeHandler<AccountId> {
+	fn charge(who: &AccountId) -> DispatchResult;
+}
+
+/// Charge `Fee` in currency `C` and hand it to `P`.
+pub struct CurrencyCreationFee<AccountId, C, Fee, P>(PhantomData<(AccountId, C, Fee, P)>);
+
+impl<AccountId, C, Fee, P> CreationFeeHandler<AccountId>
+	for CurrencyCreationFee<AccountId, C, Fee, P>
+where
+	C: Currency<AccountId>,
+	Fee: Get<C::Balance>,
+	P: OnUnbalanced<C::NegativeImbalance>,
+{
+	fn charge(who: &AccountId) -> DispatchResult {
+		P::on_unbalanced(C::withdraw(who, Fee::get(), WithdrawReasons::FEE, KeepAlive)?);
+		Ok(())
+	}
+}
+
+/// Charge `CreationFee` in the native `Currency` and hand it to `Payment`.
+pub type NativeCreationFee<T> = CurrencyCreationFee<
+	<T as frame_system::Config>::AccountId,
+	<T as Config>::Currency,
+	<T as Config>::CreationFee,
+	<T as Config>::Payment,
+>;
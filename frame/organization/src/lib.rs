@@ -33,23 +33,31 @@
 //! * `suspend_org` - Suspen organization.
 //! * `add_members` - Add account as member to the organization.
 //! * `remove_member` - Remove account member from organization.
+//! * `remove_members` - Remove multiple account members from organization.
+//! * `delete` - Delete organization and refund the creation deposit.
+//!
+//! ## Fee and deposit
+//!
+//! Creating an organization charges `CreationFee` through `CreationFeeHandler` and
+//! reserves `CreationDeposit` from the creator. The fee is not refundable, the deposit
+//! is unreserved back to the creator when the organization is deleted.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use frame_support::{
-	dispatch::DispatchResult,
+	dispatch::{DispatchResult, DispatchResultWithPostInfo},
 	ensure,
 	traits::{
 		Currency, EnsureOrigin,
-		ExistenceRequirement::KeepAlive,
-		Get, OnUnbalanced, ReservableCurrency, UnixTime, WithdrawReasons,
+		ExistenceRequirement::{AllowDeath, KeepAlive},
+		Get, OnUnbalanced, ReservableCurrency, StorageVersion, UnixTime, WithdrawReasons,
 	},
-	types::{Property, Text},
+	types::{check_props, PropError, Property, Text},
 	BoundedVec,
 };
-use frame_system::ensure_signed;
+use frame_system::{ensure_signed, pallet_prelude::OriginFor};
 use sp_core::crypto::UncheckedFrom;
-use sp_runtime::traits::{Hash, StaticLookup};
+use sp_runtime::traits::{Hash, Saturating, StaticLookup, Zero};
 use sp_std::prelude::*;
 
 use enumflags2::BitFlags;
@@ -58,9 +66,13 @@ pub use pallet::*;
 
 #[cfg(feature = "runtime-benchmarks")]
 pub mod benchmarking;
+pub mod fee;
+pub mod migrations;
 pub mod weights;
 pub use weights::WeightInfo;
 
+pub use fee::{CreationFeeHandler, CurrencyCreationFee, NativeCreationFee};
+
 use codec::{Decode, Encode, EncodeLike};
 use pallet_did::Did;
 
@@ -68,10 +80,20 @@ mod types;
 
 // pub use crate::types::Organization;
 
+/// The current storage version.
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
 pub const MAX_PROPS: usize = 10;
 pub const PROP_NAME_MAX_LENGTH: usize = 30;
 pub const PROP_VALUE_MAX_LENGTH: usize = 60;
 
+/// Bits of the changed fields mask of [`Event::OrganizationUpdated`].
+pub const CHANGED_NAME: u8 = 0b00001;
+pub const CHANGED_DESCRIPTION: u8 = 0b00010;
+pub const CHANGED_WEBSITE: u8 = 0b00100;
+pub const CHANGED_EMAIL: u8 = 0b01000;
+pub const CHANGED_PROPS: u8 = 0b10000;
+
 macro_rules! to_bounded {
 	(*$name:ident, $error:expr) => {
 		$name.clone().try_into().map_err(|()| $error)?
@@ -94,6 +116,18 @@ pub type Organization<T> = types::Organization<
 	>,
 >;
 
+/// Checked before an organization is deleted, lets dependent pallets keep
+/// organizations that still have data referring to them.
+pub trait OrganizationDeleteGuard<AccountId> {
+	fn ensure_can_delete(org_id: &AccountId) -> DispatchResult;
+}
+
+impl<AccountId> OrganizationDeleteGuard<AccountId> for () {
+	fn ensure_can_delete(_org_id: &AccountId) -> DispatchResult {
+		Ok(())
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 
@@ -109,6 +143,7 @@ pub mod pallet {
 
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
@@ -131,6 +166,18 @@ pub mod pallet {
 		/// Payment for treasury
 		type Payment: OnUnbalanced<NegativeImbalanceOf<Self>>;
 
+		/// How the creation fee is charged, [`NativeCreationFee`] charges `CreationFee`
+		/// in `Currency` and hands it to `Payment`. Use [`CurrencyCreationFee`] to charge
+		/// the fee in another currency.
+		type CreationFeeHandler: CreationFeeHandler<Self::AccountId>;
+
+		/// Deposit reserved from the creator and refunded when the organization is deleted.
+		#[pallet::constant]
+		type CreationDeposit: Get<BalanceOf<Self>>;
+
+		/// Consulted before an organization is deleted.
+		type DeleteGuard: OrganizationDeleteGuard<Self::AccountId>;
+
 		/// The origin which may forcibly set or remove a name. Root can always do this.
 		type ForceOrigin: EnsureOrigin<Self::Origin>;
 
@@ -165,6 +212,11 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxLength: Get<u32>;
 
+		/// Number of blocks the admin has to wait between admin changes,
+		/// zero disables the cooldown.
+		#[pallet::constant]
+		type AdminChangeCooldown: Get<Self::BlockNumber>;
+
 		// #[pallet::constant]
 		// type MaxLength: Get<u32>;
 	}
@@ -237,6 +289,18 @@ pub mod pallet {
 		/// Changes not made
 		NotChanged,
 
+		/// Admin changed too soon after the previous admin change.
+		AdminChangeTooSoon,
+
+		/// Transfer exceeds the spend allowance of the delegate.
+		AllowanceExceeded,
+
+		/// Organization already has `MaxMemberCount` spend allowances.
+		TooManyAllowances,
+
+		/// Unknown properties value type.
+		InvalidPropValueType,
+
 		/// Unknown error occurred
 		Unknown,
 	}
@@ -254,7 +318,10 @@ pub mod pallet {
 		// /// When object deleted
 		// OrganizationDeleted(T::AccountId),
 		/// Organization data has been updated
-		OrganizationUpdated(T::AccountId),
+		///
+		/// 1: organization id
+		/// 2: mask of changed fields, see [`CHANGED_NAME`] and friends
+		OrganizationUpdated(T::AccountId, u8),
 
 		/// Organization has been suspended.
 		OrganizationSuspended(T::AccountId),
@@ -267,6 +334,16 @@ pub mod pallet {
 
 		/// Organization admin changed.
 		AdminChanged(T::AccountId, T::AccountId),
+
+		/// Organization deleted.
+		OrganizationDeleted(T::AccountId),
+
+		/// Spend allowance of a delegate set, `None` when removed.
+		///
+		/// 1: organization id
+		/// 2: delegate account id
+		/// 3: allowance
+		SpendAllowanceSet(T::AccountId, T::AccountId, Option<BalanceOf<T>>),
 	}
 
 	/// Pair organization hash -> Organization data
@@ -287,6 +364,66 @@ pub mod pallet {
 	// 	StorageMap<_, Blake2_128Concat, T::AccountId, BoundedVec<T::AccountId, T::MaxLength>,
 	// ValueQuery>;
 
+	/// Link organization hash -> organization index, reverse of [`OrganizationIndexOf`].
+	#[pallet::storage]
+	#[pallet::getter(fn index_of_organization)]
+	pub type IndexOfOrganization<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u64>;
+
+	/// Deposit reserved for the organization, organization id -> (depositor, amount).
+	#[pallet::storage]
+	#[pallet::getter(fn creation_deposit)]
+	pub type CreationDeposits<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, (T::AccountId, BalanceOf<T>)>;
+
+	/// Hash of the sorted member set of the organization,
+	/// see [`Pallet::compute_members_root`].
+	///
+	/// Updated whenever members change, the v3 migration computes it for organizations
+	/// created before it was introduced.
+	#[pallet::storage]
+	#[pallet::getter(fn members_root)]
+	pub type MembersRoot<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, [u8; 32]>;
+
+	/// Block number of the last admin change made by the organization admin.
+	#[pallet::storage]
+	#[pallet::getter(fn last_admin_change)]
+	pub type LastAdminChange<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, T::BlockNumber>;
+
+	/// Balance a delegate may still transfer out of the organization,
+	/// organization id -> delegate account id -> allowance.
+	#[pallet::storage]
+	#[pallet::getter(fn spend_allowance)]
+	pub type SpendAllowance<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		BalanceOf<T>,
+	>;
+
+	/// Number of spend allowances set on the organization, at most `MaxMemberCount`.
+	#[pallet::storage]
+	#[pallet::getter(fn spend_allowance_count)]
+	pub type SpendAllowanceCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	/// Block number since when the account is member of the organization,
+	/// organization id -> account id -> block number.
+	///
+	/// Reset when a removed member is added again.
+	#[pallet::storage]
+	#[pallet::getter(fn member_since)]
+	pub type MembershipSince<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		T::BlockNumber,
+	>;
+
 	/// Membership store, stored as an ordered Vec.
 	#[pallet::storage]
 	#[pallet::getter(fn members)]
@@ -439,28 +576,12 @@ pub mod pallet {
 			// let admin = T::Lookup::lookup(admin)?;
 
 			// Process the payment
-			let cost = T::CreationFee::get();
-
-			// Process payment
-			T::Payment::on_unbalanced(T::Currency::withdraw(
-				&who,
-				cost,
-				WithdrawReasons::FEE,
-				KeepAlive,
-			)?);
+			T::CreationFeeHandler::charge(&who)?;
+			let deposit = T::CreationDeposit::get();
+			T::Currency::reserve(&who, deposit)?;
 
 			// generate organization id (hash)
-			let org_id: T::AccountId = UncheckedFrom::unchecked_from(T::Hashing::hash(
-				&index
-					.to_le_bytes()
-					.iter()
-					.chain(name.iter())
-					.chain(description.iter())
-					.chain(website.iter())
-					.chain(email.iter())
-					.cloned()
-					.collect::<Vec<u8>>(),
-			));
+			let org_id = Self::generate_org_id(index, &name, &description, &website, &email);
 
 			let block = <frame_system::Pallet<T>>::block_number();
 
@@ -495,6 +616,10 @@ pub mod pallet {
 			);
 
 			<OrganizationIndexOf<T>>::insert(index, org_id.clone());
+			<IndexOfOrganization<T>>::insert(&org_id, index);
+			if !deposit.is_zero() {
+				<CreationDeposits<T>>::insert(&org_id, (who.clone(), deposit));
+			}
 
 			// if OrganizationLink::<T>::contains_key(&admin) {
 			// 	OrganizationLink::<T>::mutate(&admin, |ref mut vs| {
@@ -515,10 +640,17 @@ pub mod pallet {
 			// admin added as member first
 			let members: BoundedVec<T::AccountId, T::MaxMemberCount> =
 				vec![admin.clone()].try_into().unwrap();
+			<MembersRoot<T>>::insert(&org_id, Self::compute_members_root(&members));
 			<Members<T>>::insert(&org_id, members);
+			<MembershipSince<T>>::insert(
+				&org_id,
+				&admin,
+				<frame_system::Pallet<T>>::block_number(),
+			);
 
-			// DID add attribute
-			T::Did::create_attribute(&org_id, &org_id, &b"Org".to_vec(), &name, None)?;
+			// DID add attribute, the organization account has no balance yet
+			// so the attribute deposit is reserved from the creator.
+			T::Did::create_attribute(&org_id, &who, &org_id, &b"Org".to_vec(), &name, None)?;
 			// Set owner of this organization in DID
 			T::Did::set_owner(&who, &org_id, &admin);
 
@@ -531,6 +663,8 @@ pub mod pallet {
 		///
 		/// The dispatch origin for this call must be _Signed_.
 		///
+		/// Fails with `NotChanged` when none of the given fields differs from the current one.
+		///
 		/// # <weight>
 		/// ## Weight
 		/// - `O(N)` where:
@@ -568,27 +702,41 @@ pub mod pallet {
 			//     Error::<T>::NotExists
 			// );
 
-			Organizations::<T>::try_mutate(&org_id, |ref mut org| {
+			let changed = Organizations::<T>::try_mutate(&org_id, |ref mut org| {
 				if let Some(org) = org {
-					let mut updated = false;
+					let mut changed = 0u8;
 					if let Some(name) = name {
-						org.name = to_bounded!(name, Error::<T>::NameTooLong);
-						updated = true;
+						let name: BoundedVec<_, _> = to_bounded!(name, Error::<T>::NameTooLong);
+						if org.name != name {
+							org.name = name;
+							changed |= CHANGED_NAME;
+						}
 					}
 					if let Some(description) = description {
-						org.description = to_bounded!(description, Error::<T>::DescriptionTooLong);
-						updated = true;
+						let description: BoundedVec<_, _> =
+							to_bounded!(description, Error::<T>::DescriptionTooLong);
+						if org.description != description {
+							org.description = description;
+							changed |= CHANGED_DESCRIPTION;
+						}
 					}
 					if let Some(website) = website {
-						org.website = to_bounded!(website, Error::<T>::WebsiteTooLong);
-						updated = true;
+						let website: BoundedVec<_, _> =
+							to_bounded!(website, Error::<T>::WebsiteTooLong);
+						if org.website != website {
+							org.website = website;
+							changed |= CHANGED_WEBSITE;
+						}
 					}
 					if let Some(email) = email {
-						org.email = to_bounded!(email, Error::<T>::EmailTooLong);
-						updated = true;
+						let email: BoundedVec<_, _> = to_bounded!(email, Error::<T>::EmailTooLong);
+						if org.email != email {
+							org.email = email;
+							changed |= CHANGED_EMAIL;
+						}
 					}
 					if props.is_some() {
-						org.props = props.and_then(|ps| {
+						let props = props.and_then(|ps| {
 							ps.into_iter()
 								.flat_map(|p| {
 									let x: Option<
@@ -603,10 +751,13 @@ pub mod pallet {
 								.try_into()
 								.ok()
 						});
-						updated = true;
+						if org.props != props {
+							org.props = props;
+							changed |= CHANGED_PROPS;
+						}
 					}
-					if updated {
-						Ok(())
+					if changed != 0 {
+						Ok(changed)
 					} else {
 						Err(Error::<T>::NotChanged)
 					}
@@ -615,7 +766,7 @@ pub mod pallet {
 				}
 			})?;
 
-			Self::deposit_event(Event::OrganizationUpdated(org_id));
+			Self::deposit_event(Event::OrganizationUpdated(org_id, changed));
 
 			Ok(().into())
 		}
@@ -679,18 +830,25 @@ pub mod pallet {
 		}
 
 		/// Add member to the organization.
+		///
+		/// Duplicate accounts in `new_members` are added once, the member limit is checked
+		/// before any change is made.
 		#[pallet::weight(
             <T as Config>::WeightInfo::add_members( new_members.len() as u32 )
         )]
 		pub fn add_members(
 			origin: OriginFor<T>,
 			org_id: T::AccountId,
-			new_members: Vec<T::AccountId>,
+			mut new_members: Vec<T::AccountId>,
 		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
 
 			ensure!(new_members.len() > 0, Error::<T>::InvalidParameter);
 
+			// the same account must not take two slots
+			new_members.sort();
+			new_members.dedup();
+
 			let org = Self::ensure_access(&sender, &org_id)?;
 
 			ensure!(!org.suspended, Error::<T>::Suspended);
@@ -721,12 +879,15 @@ pub mod pallet {
 			let members: BoundedVec<T::AccountId, T::MaxMemberCount> =
 				members.clone().try_into().map_err(|_| Error::<T>::MaxMemberReached)?;
 
+			<MembersRoot<T>>::insert(&org_id, Self::compute_members_root(&members));
 			<Members<T>>::insert(&org_id, members);
 
 			// <pallet_did::Pallet<T>>::create_delegate(&sender, &org.id, &account_id,
 			// b"OrgMember");
 
+			let now = <frame_system::Pallet<T>>::block_number();
 			for account_id in new_members {
+				<MembershipSince<T>>::insert(&org_id, &account_id, now);
 				Self::deposit_event(Event::MemberAdded(org_id.clone(), account_id));
 			}
 
@@ -755,49 +916,71 @@ pub mod pallet {
 			let _members: Vec<T::AccountId> =
 				members.into_iter().filter(|a| *a != account_id).collect();
 			members = to_bounded!(_members, Error::<T>::MaxMemberReached);
+			MembersRoot::<T>::insert(&org_id, Self::compute_members_root(&members));
 			Members::<T>::insert(org_id.clone(), members);
+			MembershipSince::<T>::remove(&org_id, &account_id);
 
 			Self::deposit_event(Event::MemberRemoved(org_id, account_id));
 
 			Ok(().into())
 		}
 
-		/// Change organization admin,
-		/// the origin must be current admin or conform to `ForceOrigin`.
-		#[pallet::weight(
-            <T as Config>::WeightInfo::set_admin()
-        )]
-		pub fn set_admin(
+		/// Remove multiple members from organization,
+		/// accounts that are not members are ignored.
+		#[pallet::weight(<T as Config>::WeightInfo::remove_members(accounts.len() as u32))]
+		pub fn remove_members(
 			origin: OriginFor<T>,
 			org_id: T::AccountId,
-			account_id: T::AccountId,
+			accounts: Vec<T::AccountId>,
 		) -> DispatchResultWithPostInfo {
-			let who = ensure_signed(origin.clone())?;
+			let origin = ensure_signed(origin)?;
 
-			// harus member terlebih dahulu untuk jadi admin
-			ensure!(Self::is_member(&org_id, &account_id), Error::<T>::NotMember);
+			ensure!(accounts.len() > 0, Error::<T>::InvalidParameter);
+			ensure!(
+				accounts.len() as u32 <= T::MaxMemberCount::get(),
+				Error::<T>::InvalidParameter
+			);
 
 			let org = Organizations::<T>::get(&org_id).ok_or(Error::<T>::NotExists)?;
 
-			if org.admin != who {
-				T::ForceOrigin::ensure_origin(origin)?;
-			} else {
-				ensure!(!org.suspended, Error::<T>::Suspended);
-			}
-
-			ensure!(org.admin != account_id, Error::<T>::AlreadySet);
+			Self::ensure_access(&origin, &org_id)?;
 
-			<Organizations<T>>::mutate(&org_id, |org| {
-				if let Some(org) = org {
-					org.admin = account_id.clone();
-				}
-			});
+			ensure!(!org.suspended, Error::<T>::Suspended);
 
-			Self::deposit_event(Event::AdminChanged(org_id, account_id));
+			Self::do_remove_members(&org_id, accounts)?;
 
 			Ok(().into())
 		}
 
+		/// Change organization admin,
+		/// the origin must be current admin or conform to `ForceOrigin`.
+		///
+		/// The current admin can only change admin once per `AdminChangeCooldown` blocks,
+		/// `ForceOrigin` is not subject to the cooldown.
+		#[pallet::weight(
+            <T as Config>::WeightInfo::set_admin()
+        )]
+		pub fn set_admin(
+			origin: OriginFor<T>,
+			org_id: T::AccountId,
+			account_id: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			Self::do_set_admin(origin, org_id, account_id, false)
+		}
+
+		/// Change organization admin like [`Pallet::set_admin`],
+		/// the account is added to organization members first when it is not a member yet.
+		#[pallet::weight(
+            <T as Config>::WeightInfo::set_admin_adding_member()
+        )]
+		pub fn set_admin_adding_member(
+			origin: OriginFor<T>,
+			org_id: T::AccountId,
+			account_id: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			Self::do_set_admin(origin, org_id, account_id, true)
+		}
+
 		/// Delegate admin access to other user.
 		/// User who delegated will have all admin access
 		/// except:
@@ -865,7 +1048,8 @@ pub mod pallet {
 
 		/// Transfer balance from this organization to another org/account.
 		///
-		/// Only super admin allowed to do this opperation.
+		/// Super admin is unrestricted, a delegated admin can only spend
+		/// its allowance set by [`Pallet::set_spend_allowance`].
 		#[pallet::weight(<T as Config>::WeightInfo::transfer())]
 		pub fn transfer(
 			origin: OriginFor<T>,
@@ -878,12 +1062,122 @@ pub mod pallet {
 			let org = Organizations::<T>::get(&org_id).ok_or(Error::<T>::NotExists)?;
 
 			ensure!(!org.suspended, Error::<T>::Suspended);
-			ensure!(org.admin == transactor, Error::<T>::PermissionDenied);
+
+			if org.admin != transactor {
+				let allowance = Self::spend_allowance(&org_id, &transactor)
+					.ok_or(Error::<T>::PermissionDenied)?;
+				Self::ensure_access(&transactor, &org_id)?;
+				ensure!(value <= allowance, Error::<T>::AllowanceExceeded);
+				SpendAllowance::<T>::insert(&org_id, &transactor, allowance - value);
+			}
 
 			let dest = T::Lookup::lookup(dest)?;
 			T::Currency::transfer(&org_id, &dest, value, KeepAlive)?;
 			Ok(().into())
 		}
+
+		/// Set balance a delegated admin may transfer out of the organization,
+		/// `None` removes the allowance. At most `MaxMemberCount` allowances may be set.
+		///
+		/// Only super admin allowed to do this opperation.
+		#[pallet::weight(<T as Config>::WeightInfo::set_spend_allowance())]
+		pub fn set_spend_allowance(
+			origin: OriginFor<T>,
+			org_id: T::AccountId,
+			delegate: T::AccountId,
+			allowance: Option<BalanceOf<T>>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let org = Organizations::<T>::get(&org_id).ok_or(Error::<T>::NotExists)?;
+
+			ensure!(!org.suspended, Error::<T>::Suspended);
+			ensure!(org.admin == who, Error::<T>::PermissionDenied);
+
+			let exists = SpendAllowance::<T>::contains_key(&org_id, &delegate);
+			match allowance {
+				Some(allowance) => {
+					if !exists {
+						SpendAllowanceCount::<T>::try_mutate(&org_id, |count| {
+							ensure!(
+								*count < T::MaxMemberCount::get(),
+								Error::<T>::TooManyAllowances
+							);
+							*count = count.saturating_add(1);
+							Ok::<_, Error<T>>(())
+						})?;
+					}
+					SpendAllowance::<T>::insert(&org_id, &delegate, allowance);
+				},
+				None =>
+					if exists {
+						SpendAllowance::<T>::remove(&org_id, &delegate);
+						SpendAllowanceCount::<T>::mutate(&org_id, |count| {
+							*count = count.saturating_sub(1)
+						});
+					},
+			}
+
+			Self::deposit_event(Event::SpendAllowanceSet(org_id, delegate, allowance));
+
+			Ok(().into())
+		}
+
+		/// Delete organization and unreserve the creation deposit back to the creator,
+		/// the creation fee is not refunded. The free balance of the organization account
+		/// is transferred to the organization admin.
+		///
+		/// The origin must be the organization admin or conform to `ForceOrigin`.
+		/// `DeleteGuard` may refuse deletion while other pallets still refer to the organization.
+		///
+		/// The DID state of the organization is removed too, `max_delegates` is the witness
+		/// of the number of delegates listed for the organization identity.
+		#[pallet::weight(
+			<T as Config>::WeightInfo::delete(T::MaxMemberCount::get(), *max_delegates)
+		)]
+		pub fn delete(
+			origin: OriginFor<T>,
+			org_id: T::AccountId,
+			max_delegates: u32,
+		) -> DispatchResultWithPostInfo {
+			let org = Organizations::<T>::get(&org_id).ok_or(Error::<T>::NotExists)?;
+
+			if let Err(origin) = T::ForceOrigin::try_origin(origin) {
+				let who = ensure_signed(origin)?;
+				ensure!(org.admin == who, Error::<T>::PermissionDenied);
+			}
+
+			T::DeleteGuard::ensure_can_delete(&org_id)?;
+
+			// The `Org` attribute, the ownership given to the admin and the delegates.
+			let delegates = T::Did::remove_identity(&org_id, max_delegates)?;
+
+			let balance = T::Currency::free_balance(&org_id);
+			if !balance.is_zero() {
+				T::Currency::transfer(&org_id, &org.admin, balance, AllowDeath)?;
+			}
+
+			for account_id in <Members<T>>::take(&org_id).unwrap_or_default() {
+				<MembershipSince<T>>::remove(&org_id, &account_id);
+			}
+			<MembersRoot<T>>::remove(&org_id);
+			<LastAdminChange<T>>::remove(&org_id);
+			let allowances = <SpendAllowanceCount<T>>::take(&org_id);
+			let _ = <SpendAllowance<T>>::clear_prefix(&org_id, allowances, None);
+			<OrganizationFlagData<T>>::remove(&org_id);
+			if let Some(index) = <IndexOfOrganization<T>>::take(&org_id) {
+				<OrganizationIndexOf<T>>::remove(index);
+			}
+			<Organizations<T>>::remove(&org_id);
+
+			if let Some((depositor, deposit)) = <CreationDeposits<T>>::take(&org_id) {
+				T::Currency::unreserve(&depositor, deposit);
+			}
+
+			Self::deposit_event(Event::OrganizationDeleted(org_id));
+
+			Ok(Some(<T as Config>::WeightInfo::delete(T::MaxMemberCount::get(), delegates)).into())
+		}
 	}
 
 	// -------------------------------------------------------------------
@@ -927,19 +1221,60 @@ macro_rules! method_is_flag {
 }
 
 /// The main implementation of this Organization pallet.
+impl<T: Config> Pallet<T>
+where
+	T::AccountId: UncheckedFrom<T::Hash>,
+{
+	/// Organization id of organization registered at `index`,
+	/// hash of the index and the organization fields.
+	pub fn generate_org_id(
+		index: u64,
+		name: &[u8],
+		description: &[u8],
+		website: &[u8],
+		email: &[u8],
+	) -> T::AccountId {
+		UncheckedFrom::unchecked_from(T::Hashing::hash(
+			&index
+				.to_le_bytes()
+				.iter()
+				.chain(name.iter())
+				.chain(description.iter())
+				.chain(website.iter())
+				.chain(email.iter())
+				.cloned()
+				.collect::<Vec<u8>>(),
+		))
+	}
+
+	/// Organization id the next `create` with the given fields would produce,
+	/// only holds as long as no other organization is created in between.
+	pub fn predicted_org_id(
+		name: &[u8],
+		description: &[u8],
+		website: &[u8],
+		email: &[u8],
+	) -> T::AccountId {
+		let index = Self::organization_count().saturating_add(1);
+		Self::generate_org_id(index, name, description, website, email)
+	}
+}
+
 impl<T: Config> Pallet<T> {
 	/// Validasi properties
 	pub fn validate_props(props: &Option<Vec<Property<Text, Text>>>) -> Result<(), Error<T>> {
-		if let Some(props) = props {
-			ensure!(props.len() <= MAX_PROPS, Error::<T>::TooManyProps);
-			for prop in props {
-				let len = prop.name().len();
-				ensure!(len > 0 && len <= PROP_NAME_MAX_LENGTH, Error::<T>::InvalidPropName);
-				let len = prop.value().len();
-				ensure!(len > 0 && len <= PROP_VALUE_MAX_LENGTH, Error::<T>::InvalidPropValue);
+		let props = match props {
+			Some(props) => props,
+			None => return Ok(()),
+		};
+		check_props(props, MAX_PROPS, PROP_NAME_MAX_LENGTH, PROP_VALUE_MAX_LENGTH).map_err(|e| {
+			match e {
+				PropError::TooMany => Error::<T>::TooManyProps,
+				PropError::InvalidName => Error::<T>::InvalidPropName,
+				PropError::InvalidValue => Error::<T>::InvalidPropValue,
+				PropError::InvalidValueType => Error::<T>::InvalidPropValueType,
 			}
-		}
-		Ok(())
+		})
 	}
 
 	/// Memastikan origin dapat akses resource.
@@ -960,6 +1295,12 @@ impl<T: Config> Pallet<T> {
 		Ok(org)
 	}
 
+	/// Check whether account is the admin or a valid `OrgAdmin` delegate of the organization,
+	/// returns false when the organization does not exist.
+	pub fn has_admin_access(org_id: &T::AccountId, who: &T::AccountId) -> bool {
+		Self::ensure_access(who, org_id).is_ok()
+	}
+
 	/// Memastikan bahwa akun memiliki akses pada organisasi.
 	/// bukan hanya akses, ini juga memastikan organisasi dalam posisi tidak suspended.
 	pub fn ensure_access_active_id(
@@ -987,6 +1328,12 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Get number of organizations ever registered,
+	/// `OrganizationIndexOf` indexes run from 1 up to this number.
+	pub fn organization_count() -> u64 {
+		<OrgIdIndex<T>>::get().unwrap_or(0)
+	}
+
 	/// Get next Organization ID
 	pub fn next_index() -> Result<u64, Error<T>> {
 		<OrgIdIndex<T>>::mutate(|o| {
@@ -1003,6 +1350,106 @@ impl<T: Config> Pallet<T> {
 			.unwrap_or(false)
 	}
 
+	/// Change organization admin, when `add_member` is true the new admin
+	/// is added to organization members if not a member yet.
+	fn do_set_admin(
+		origin: OriginFor<T>,
+		org_id: T::AccountId,
+		account_id: T::AccountId,
+		add_member: bool,
+	) -> DispatchResultWithPostInfo {
+		let who = ensure_signed(origin.clone())?;
+
+		// harus member terlebih dahulu untuk jadi admin
+		let is_member = Self::is_member(&org_id, &account_id);
+		ensure!(is_member || add_member, Error::<T>::NotMember);
+
+		let org = Organizations::<T>::get(&org_id).ok_or(Error::<T>::NotExists)?;
+
+		let now = <frame_system::Pallet<T>>::block_number();
+		let by_admin = org.admin == who;
+		if !by_admin {
+			T::ForceOrigin::ensure_origin(origin)?;
+		} else {
+			ensure!(!org.suspended, Error::<T>::Suspended);
+			if let Some(last) = Self::last_admin_change(&org_id) {
+				ensure!(
+					now >= last.saturating_add(T::AdminChangeCooldown::get()),
+					Error::<T>::AdminChangeTooSoon
+				);
+			}
+		}
+
+		ensure!(org.admin != account_id, Error::<T>::AlreadySet);
+
+		if !is_member {
+			let mut members = <Members<T>>::get(&org_id).unwrap_or_default();
+			let pos = members.binary_search(&account_id).unwrap_or_else(|e| e);
+			members
+				.try_insert(pos, account_id.clone())
+				.map_err(|_| Error::<T>::MaxMemberReached)?;
+			<MembersRoot<T>>::insert(&org_id, Self::compute_members_root(&members));
+			<Members<T>>::insert(&org_id, members);
+			<MembershipSince<T>>::insert(&org_id, &account_id, now);
+			Self::deposit_event(Event::MemberAdded(org_id.clone(), account_id.clone()));
+		}
+
+		if by_admin {
+			LastAdminChange::<T>::insert(&org_id, now);
+		}
+
+		<Organizations<T>>::mutate(&org_id, |org| {
+			if let Some(org) = org {
+				org.admin = account_id.clone();
+			}
+		});
+
+		Self::deposit_event(Event::AdminChanged(org_id, account_id));
+
+		Ok(().into())
+	}
+
+	/// Remove accounts from organization members in one pass,
+	/// returns number of accounts actually removed.
+	pub fn do_remove_members(
+		org_id: &T::AccountId,
+		mut accounts: Vec<T::AccountId>,
+	) -> Result<u32, Error<T>> {
+		let members = <Members<T>>::get(org_id).ok_or(Error::<T>::NotExists)?;
+
+		accounts.sort();
+		accounts.dedup();
+
+		let (removed, kept): (Vec<T::AccountId>, Vec<T::AccountId>) =
+			members.into_iter().partition(|a| accounts.binary_search(a).is_ok());
+
+		if removed.is_empty() {
+			return Ok(0)
+		}
+
+		let members: BoundedVec<T::AccountId, T::MaxMemberCount> =
+			kept.try_into().map_err(|_| Error::<T>::MaxMemberReached)?;
+		<MembersRoot<T>>::insert(org_id, Self::compute_members_root(&members));
+		<Members<T>>::insert(org_id, members);
+
+		for account_id in removed.iter() {
+			<MembershipSince<T>>::remove(org_id, account_id);
+			Self::deposit_event(Event::MemberRemoved(org_id.clone(), account_id.clone()));
+		}
+
+		Ok(removed.len() as u32)
+	}
+
+	/// Blake2-256 hash of the SCALE encoded member list sorted ascending.
+	///
+	/// Clients can hash a fetched member list the same way and compare it
+	/// with [`Pallet::members_root`].
+	pub fn compute_members_root(members: &[T::AccountId]) -> [u8; 32] {
+		let mut members = members.to_vec();
+		members.sort();
+		sp_io::hashing::blake2_256(&members.encode())
+	}
+
 	/// Check whether the ID is organization account.
 	pub fn is_organization(id: &T::AccountId) -> bool {
 		Self::organization(id).is_some()
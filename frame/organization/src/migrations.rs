@@ -0,0 +1,250 @@
+// This file is part of Nuchain.
+//
+// Copyright (C) 2021-2022 Rantai Nusantara Foundation..
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Storage migrations for the organization pallet.
+
+use super::*;
+use frame_support::{
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
+
+pub mod v1 {
+	use super::*;
+
+	/// Baseline migration that introduces the storage version.
+	///
+	/// The `Organizations` layout is unchanged in v1, existing entries are kept as they are,
+	/// only the on-chain storage version is set. Future layout changes should add a new
+	/// module next to this one that translates from v1.
+	pub struct MigrateToV1<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain = Pallet::<T>::on_chain_storage_version();
+
+			if onchain < 1 {
+				StorageVersion::new(1).put::<Pallet<T>>();
+				T::DbWeight::get().reads_writes(1, 1)
+			} else {
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+
+			Self::set_temp_storage(Organizations::<T>::iter().count() as u32, "org_count");
+			Ok(())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+
+			let org_count: u32 =
+				Self::get_temp_storage("org_count").ok_or("org count not stored in pre_upgrade")?;
+			ensure!(
+				Organizations::<T>::iter().count() as u32 == org_count,
+				"organization count changed by migration"
+			);
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 1,
+				"storage version not updated by migration"
+			);
+			Ok(())
+		}
+	}
+}
+
+pub mod v2 {
+	use super::*;
+	use frame_support::BoundedVec;
+
+	#[derive(Decode)]
+	struct OldProperty<NameT, ValueT> {
+		name: NameT,
+		value: ValueT,
+	}
+
+	type OldOrganization<T> = types::Organization<
+		<T as frame_system::Config>::AccountId,
+		<T as frame_system::Config>::BlockNumber,
+		BoundedVec<u8, <T as Config>::MaxLength>,
+		BoundedVec<
+			OldProperty<
+				BoundedVec<u8, <T as Config>::MaxLength>,
+				BoundedVec<u8, <T as Config>::MaxLength>,
+			>,
+			<T as Config>::MaxLength,
+		>,
+	>;
+
+	/// Add the value type to stored organization properties.
+	///
+	/// Properties of existing organizations get no value type.
+	pub struct MigrateToV2<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain = Pallet::<T>::on_chain_storage_version();
+			if onchain >= 2 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut translated: Weight = 0;
+			Organizations::<T>::translate::<OldOrganization<T>, _>(|_, org| {
+				translated = translated.saturating_add(1);
+				Some(types::Organization {
+					id: org.id,
+					name: org.name,
+					description: org.description,
+					admin: org.admin,
+					website: org.website,
+					email: org.email,
+					suspended: org.suspended,
+					block: org.block,
+					timestamp: org.timestamp,
+					props: org.props.and_then(|ps| {
+						ps.into_iter()
+							.map(|p| Property::from_parts(p.name, p.value, None))
+							.collect::<Vec<_>>()
+							.try_into()
+							.ok()
+					}),
+				})
+			});
+
+			StorageVersion::new(2).put::<Pallet<T>>();
+			T::DbWeight::get()
+				.reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+
+			Self::set_temp_storage(Organizations::<T>::iter_keys().count() as u32, "org_count");
+			Ok(())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+
+			let org_count: u32 =
+				Self::get_temp_storage("org_count").ok_or("org count not stored in pre_upgrade")?;
+			ensure!(
+				Organizations::<T>::iter().count() as u32 == org_count,
+				"organization count changed by migration"
+			);
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 2,
+				"storage version not updated by migration"
+			);
+			Ok(())
+		}
+	}
+}
+
+pub mod v3 {
+	use super::*;
+
+	/// Backfill [`IndexOfOrganization`] for organizations created before it was introduced,
+	/// count the spend allowances of every organization into [`SpendAllowanceCount`]
+	/// and compute the [`MembersRoot`] of every member set.
+	///
+	/// Index entries of organizations that were already deleted are removed.
+	pub struct MigrateToV3<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV3<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain = Pallet::<T>::on_chain_storage_version();
+			if onchain >= 3 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut reads: Weight = 1;
+			let mut writes: Weight = 1;
+
+			for (index, org_id) in OrganizationIndexOf::<T>::iter() {
+				reads = reads.saturating_add(2);
+				writes = writes.saturating_add(1);
+				if Organizations::<T>::contains_key(&org_id) {
+					IndexOfOrganization::<T>::insert(&org_id, index);
+				} else {
+					OrganizationIndexOf::<T>::remove(index);
+				}
+			}
+
+			for (org_id, _delegate) in SpendAllowance::<T>::iter_keys() {
+				reads = reads.saturating_add(2);
+				writes = writes.saturating_add(1);
+				SpendAllowanceCount::<T>::mutate(&org_id, |count| *count = count.saturating_add(1));
+			}
+
+			for (org_id, members) in Members::<T>::iter() {
+				reads = reads.saturating_add(1);
+				writes = writes.saturating_add(1);
+				MembersRoot::<T>::insert(&org_id, Pallet::<T>::compute_members_root(&members));
+			}
+
+			StorageVersion::new(3).put::<Pallet<T>>();
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+
+			Self::set_temp_storage(Organizations::<T>::iter_keys().count() as u32, "org_count");
+			Ok(())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+
+			let org_count: u32 =
+				Self::get_temp_storage("org_count").ok_or("org count not stored in pre_upgrade")?;
+			ensure!(
+				Organizations::<T>::iter().count() as u32 == org_count,
+				"organization count changed by migration"
+			);
+			for (index, org_id) in OrganizationIndexOf::<T>::iter() {
+				ensure!(
+					IndexOfOrganization::<T>::get(&org_id) == Some(index),
+					"organization index not backfilled by migration"
+				);
+			}
+			for (org_id, members) in Members::<T>::iter() {
+				ensure!(
+					MembersRoot::<T>::get(&org_id) ==
+						Some(Pallet::<T>::compute_members_root(&members)),
+					"members root not backfilled by migration"
+				);
+			}
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 3,
+				"storage version not updated by migration"
+			);
+			Ok(())
+		}
+	}
+}
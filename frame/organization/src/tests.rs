@@ -21,7 +21,8 @@ use crate as pallet_organization;
 
 use frame_support::{
 	assert_err_ignore_postinfo, assert_noop, assert_ok, ord_parameter_types, parameter_types,
-	traits::{ConstU32, Everything},
+	traits::{ConstU32, ConstU64, Everything, StorageMapShim},
+	types::PropValueType,
 };
 use frame_system::EnsureSignedBy;
 use sp_core::{sr25519, H256};
@@ -42,6 +43,7 @@ frame_support::construct_runtime!(
 	{
 		System: frame_system,
 		Balances: pallet_balances,
+		SecondaryBalances: pallet_balances::<Instance1>,
 		Did: pallet_did,
 		Timestamp: pallet_timestamp,
 		Organization: pallet_organization,
@@ -96,6 +98,23 @@ impl pallet_balances::Config for Test {
 	type WeightInfo = ();
 }
 
+impl pallet_balances::Config<pallet_balances::Instance1> for Test {
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u64;
+	type Event = Event;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = StorageMapShim<
+		pallet_balances::Account<Test, pallet_balances::Instance1>,
+		frame_system::Provider<Test>,
+		AccountId,
+		pallet_balances::AccountData<u64>,
+	>;
+	type WeightInfo = ();
+}
+
 impl pallet_timestamp::Config for Test {
 	type Moment = u64;
 	type OnTimestampSet = ();
@@ -110,6 +129,16 @@ impl pallet_did::Config for Test {
 	type Time = Timestamp;
 	type WeightInfo = pallet_did::weights::SubstrateWeight<Self>;
 	type MaxLength = ConstU32<64>;
+	type MaxDelegateTypeLength = ConstU32<64>;
+	type MaxAttributeNameLength = ConstU32<64>;
+	type MaxServiceIdLength = ConstU32<32>;
+	type MaxServiceTypeLength = ConstU32<32>;
+	type MaxServiceEndpointLength = ConstU32<64>;
+	type MaxServicePerDid = ConstU32<2>;
+	type MaxAttributes = ConstU32<4>;
+	type Currency = Balances;
+	type AttributeDeposit = DidAttributeDeposit;
+	type DelegateDeposit = ConstU64<0>;
 }
 
 parameter_types! {
@@ -117,6 +146,34 @@ parameter_types! {
 	pub const MaxOrgNameLength: u32 = 16;
 	pub const MaxMemberCount: u32 = 5;
 	pub const CreationFee: u64 = 20;
+	pub const SecondaryCreationFee: u64 = 7;
+	pub const CreationDeposit: u64 = 5;
+	pub static DeleteAllowed: bool = true;
+	// DID attribute deposit, disabled unless a test sets it
+	pub static DidAttributeDeposit: u64 = 0;
+	// charge the creation fee in `SecondaryBalances` instead of `Balances`
+	pub static FeeInSecondary: bool = false;
+}
+
+pub struct TestCreationFee;
+impl CreationFeeHandler<AccountId> for TestCreationFee {
+	fn charge(who: &AccountId) -> DispatchResult {
+		if FeeInSecondary::get() {
+			CurrencyCreationFee::<AccountId, SecondaryBalances, SecondaryCreationFee, ()>::charge(
+				who,
+			)
+		} else {
+			NativeCreationFee::<Test>::charge(who)
+		}
+	}
+}
+
+pub struct TestDeleteGuard;
+impl OrganizationDeleteGuard<AccountId> for TestDeleteGuard {
+	fn ensure_can_delete(_org_id: &AccountId) -> DispatchResult {
+		ensure!(DeleteAllowed::get(), DispatchError::Other("organization in use"));
+		Ok(())
+	}
 }
 
 lazy_static::lazy_static! {
@@ -141,6 +198,9 @@ impl Config for Test {
 	type CreationFee = CreationFee;
 	type Currency = Balances;
 	type Payment = ();
+	type CreationFeeHandler = TestCreationFee;
+	type CreationDeposit = CreationDeposit;
+	type DeleteGuard = TestDeleteGuard;
 	type ForceOrigin = EnsureSignedBy<One, sr25519::Public>;
 	type MinOrgNameLength = MinOrgNameLength;
 	type MaxOrgNameLength = MaxOrgNameLength;
@@ -150,6 +210,7 @@ impl Config for Test {
 	type Signature = sr25519::Signature;
 	type Did = Did;
 	type MaxLength = ConstU32<64>;
+	type AdminChangeCooldown = ConstU64<10>;
 	// type MaxHandledOrgCount = ConstU32<32>;
 }
 
@@ -160,7 +221,13 @@ macro_rules! assert_bounded_vec {
 		let ret_props: Vec<Property<Text, Text>> = $a
 			.clone()
 			.into_iter()
-			.map(|p| Property::new(p.name().to_vec(), p.value().to_vec()))
+			.map(|p| {
+				let prop = Property::new(p.name().to_vec(), p.value().to_vec());
+				match p.value_type() {
+					Some(value_type) => prop.with_value_type(value_type),
+					None => prop,
+				}
+			})
 			.collect();
 		assert_eq!(ret_props, $b);
 	}};
@@ -171,6 +238,11 @@ fn new_test_ext() -> sp_io::TestExternalities {
 	pallet_balances::GenesisConfig::<Test> { balances: vec![(*ALICE, 50), (*BOB, 10)] }
 		.assimilate_storage(&mut t)
 		.unwrap();
+	pallet_balances::GenesisConfig::<Test, pallet_balances::Instance1> {
+		balances: vec![(*ALICE, 30)],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
 	t.into()
 }
 
@@ -238,7 +310,7 @@ fn insufficient_balance_cannot_create() {
 				b"".to_vec(),
 				None
 			),
-			pallet_balances::Error::<Test, _>::InsufficientBalance
+			pallet_balances::Error::<Test, ()>::InsufficientBalance
 		);
 		assert_eq!(Balances::total_balance(&*BOB), 10);
 	});
@@ -355,6 +427,58 @@ fn create_organization_with_properties() {
 	});
 }
 
+#[test]
+fn create_organization_with_typed_properties() {
+	new_test_ext().execute_with(|| {
+		let props = vec![
+			Property::new(b"nama".to_vec(), b"Nusantara".to_vec())
+				.with_value_type(PropValueType::String),
+			Property::new(b"karyawan".to_vec(), b"120".to_vec())
+				.with_value_type(PropValueType::Number),
+			Property::new(b"berdiri".to_vec(), b"2021-05-20".to_vec())
+				.with_value_type(PropValueType::Date),
+			Property::new(b"logo".to_vec(), b"https://nuchain.network/logo.png".to_vec())
+				.with_value_type(PropValueType::Uri),
+			Property::new(b"catatan".to_vec(), b"-".to_vec()),
+		];
+		assert_ok!(Organization::create(
+			Origin::signed(*ALICE),
+			b"ORG1".to_vec(),
+			b"ORG1 DESCRIPTION".to_vec(),
+			*BOB,
+			b"".to_vec(),
+			b"".to_vec(),
+			Some(props.clone())
+		));
+
+		let org_id = Organization::organization_index(1).unwrap();
+		let org = Organization::organization(org_id).unwrap();
+		let org_props = org.props.unwrap();
+		assert_bounded_vec!(org_props, props);
+		assert_eq!(org_props[2].value_type(), Some(PropValueType::Date));
+		assert_eq!(org_props[4].value_type(), None);
+	});
+}
+
+#[test]
+fn create_organization_with_unknown_prop_value_type() {
+	new_test_ext().execute_with(|| {
+		let props = vec![Property::from_parts(b"satu".to_vec(), b"1".to_vec(), Some(9))];
+		assert_noop!(
+			Organization::create(
+				Origin::signed(*ALICE),
+				b"ORG1".to_vec(),
+				b"ORG1 DESCRIPTION".to_vec(),
+				*BOB,
+				b"".to_vec(),
+				b"".to_vec(),
+				Some(props)
+			),
+			Error::<Test>::InvalidPropValueType
+		);
+	});
+}
+
 #[test]
 fn create_organization_with_too_many_props() {
 	new_test_ext().execute_with(|| {
@@ -454,6 +578,48 @@ fn create_organization_with_invalid_prop_value() {
 	});
 }
 
+#[test]
+fn create_organization_with_control_char_props() {
+	new_test_ext().execute_with(|| {
+		let props = vec![Property::new(b"na\nme".to_vec(), b"1".to_vec())];
+		assert_noop!(
+			Organization::create(
+				Origin::signed(*ALICE),
+				b"ORG1".to_vec(),
+				b"ORG1 DESCRIPTION".to_vec(),
+				*BOB,
+				b"".to_vec(),
+				b"".to_vec(),
+				Some(props.clone())
+			),
+			Error::<Test>::InvalidPropName
+		);
+		let props = vec![Property::new(b"name".to_vec(), b"val\x00ue".to_vec())];
+		assert_noop!(
+			Organization::create(
+				Origin::signed(*ALICE),
+				b"ORG1".to_vec(),
+				b"ORG1 DESCRIPTION".to_vec(),
+				*BOB,
+				b"".to_vec(),
+				b"".to_vec(),
+				Some(props.clone())
+			),
+			Error::<Test>::InvalidPropValue
+		);
+		let props = vec![Property::new(b"name".to_vec(), b"Jl. Merdeka No. 1".to_vec())];
+		assert_ok!(Organization::create(
+			Origin::signed(*ALICE),
+			b"ORG1".to_vec(),
+			b"ORG1 DESCRIPTION".to_vec(),
+			*BOB,
+			b"".to_vec(),
+			b"".to_vec(),
+			Some(props)
+		));
+	});
+}
+
 #[test]
 fn set_flags_works() {
 	new_test_ext().execute_with(|| {
@@ -634,6 +800,51 @@ fn update_works() {
 			assert_eq!(org.email, new_email.to_vec());
 			assert_eq!(org.suspended, false);
 			assert_bounded_vec!(org.props.clone().unwrap(), new_props);
+			assert_eq!(
+				last_event(),
+				OrgEvent::OrganizationUpdated(
+					org_id,
+					CHANGED_NAME |
+						CHANGED_DESCRIPTION | CHANGED_WEBSITE |
+						CHANGED_EMAIL | CHANGED_PROPS
+				)
+			);
+		});
+	});
+}
+
+#[test]
+fn update_email_only_reports_email_changed() {
+	new_test_ext().execute_with(|| {
+		with_org(|org_id, _index| {
+			// nama sama dengan sebelumnya, tidak dihitung sebagai perubahan
+			assert_ok!(Organization::update(
+				Origin::signed(*BOB),
+				org_id.clone(),
+				Some(b"ORG1".to_vec()),
+				None,
+				None,
+				Some(b"info@org1.org".to_vec()),
+				None
+			));
+			assert_eq!(last_event(), OrgEvent::OrganizationUpdated(org_id, CHANGED_EMAIL));
+			assert_eq!(
+				Organization::organization(&org_id).unwrap().email,
+				b"info@org1.org".to_vec()
+			);
+
+			assert_err_ignore_postinfo!(
+				Organization::update(
+					Origin::signed(*BOB),
+					org_id.clone(),
+					None,
+					None,
+					None,
+					Some(b"info@org1.org".to_vec()),
+					None
+				),
+				Error::<Test>::NotChanged
+			);
 		});
 	});
 }
@@ -956,3 +1167,746 @@ fn delegated_admin_cannot_transfer_value() {
 		});
 	});
 }
+
+#[test]
+fn delegated_admin_can_transfer_within_allowance() {
+	new_test_ext().execute_with(|| {
+		with_org(|org_id, _index| {
+			let _ = Balances::deposit_creating(&org_id, 20);
+
+			assert_ok!(Organization::delegate_access(
+				Origin::signed(*BOB),
+				org_id,
+				*CHARLIE,
+				Some(5)
+			));
+
+			// hanya super admin yang bisa mengatur allowance
+			assert_err_ignore_postinfo!(
+				Organization::set_spend_allowance(
+					Origin::signed(*CHARLIE),
+					org_id,
+					*CHARLIE,
+					Some(8)
+				),
+				Error::<Test>::PermissionDenied
+			);
+			assert_ok!(Organization::set_spend_allowance(
+				Origin::signed(*BOB),
+				org_id,
+				*CHARLIE,
+				Some(8)
+			));
+			assert_eq!(last_event(), OrgEvent::SpendAllowanceSet(org_id, *CHARLIE, Some(8)));
+			assert_eq!(Organization::spend_allowance_count(org_id), 1);
+
+			assert_ok!(Organization::transfer(Origin::signed(*CHARLIE), org_id, *DAVE, 5));
+			assert_eq!(Organization::spend_allowance(org_id, *CHARLIE), Some(3));
+			assert_eq!(Balances::free_balance(&org_id), 15);
+
+			// melebihi sisa allowance
+			assert_err_ignore_postinfo!(
+				Organization::transfer(Origin::signed(*CHARLIE), org_id, *DAVE, 4),
+				Error::<Test>::AllowanceExceeded
+			);
+			assert_eq!(Organization::spend_allowance(org_id, *CHARLIE), Some(3));
+			assert_eq!(Balances::free_balance(&org_id), 15);
+
+			// super admin tidak dibatasi allowance
+			assert_ok!(Organization::transfer(Origin::signed(*BOB), org_id, *DAVE, 10));
+			assert_eq!(Balances::free_balance(&org_id), 5);
+
+			assert_ok!(Organization::set_spend_allowance(
+				Origin::signed(*BOB),
+				org_id,
+				*CHARLIE,
+				None
+			));
+			assert_eq!(Organization::spend_allowance(org_id, *CHARLIE), None);
+			assert_eq!(Organization::spend_allowance_count(org_id), 0);
+			assert_err_ignore_postinfo!(
+				Organization::transfer(Origin::signed(*CHARLIE), org_id, *DAVE, 1),
+				Error::<Test>::PermissionDenied
+			);
+		});
+	});
+}
+
+#[test]
+fn allowance_requires_valid_delegation() {
+	new_test_ext().execute_with(|| {
+		with_org(|org_id, _index| {
+			let _ = Balances::deposit_creating(&org_id, 20);
+
+			// allowance tanpa delegasi akses tidak berlaku
+			assert_ok!(Organization::set_spend_allowance(
+				Origin::signed(*BOB),
+				org_id,
+				*DAVE,
+				Some(8)
+			));
+			assert_err_ignore_postinfo!(
+				Organization::transfer(Origin::signed(*DAVE), org_id, *EVE, 5),
+				Error::<Test>::PermissionDenied
+			);
+			assert_eq!(Balances::free_balance(&org_id), 20);
+		});
+	});
+}
+
+#[test]
+fn migrate_to_v1_keeps_organizations() {
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade};
+
+	new_test_ext().execute_with(|| {
+		with_org(|org_id, _index| {
+			with_org(|org_id2, _index| {
+				StorageVersion::new(0).put::<Pallet<Test>>();
+				assert_eq!(Organization::on_chain_storage_version(), 0);
+
+				migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+				assert_eq!(Organization::on_chain_storage_version(), 1);
+				assert_eq!(Organizations::<Test>::iter().count(), 2);
+				assert_eq!(Organization::organization(org_id).unwrap().name, b"ORG1".to_vec());
+				assert_eq!(Organization::organization(org_id2).unwrap().name, b"ORG1".to_vec());
+			});
+		});
+	});
+}
+
+#[test]
+fn migrate_to_v2_keeps_organization_props() {
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade};
+
+	#[derive(Encode)]
+	struct OldProperty {
+		name: Vec<u8>,
+		value: Vec<u8>,
+	}
+
+	new_test_ext().execute_with(|| {
+		with_org(|org_id, _index| {
+			let org = Organization::organization(org_id).unwrap();
+			let mut old = (
+				&org.id,
+				&org.name,
+				&org.description,
+				&org.admin,
+				&org.website,
+				&org.email,
+				org.suspended,
+				org.block,
+				org.timestamp,
+			)
+				.encode();
+			Some(vec![OldProperty { name: b"satu".to_vec(), value: b"1".to_vec() }])
+				.encode_to(&mut old);
+			frame_support::storage::unhashed::put_raw(
+				&Organizations::<Test>::hashed_key_for(org_id),
+				&old,
+			);
+			StorageVersion::new(1).put::<Pallet<Test>>();
+
+			migrations::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+
+			assert_eq!(Organization::on_chain_storage_version(), 2);
+			let migrated = Organization::organization(org_id).unwrap();
+			assert_eq!(migrated.name, org.name);
+			assert_bounded_vec!(
+				migrated.props.unwrap(),
+				vec![Property::new(b"satu".to_vec(), b"1".to_vec())]
+			);
+		});
+	});
+}
+
+#[test]
+fn organization_paged_by_index() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// enough to pay creation fee of three organizations
+		let _ = Balances::deposit_creating(&*ALICE, 50);
+
+		assert_eq!(Organization::organization_count(), 0);
+		assert_eq!(Organization::organization_index(1), None);
+
+		let mut org_ids = vec![];
+		for name in [b"ORG1", b"ORG2", b"ORG3"] {
+			assert_ok!(Organization::create(
+				Origin::signed(*ALICE),
+				name.to_vec(),
+				b"ORG DESCRIPTION".to_vec(),
+				*BOB,
+				b"".to_vec(),
+				b"".to_vec(),
+				None
+			));
+			org_ids.push(last_org_id().unwrap());
+		}
+
+		assert_eq!(Organization::organization_count(), 3);
+		let paged: Vec<_> = (1..=Organization::organization_count())
+			.filter_map(Organization::organization_index)
+			.collect();
+		assert_eq!(paged, org_ids);
+		assert_eq!(Organization::organization(paged[1]).unwrap().name, b"ORG2".to_vec());
+		assert_eq!(Organization::organization_index(4), None);
+	});
+}
+
+#[test]
+fn set_admin_cooldown() {
+	new_test_ext().execute_with(|| {
+		with_org(|org_id, _index| {
+			assert_ok!(Organization::add_members(
+				Origin::signed(*BOB),
+				org_id,
+				vec![*CHARLIE, *DAVE]
+			));
+
+			System::set_block_number(1);
+			assert_ok!(Organization::set_admin(Origin::signed(*BOB), org_id, *CHARLIE));
+			assert_eq!(Organization::last_admin_change(org_id), Some(1));
+
+			// within cooldown
+			System::set_block_number(10);
+			assert_err_ignore_postinfo!(
+				Organization::set_admin(Origin::signed(*CHARLIE), org_id, *DAVE),
+				Error::<Test>::AdminChangeTooSoon
+			);
+			assert_eq!(Organization::get_admin(org_id), Some(*CHARLIE));
+
+			// cooldown elapsed
+			System::set_block_number(11);
+			assert_ok!(Organization::set_admin(Origin::signed(*CHARLIE), org_id, *DAVE));
+			assert_eq!(Organization::get_admin(org_id), Some(*DAVE));
+			assert_eq!(Organization::last_admin_change(org_id), Some(11));
+
+			// force origin bypasses the cooldown
+			assert_ok!(Organization::set_admin(Origin::signed(*ALICE), org_id, *CHARLIE));
+			assert_eq!(Organization::get_admin(org_id), Some(*CHARLIE));
+		});
+	});
+}
+
+#[test]
+fn members_root_follows_member_changes() {
+	new_test_ext().execute_with(|| {
+		with_org(|org_id, _index| {
+			let root = Organization::members_root(org_id).expect("root set on creation");
+			assert_eq!(root, sp_io::hashing::blake2_256(&vec![*BOB].encode()));
+
+			assert_ok!(Organization::add_members(
+				Origin::signed(*BOB),
+				org_id,
+				vec![*DAVE, *CHARLIE]
+			));
+			let added_root = Organization::members_root(org_id).unwrap();
+			assert_ne!(added_root, root);
+			// hash of the sorted member set regardless of the order it was fetched in
+			assert_eq!(added_root, Organization::compute_members_root(&[*DAVE, *BOB, *CHARLIE]));
+			assert_eq!(
+				added_root,
+				sp_io::hashing::blake2_256(&vec![*BOB, *CHARLIE, *DAVE].encode())
+			);
+			assert_eq!(
+				added_root,
+				Organization::compute_members_root(&Organization::members(org_id).unwrap())
+			);
+
+			assert_ok!(Organization::remove_member(Origin::signed(*BOB), org_id, *DAVE));
+			let removed_root = Organization::members_root(org_id).unwrap();
+			assert_ne!(removed_root, added_root);
+			assert_eq!(removed_root, Organization::compute_members_root(&[*CHARLIE, *BOB]));
+		});
+	});
+}
+
+#[test]
+fn creation_fee_charged_in_native_currency() {
+	new_test_ext().execute_with(|| {
+		with_org(|_org_id, _index| {
+			// creation deposit is reserved on top of the fee
+			assert_eq!(Balances::free_balance(*ALICE), 50 - 20 - 5);
+			assert_eq!(SecondaryBalances::free_balance(*ALICE), 30);
+		});
+	});
+}
+
+#[test]
+fn creation_fee_charged_in_secondary_currency() {
+	new_test_ext().execute_with(|| {
+		FeeInSecondary::set(true);
+		with_org(|_org_id, _index| {
+			assert_eq!(Balances::free_balance(*ALICE), 50 - 5);
+			assert_eq!(SecondaryBalances::free_balance(*ALICE), 30 - 7);
+		});
+		FeeInSecondary::set(false);
+	});
+}
+
+#[test]
+fn creation_fee_in_secondary_currency_requires_balance() {
+	new_test_ext().execute_with(|| {
+		FeeInSecondary::set(true);
+		assert_noop!(
+			Organization::create(
+				Origin::signed(*BOB),
+				b"ORG1".to_vec(),
+				b"ORG1 DESCRIPTION".to_vec(),
+				*BOB,
+				b"".to_vec(),
+				b"".to_vec(),
+				None
+			),
+			pallet_balances::Error::<Test, pallet_balances::Instance1>::InsufficientBalance
+		);
+		FeeInSecondary::set(false);
+	});
+}
+
+#[test]
+fn membership_since_tracks_member_changes() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(2);
+		with_org(|org_id, _index| {
+			assert_eq!(Organization::member_since(org_id, *BOB), Some(2));
+			assert_eq!(Organization::member_since(org_id, *DAVE), None);
+
+			System::set_block_number(5);
+			assert_ok!(Organization::add_members(Origin::signed(*BOB), org_id, vec![*DAVE]));
+			assert_eq!(Organization::member_since(org_id, *DAVE), Some(5));
+			assert_eq!(Organization::member_since(org_id, *BOB), Some(2));
+
+			System::set_block_number(7);
+			assert_ok!(Organization::remove_member(Origin::signed(*BOB), org_id, *DAVE));
+			assert_eq!(Organization::member_since(org_id, *DAVE), None);
+
+			// re-added member starts a new tenure
+			System::set_block_number(9);
+			assert_ok!(Organization::add_members(Origin::signed(*BOB), org_id, vec![*DAVE]));
+			assert_eq!(Organization::member_since(org_id, *DAVE), Some(9));
+		});
+	});
+}
+
+#[test]
+fn remove_members_ignores_non_members() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		with_org(|org_id, _index| {
+			assert_ok!(Organization::add_members(
+				Origin::signed(*BOB),
+				org_id,
+				vec![*CHARLIE, *DAVE, *EVE]
+			));
+			assert_noop!(
+				Organization::remove_members(Origin::signed(*DAVE), org_id, vec![*CHARLIE]),
+				Error::<Test>::PermissionDenied
+			);
+			assert_noop!(
+				Organization::remove_members(Origin::signed(*BOB), org_id, vec![]),
+				Error::<Test>::InvalidParameter
+			);
+
+			System::reset_events();
+			assert_ok!(Organization::remove_members(
+				Origin::signed(*BOB),
+				org_id,
+				vec![*EVE, *ALICE, *CHARLIE, *EVE]
+			));
+			assert_eq!(Organization::members(org_id).unwrap().into_inner(), {
+				let mut members = vec![*BOB, *DAVE];
+				members.sort();
+				members
+			});
+			assert_eq!(
+				Organization::members_root(org_id),
+				Some(Organization::compute_members_root(&[*BOB, *DAVE]))
+			);
+			assert_eq!(Organization::member_since(org_id, *EVE), None);
+			assert_eq!(Organization::member_since(org_id, *CHARLIE), None);
+			assert_eq!(Organization::member_since(org_id, *DAVE), Some(1));
+
+			let removed: Vec<_> = System::events()
+				.into_iter()
+				.filter_map(|r| match r.event {
+					Event::Organization(OrgEvent::MemberRemoved(_, who)) => Some(who),
+					_ => None,
+				})
+				.collect();
+			assert_eq!(removed.len(), 2);
+			assert!(removed.contains(&*EVE) && removed.contains(&*CHARLIE));
+
+			// nothing to remove
+			assert_ok!(Organization::remove_members(Origin::signed(*BOB), org_id, vec![*EVE]));
+			assert_eq!(Organization::do_remove_members(&org_id, vec![*EVE, *DAVE]).ok(), Some(1));
+			assert_eq!(Organization::members(org_id).unwrap().into_inner(), vec![*BOB]);
+		});
+	});
+}
+
+#[test]
+fn creation_deposit_reserved() {
+	new_test_ext().execute_with(|| {
+		with_org(|org_id, _index| {
+			assert_eq!(Balances::reserved_balance(*ALICE), 5);
+			assert_eq!(Organization::creation_deposit(org_id), Some((*ALICE, 5)));
+		});
+	});
+}
+
+#[test]
+fn did_attribute_deposit_reserved_from_creator() {
+	new_test_ext().execute_with(|| {
+		DidAttributeDeposit::set(3);
+		with_org(|org_id, _index| {
+			// the new organization account has no balance to reserve from
+			assert_eq!(Balances::total_balance(&org_id), 0);
+			assert_eq!(Balances::reserved_balance(*ALICE), 5 + 3);
+			let name: BoundedVec<u8, ConstU32<64>> = b"Org".to_vec().try_into().unwrap();
+			assert_eq!(Did::attribute_deposit((org_id, name)), Some((*ALICE, 3)));
+		});
+	});
+}
+
+#[test]
+fn delete_org_refunds_deposit() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		with_org(|org_id, index| {
+			assert_ok!(Organization::add_members(Origin::signed(*BOB), org_id, vec![*DAVE]));
+			assert_ok!(Organization::set_spend_allowance(
+				Origin::signed(*BOB),
+				org_id,
+				*CHARLIE,
+				Some(8)
+			));
+			assert_noop!(
+				Organization::delete(Origin::signed(*DAVE), org_id, 0),
+				Error::<Test>::PermissionDenied
+			);
+
+			assert_ok!(Organization::delete(Origin::signed(*BOB), org_id, 0));
+			assert_eq!(last_event(), OrgEvent::OrganizationDeleted(org_id));
+
+			assert!(Organization::organization(org_id).is_none());
+			assert!(Organization::organization_index(index).is_none());
+			assert!(Organization::index_of_organization(org_id).is_none());
+			assert!(Organization::members(org_id).is_none());
+			assert!(Organization::members_root(org_id).is_none());
+			assert!(Organization::member_since(org_id, *DAVE).is_none());
+			assert!(Organization::flags(org_id).is_none());
+			assert!(Organization::creation_deposit(org_id).is_none());
+			assert!(Organization::spend_allowance(org_id, *CHARLIE).is_none());
+			assert_eq!(Organization::spend_allowance_count(org_id), 0);
+
+			// deposit back to creator, fee is not refunded
+			assert_eq!(Balances::reserved_balance(*ALICE), 0);
+			assert_eq!(Balances::free_balance(*ALICE), 50 - 20);
+
+			assert_noop!(
+				Organization::delete(Origin::signed(*BOB), org_id, 0),
+				Error::<Test>::NotExists
+			);
+		});
+	});
+}
+
+#[test]
+fn delete_org_sweeps_balance_to_admin() {
+	new_test_ext().execute_with(|| {
+		with_org(|org_id, _index| {
+			let _ = Balances::deposit_creating(&org_id, 7);
+			let admin_balance = Balances::free_balance(*BOB);
+
+			assert_ok!(Organization::delete(Origin::signed(*BOB), org_id, 0));
+
+			assert_eq!(Balances::free_balance(&org_id), 0);
+			assert_eq!(Balances::free_balance(*BOB), admin_balance + 7);
+		});
+	});
+}
+
+#[test]
+fn delete_org_removes_did_state() {
+	new_test_ext().execute_with(|| {
+		DidAttributeDeposit::set(3);
+		with_org(|org_id, _index| {
+			assert_ok!(Organization::delegate_access(Origin::signed(*BOB), org_id, *DAVE, None));
+			assert_ok!(Organization::delegate_access_as(
+				Origin::signed(*BOB),
+				org_id,
+				*EVE,
+				b"Issuer".to_vec(),
+				None
+			));
+			assert_eq!(Did::owner_of(org_id), Some(*BOB));
+			assert_eq!(Did::valid_delegates_of(&org_id).len(), 2);
+
+			// the witness must cover all delegates of the organization
+			assert_noop!(
+				Organization::delete(Origin::signed(*BOB), org_id, 1),
+				pallet_did::Error::<Test>::TooManyDelegates
+			);
+
+			assert_ok!(Organization::delete(Origin::signed(*BOB), org_id, 2));
+
+			assert!(Did::owner_of(org_id).is_none());
+			assert!(Did::valid_delegates_of(&org_id).is_empty());
+			assert!(Did::attributes_of(&org_id).is_empty());
+			assert!(Did::attribute_names_of(org_id).is_empty());
+			let name: BoundedVec<u8, ConstU32<64>> = b"Org".to_vec().try_into().unwrap();
+			assert!(Did::attribute_deposit((org_id, name)).is_none());
+			let delegate_type: BoundedVec<u8, ConstU32<64>> =
+				b"OrgAdmin".to_vec().try_into().unwrap();
+			assert!(Did::delegate_of((org_id, delegate_type, *DAVE)).is_none());
+
+			// the attribute deposit is back to the creator
+			assert_eq!(Balances::reserved_balance(*ALICE), 0);
+		});
+	});
+}
+
+#[test]
+fn spend_allowances_limited_to_max_member_count() {
+	new_test_ext().execute_with(|| {
+		with_org(|org_id, _index| {
+			let max = MaxMemberCount::get();
+			for i in 0..max {
+				assert_ok!(Organization::set_spend_allowance(
+					Origin::signed(*BOB),
+					org_id,
+					sr25519::Public::from_raw([100 + i as u8; 32]),
+					Some(1)
+				));
+			}
+			assert_eq!(Organization::spend_allowance_count(org_id), max);
+
+			assert_err_ignore_postinfo!(
+				Organization::set_spend_allowance(
+					Origin::signed(*BOB),
+					org_id,
+					*CHARLIE,
+					Some(1)
+				),
+				Error::<Test>::TooManyAllowances
+			);
+
+			// mengubah allowance yang sudah ada tetap bisa
+			let delegate = sr25519::Public::from_raw([100; 32]);
+			assert_ok!(Organization::set_spend_allowance(
+				Origin::signed(*BOB),
+				org_id,
+				delegate,
+				Some(2)
+			));
+			assert_eq!(Organization::spend_allowance_count(org_id), max);
+
+			// menghapus allowance yang tidak ada tidak mengubah jumlah
+			assert_ok!(Organization::set_spend_allowance(
+				Origin::signed(*BOB),
+				org_id,
+				*CHARLIE,
+				None
+			));
+			assert_eq!(Organization::spend_allowance_count(org_id), max);
+
+			assert_ok!(Organization::delete(Origin::signed(*BOB), org_id, 0));
+			assert_eq!(SpendAllowance::<Test>::iter_prefix(org_id).count(), 0);
+		});
+	});
+}
+
+#[test]
+fn migrate_to_v3_backfills_index_of_organization() {
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade};
+
+	new_test_ext().execute_with(|| {
+		with_org(|org_id, index| {
+			assert_ok!(Organization::add_members(Origin::signed(*BOB), org_id, vec![*DAVE]));
+			let members_root = Organization::members_root(org_id);
+			assert!(members_root.is_some());
+			assert_ok!(Organization::set_spend_allowance(
+				Origin::signed(*BOB),
+				org_id,
+				*CHARLIE,
+				Some(8)
+			));
+
+			// organisasi lama, dibuat sebelum ada index balik dan hitungan allowance
+			IndexOfOrganization::<Test>::remove(org_id);
+			SpendAllowanceCount::<Test>::remove(org_id);
+			MembersRoot::<Test>::remove(org_id);
+			// index dari organisasi yang sudah dihapus
+			OrganizationIndexOf::<Test>::insert(index + 1, *EVE);
+			StorageVersion::new(2).put::<Pallet<Test>>();
+
+			migrations::v3::MigrateToV3::<Test>::on_runtime_upgrade();
+
+			assert_eq!(Organization::on_chain_storage_version(), STORAGE_VERSION);
+			assert_eq!(Organization::index_of_organization(org_id), Some(index));
+			assert_eq!(Organization::organization_index(index + 1), None);
+			assert_eq!(Organization::spend_allowance_count(org_id), 1);
+			assert_eq!(Organization::members_root(org_id), members_root);
+		});
+	});
+}
+
+#[test]
+fn force_origin_can_delete_org() {
+	new_test_ext().execute_with(|| {
+		with_org(|org_id, _index| {
+			DeleteAllowed::set(false);
+			assert_noop!(
+				Organization::delete(Origin::signed(*ALICE), org_id, 0),
+				DispatchError::Other("organization in use")
+			);
+			DeleteAllowed::set(true);
+
+			assert_ok!(Organization::delete(Origin::signed(*ALICE), org_id, 0));
+			assert!(Organization::organization(org_id).is_none());
+		});
+	});
+}
+
+#[test]
+fn add_members_dedupes_accounts() {
+	new_test_ext().execute_with(|| {
+		with_org(|org_id, _index| {
+			// org has 1 member (admin), max 5
+			assert_ok!(Organization::add_members(
+				Origin::signed(*BOB),
+				org_id,
+				vec![*DAVE, *CHARLIE, *DAVE, *DAVE, *EVE, *CHARLIE]
+			));
+			let mut expected = vec![*BOB, *CHARLIE, *DAVE, *EVE];
+			expected.sort();
+			assert_eq!(Organization::members(org_id).unwrap().into_inner(), expected);
+
+			// one slot left, rejected before any change
+			assert_noop!(
+				Organization::add_members(Origin::signed(*BOB), org_id, vec![account(10), account(11)]),
+				Error::<Test>::MaxMemberReached
+			);
+			assert_ok!(Organization::add_members(
+				Origin::signed(*BOB),
+				org_id,
+				vec![account(10), account(10)]
+			));
+			assert_eq!(Organization::members(org_id).unwrap().len(), 5);
+		});
+	});
+}
+
+#[test]
+fn has_admin_access_works() {
+	new_test_ext().execute_with(|| {
+		// organisasi tidak ada, tidak error cukup false
+		assert_eq!(Organization::has_admin_access(&*DAVE, &*BOB), false);
+
+		with_org(|org_id, _index| {
+			System::set_block_number(1);
+			assert_eq!(Organization::has_admin_access(&org_id, &*BOB), true);
+			assert_eq!(Organization::has_admin_access(&org_id, &*DAVE), false);
+
+			assert_ok!(Organization::delegate_access(
+				Origin::signed(*BOB),
+				org_id,
+				*DAVE,
+				Some(5)
+			));
+			assert_eq!(Organization::has_admin_access(&org_id, &*DAVE), true);
+
+			// akses delegasi DAVE expired
+			System::set_block_number(6);
+			assert_eq!(Organization::has_admin_access(&org_id, &*DAVE), false);
+		});
+	});
+}
+
+#[test]
+fn set_admin_adding_member_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		with_org(|org_id, _index| {
+			assert_eq!(Organization::is_member(&org_id, &*CHARLIE), false);
+
+			// set_admin tetap strict
+			assert_err_ignore_postinfo!(
+				Organization::set_admin(Origin::signed(*BOB), org_id, *CHARLIE),
+				Error::<Test>::NotMember
+			);
+
+			System::set_block_number(3);
+			assert_ok!(Organization::set_admin_adding_member(
+				Origin::signed(*BOB),
+				org_id,
+				*CHARLIE
+			));
+			assert_eq!(Organization::get_admin(org_id), Some(*CHARLIE));
+			assert_eq!(Organization::is_member(&org_id, &*CHARLIE), true);
+			assert_eq!(Organization::member_since(org_id, *CHARLIE), Some(3));
+			let members = Organization::members(org_id).unwrap();
+			assert_eq!(
+				Organization::members_root(org_id),
+				Some(Organization::compute_members_root(&members))
+			);
+			assert!(System::events()
+				.into_iter()
+				.any(|r| r.event == Event::Organization(OrgEvent::MemberAdded(org_id, *CHARLIE))));
+			assert_eq!(last_event(), OrgEvent::AdminChanged(org_id, *CHARLIE));
+		});
+	});
+}
+
+#[test]
+fn set_admin_adding_member_respects_max_member_count() {
+	new_test_ext().execute_with(|| {
+		with_org(|org_id, _index| {
+			// org has 1 member (admin), max 5
+			assert_ok!(Organization::add_members(
+				Origin::signed(*BOB),
+				org_id,
+				vec![*CHARLIE, *DAVE, *EVE, account(10)]
+			));
+			assert_err_ignore_postinfo!(
+				Organization::set_admin_adding_member(Origin::signed(*BOB), org_id, account(11)),
+				Error::<Test>::MaxMemberReached
+			);
+			assert_eq!(Organization::get_admin(org_id), Some(*BOB));
+
+			// already a member, no slot needed
+			assert_ok!(Organization::set_admin_adding_member(Origin::signed(*BOB), org_id, *DAVE));
+			assert_eq!(Organization::get_admin(org_id), Some(*DAVE));
+		});
+	});
+}
+
+#[test]
+fn predicted_org_id_matches_created() {
+	new_test_ext().execute_with(|| {
+		let predicted = Organization::predicted_org_id(b"ORG1", b"ORG1 DESCRIPTION", b"", b"");
+		assert!(Organization::organization(&predicted).is_none());
+		with_org(|org_id, _index| {
+			assert_eq!(org_id, predicted);
+
+			// same fields at the next index get another id
+			let predicted =
+				Organization::predicted_org_id(b"ORG1", b"ORG1 DESCRIPTION", b"", b"");
+			assert_ne!(predicted, org_id);
+			assert_ok!(Organization::create(
+				Origin::signed(*ALICE),
+				b"ORG1".to_vec(),
+				b"ORG1 DESCRIPTION".to_vec(),
+				*BOB,
+				b"".to_vec(),
+				b"".to_vec(),
+				None
+			));
+			assert_eq!(Organization::organization_index(2), Some(predicted));
+		});
+	});
+}
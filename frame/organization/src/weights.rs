@@ -50,11 +50,15 @@ pub trait WeightInfo {
 	fn set_flags() -> Weight;
 	fn add_members(n: u32, ) -> Weight;
 	fn remove_member() -> Weight;
+	fn remove_members(n: u32, ) -> Weight;
 	fn set_admin() -> Weight;
+	fn set_admin_adding_member() -> Weight;
 	fn delegate_access() -> Weight;
 	fn revoke_access() -> Weight;
 	fn delegate_access_as() -> Weight;
 	fn transfer() -> Weight;
+	fn set_spend_allowance() -> Weight;
+	fn delete(n: u32, d: u32, ) -> Weight;
 }
 
 /// Weights for pallet_organization using the Substrate node and recommended hardware.
@@ -110,6 +114,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	// Storage: Organization Organizations (r:1 w:0)
+	// Storage: Organization Members (r:1 w:1)
+	// Storage: Organization MembersRoot (r:0 w:1)
+	// Storage: Organization MembershipSince (r:0 w:1)
+	fn remove_members(n: u32, ) -> Weight {
+		(47_000_000 as Weight)
+			.saturating_add((6_737_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
 	// Storage: Organization Members (r:1 w:0)
 	// Storage: Organization Organizations (r:1 w:1)
 	fn set_admin() -> Weight {
@@ -117,6 +132,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	// Storage: Organization Members (r:1 w:1)
+	// Storage: Organization Organizations (r:1 w:1)
+	// Storage: Organization MembersRoot (r:0 w:1)
+	// Storage: Organization MembershipSince (r:0 w:1)
+	// Storage: Organization LastAdminChange (r:1 w:1)
+	fn set_admin_adding_member() -> Weight {
+		(58_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
 	// Storage: Organization Organizations (r:1 w:0)
 	// Storage: Did OwnerOf (r:1 w:0)
 	// Storage: Did DelegateOf (r:1 w:1)
@@ -143,12 +168,54 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
 	// Storage: Organization Organizations (r:1 w:0)
+	// Storage: Organization SpendAllowance (r:1 w:1)
+	// Storage: Did DelegateOf (r:1 w:0)
 	// Storage: System Account (r:2 w:2)
 	fn transfer() -> Weight {
 		(64_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Organization Organizations (r:1 w:0)
+	// Storage: Organization SpendAllowance (r:1 w:1)
+	// Storage: Organization SpendAllowanceCount (r:1 w:1)
+	fn set_spend_allowance() -> Weight {
+		(34_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(3 as Weight))
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
+	// Estimated by hand, not benchmarked yet.
+	// Storage: Organization Organizations (r:1 w:1)
+	// Storage: Organization Members (r:1 w:1)
+	// Storage: Organization MembershipSince (r:0 w:1)
+	// Storage: Organization MembersRoot (r:0 w:1)
+	// Storage: Organization LastAdminChange (r:0 w:1)
+	// Storage: Organization OrganizationFlagData (r:0 w:1)
+	// Storage: Organization IndexOfOrganization (r:1 w:1)
+	// Storage: Organization OrganizationIndexOf (r:0 w:1)
+	// Storage: Organization CreationDeposits (r:1 w:1)
+	// Storage: Organization SpendAllowanceCount (r:1 w:1)
+	// Storage: System Account (r:4 w:4)
+	// Storage: Did DelegatesOf (r:1 w:1)
+	// Storage: Did DelegateOf (r:0 w:1)
+	// Storage: Did DelegateDeposits (r:1 w:1)
+	// Storage: Did AttributeNamesOf (r:1 w:1)
+	// Storage: Did AttributeNonce (r:1 w:0)
+	// Storage: Did AttributeOf (r:0 w:1)
+	// Storage: Did AttributeDeposits (r:1 w:1)
+	// Storage: Did ServicesOf (r:0 w:1)
+	// Storage: Did OwnerOf (r:0 w:1)
+	// Storage: Did UpdatedBy (r:0 w:1)
+	fn delete(n: u32, d: u32, ) -> Weight {
+		(95_000_000 as Weight)
+			.saturating_add((2_100_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add((18_700_000 as Weight).saturating_mul(d as Weight))
+			.saturating_add(T::DbWeight::get().reads(12 as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(d as Weight)))
+			.saturating_add(T::DbWeight::get().writes(19 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes((4 as Weight).saturating_mul(d as Weight)))
+	}
 }
 
 // For backwards compatibility and tests
@@ -203,6 +270,17 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+	// Storage: Organization Organizations (r:1 w:0)
+	// Storage: Organization Members (r:1 w:1)
+	// Storage: Organization MembersRoot (r:0 w:1)
+	// Storage: Organization MembershipSince (r:0 w:1)
+	fn remove_members(n: u32, ) -> Weight {
+		(47_000_000 as Weight)
+			.saturating_add((6_737_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
 	// Storage: Organization Members (r:1 w:0)
 	// Storage: Organization Organizations (r:1 w:1)
 	fn set_admin() -> Weight {
@@ -210,6 +288,16 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+	// Storage: Organization Members (r:1 w:1)
+	// Storage: Organization Organizations (r:1 w:1)
+	// Storage: Organization MembersRoot (r:0 w:1)
+	// Storage: Organization MembershipSince (r:0 w:1)
+	// Storage: Organization LastAdminChange (r:1 w:1)
+	fn set_admin_adding_member() -> Weight {
+		(58_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
 	// Storage: Organization Organizations (r:1 w:0)
 	// Storage: Did OwnerOf (r:1 w:0)
 	// Storage: Did DelegateOf (r:1 w:1)
@@ -236,10 +324,52 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
 	// Storage: Organization Organizations (r:1 w:0)
+	// Storage: Organization SpendAllowance (r:1 w:1)
+	// Storage: Did DelegateOf (r:1 w:0)
 	// Storage: System Account (r:2 w:2)
 	fn transfer() -> Weight {
 		(64_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Organization Organizations (r:1 w:0)
+	// Storage: Organization SpendAllowance (r:1 w:1)
+	// Storage: Organization SpendAllowanceCount (r:1 w:1)
+	fn set_spend_allowance() -> Weight {
+		(34_000_000 as Weight)
 			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
 	}
+	// Estimated by hand, not benchmarked yet.
+	// Storage: Organization Organizations (r:1 w:1)
+	// Storage: Organization Members (r:1 w:1)
+	// Storage: Organization MembershipSince (r:0 w:1)
+	// Storage: Organization MembersRoot (r:0 w:1)
+	// Storage: Organization LastAdminChange (r:0 w:1)
+	// Storage: Organization OrganizationFlagData (r:0 w:1)
+	// Storage: Organization IndexOfOrganization (r:1 w:1)
+	// Storage: Organization OrganizationIndexOf (r:0 w:1)
+	// Storage: Organization CreationDeposits (r:1 w:1)
+	// Storage: Organization SpendAllowanceCount (r:1 w:1)
+	// Storage: System Account (r:4 w:4)
+	// Storage: Did DelegatesOf (r:1 w:1)
+	// Storage: Did DelegateOf (r:0 w:1)
+	// Storage: Did DelegateDeposits (r:1 w:1)
+	// Storage: Did AttributeNamesOf (r:1 w:1)
+	// Storage: Did AttributeNonce (r:1 w:0)
+	// Storage: Did AttributeOf (r:0 w:1)
+	// Storage: Did AttributeDeposits (r:1 w:1)
+	// Storage: Did ServicesOf (r:0 w:1)
+	// Storage: Did OwnerOf (r:0 w:1)
+	// Storage: Did UpdatedBy (r:0 w:1)
+	fn delete(n: u32, d: u32, ) -> Weight {
+		(95_000_000 as Weight)
+			.saturating_add((2_100_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add((18_700_000 as Weight).saturating_mul(d as Weight))
+			.saturating_add(RocksDbWeight::get().reads(12 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(d as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(19 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((4 as Weight).saturating_mul(d as Weight)))
+	}
 }
@@ -42,9 +42,17 @@
 
 use codec::{Decode, Encode};
 use core::result::Result;
-use frame_support::{ensure, sp_runtime::RuntimeDebug, sp_std::prelude::*, types::Property};
+use frame_support::{
+    ensure,
+    sp_runtime::RuntimeDebug,
+    sp_std::prelude::*,
+    traits::StorageVersion,
+    types::{check_props, PropError, Property},
+};
 use frame_system::{self, ensure_signed};
 
+pub mod migrations;
+
 #[cfg(test)]
 mod mock;
 
@@ -62,6 +70,9 @@ pub const PRODUCT_MAX_PROPS: usize = 5;
 pub type ProductId = Vec<u8>;
 pub type Year = u32;
 
+/// The current storage version.
+pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 #[frame_support::pallet]
 pub mod pallet {
 
@@ -71,6 +82,7 @@ pub mod pallet {
 
     #[pallet::pallet]
     #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     // Product contains master data (aka class-level) about a trade item.
@@ -102,6 +114,10 @@ pub mod pallet {
         type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 
         // type CreateRoleOrigin: EnsureOrigin<Self::Origin>;
+
+        /// Maximum number of products an organization can register per year.
+        #[pallet::constant]
+        type MaxProductsPerYear: Get<u32>;
     }
 
     /// Get product by ID.
@@ -113,8 +129,14 @@ pub mod pallet {
     /// Get list of products of the organization.
     #[pallet::storage]
     #[pallet::getter(fn products_of_org)]
-    pub type ProductsOfOrganization<T: Config> =
-        StorageDoubleMap<_, Twox64Concat, T::AccountId, Blake2_128Concat, Year, Vec<ProductId>>;
+    pub type ProductsOfOrganization<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        Year,
+        BoundedVec<ProductId, T::MaxProductsPerYear>,
+    >;
 
     /// Get owner (organization) of the product where belongs to.
     #[pallet::storage]
@@ -130,7 +152,8 @@ pub mod pallet {
         /// 1: account who register the product
         /// 2: product id
         /// 3: organization id where product belong
-        ProductRegistered(T::AccountId, ProductId, T::AccountId),
+        /// 4: registration timestamp
+        ProductRegistered(T::AccountId, ProductId, T::AccountId, T::Moment),
     }
 
     #[pallet::error]
@@ -152,6 +175,9 @@ pub mod pallet {
 
         /// Invalid property value.
         InvalidPropValue,
+
+        /// Organization already registered `MaxProductsPerYear` products in the year.
+        TooManyProducts,
     }
 
     /// Supply Chain product registry module.
@@ -196,19 +222,21 @@ pub mod pallet {
             <pallet_organization::Module<T>>::ensure_access_active_id(&who, &org_id)?;
 
             // Create a product instance
+            let registered = <pallet_timestamp::Module<T>>::now();
             let product = Self::new_product()
                 .identified_by(id.clone())
                 .owned_by(org_id.clone())
-                .registered_on(<pallet_timestamp::Module<T>>::now())
+                .registered_on(registered)
                 .with_props(props)
                 .build();
 
             // Add product & ownerOf (3 DB writes)
+            <ProductsOfOrganization<T>>::try_append(&org_id, year, &id)
+                .map_err(|_| Error::<T>::TooManyProducts)?;
             <Products<T>>::insert(&id, product);
-            <ProductsOfOrganization<T>>::append(&org_id, year, &id);
             <OwnerOf<T>>::insert(&id, &org_id);
 
-            Self::deposit_event(Event::ProductRegistered(who, id, org_id));
+            Self::deposit_event(Event::ProductRegistered(who, id, org_id, registered));
 
             Ok(().into())
         }
@@ -219,6 +247,20 @@ pub mod pallet {
     // ----------------------------------------------------------------
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_runtime_upgrade() -> Weight {
+            migrations::v1::migrate::<T>()
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<(), &'static str> {
+            migrations::v1::pre_migrate::<T>()
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade() -> Result<(), &'static str> {
+            migrations::v1::post_migrate::<T>()
+        }
+
         // fn offchain_worker(n: T::BlockNumber){
         //     // @TODO(you): Your off-chain logic here
         // }
@@ -259,6 +301,11 @@ impl<T: Config> Pallet<T> {
         ProductBuilder::<T::AccountId, T::Moment>::default()
     }
 
+    /// Timestamp at which the product was registered.
+    pub fn registered_at(id: &ProductId) -> Option<T::Moment> {
+        Self::product_by_id(id).map(|product| product.registered)
+    }
+
     pub fn validate_product_id(id: &[u8]) -> Result<(), Error<T>> {
         // Basic product ID validation
         ensure!(!id.is_empty(), Error::<T>::ProductIdMissing);
@@ -279,22 +326,22 @@ impl<T: Config> Pallet<T> {
     }
 
     pub fn validate_product_props(props: &Option<Vec<Property>>) -> Result<(), Error<T>> {
-        if let Some(props) = props {
-            ensure!(props.len() <= PRODUCT_MAX_PROPS, Error::<T>::TooManyProps,);
-            for prop in props {
-                let len = prop.name().len();
-                ensure!(
-                    len > 0 && len <= PRODUCT_PROP_NAME_MAX_LENGTH,
-                    Error::<T>::InvalidPropName
-                );
-                let len = prop.value().len();
-                ensure!(
-                    len > 0 && len <= PRODUCT_PROP_VALUE_MAX_LENGTH,
-                    Error::<T>::InvalidPropValue
-                );
-            }
-        }
-        Ok(())
+        let props = match props {
+            Some(props) => props,
+            None => return Ok(()),
+        };
+        check_props(
+            props,
+            PRODUCT_MAX_PROPS,
+            PRODUCT_PROP_NAME_MAX_LENGTH,
+            PRODUCT_PROP_VALUE_MAX_LENGTH,
+        )
+        .map_err(|e| match e {
+            PropError::TooMany => Error::<T>::TooManyProps,
+            PropError::InvalidName => Error::<T>::InvalidPropName,
+            // No dedicated error for the value type hint.
+            PropError::InvalidValue | PropError::InvalidValueType => Error::<T>::InvalidPropValue,
+        })
     }
 }
 
@@ -0,0 +1,86 @@
+// This file is part of Nuchain.
+//
+// Copyright (C) 2021 Rantai Nusantara Foundation.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Storage migrations for the product registry pallet.
+
+use super::*;
+use frame_support::{
+    traits::{Get, GetStorageVersion, StorageVersion},
+    weights::Weight,
+};
+
+pub mod v1 {
+    use super::*;
+    use core::convert::TryInto;
+
+    /// Bound the `ProductsOfOrganization` year buckets to `MaxProductsPerYear`.
+    ///
+    /// Buckets holding more products than the bound are truncated and a warning is logged,
+    /// the truncated products stay registered in `Products` and `OwnerOf`.
+    pub fn migrate<T: Config>() -> Weight {
+        let onchain = Pallet::<T>::on_chain_storage_version();
+        if onchain >= 1 {
+            return T::DbWeight::get().reads(1);
+        }
+
+        let max = T::MaxProductsPerYear::get() as usize;
+        let mut translated: Weight = 0;
+        <ProductsOfOrganization<T>>::translate::<Vec<ProductId>, _>(|org_id, year, mut ids| {
+            translated = translated.saturating_add(1);
+            if ids.len() > max {
+                log::warn!(
+                    target: "runtime::product-registry",
+                    "truncating {} products of organization {:?} in year {}",
+                    ids.len() - max,
+                    org_id,
+                    year,
+                );
+                ids.truncate(max);
+            }
+            ids.try_into().ok()
+        });
+
+        StorageVersion::new(1).put::<Pallet<T>>();
+        T::DbWeight::get().reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+    }
+
+    #[cfg(feature = "try-runtime")]
+    pub fn pre_migrate<T: Config>() -> Result<(), &'static str> {
+        use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+
+        let count = <ProductsOfOrganization<T>>::iter_keys().count() as u32;
+        Pallet::<T>::set_temp_storage(count, "bucket_count");
+        Ok(())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    pub fn post_migrate<T: Config>() -> Result<(), &'static str> {
+        use frame_support::traits::OnRuntimeUpgradeHelpersExt;
+
+        let count: u32 = Pallet::<T>::get_temp_storage("bucket_count")
+            .ok_or("bucket count not stored in pre_migrate")?;
+        ensure!(
+            <ProductsOfOrganization<T>>::iter().count() as u32 == count,
+            "year buckets lost by migration"
+        );
+        ensure!(
+            Pallet::<T>::on_chain_storage_version() == 1,
+            "storage version not updated by migration"
+        );
+        Ok(())
+    }
+}
@@ -136,9 +136,14 @@ impl pallet_organization::Config for Test {
     type WeightInfo = ();
 }
 
+parameter_types! {
+    pub const MaxProductsPerYear: u32 = 3;
+}
+
 impl pallet_product_registry::Config for Test {
     type Event = Event;
     // type CreateRoleOrigin = MockOrigin<Test>;
+    type MaxProductsPerYear = MaxProductsPerYear;
 }
 
 pub struct MockOrigin<T>(PhantomData<T>);
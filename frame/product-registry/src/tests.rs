@@ -19,7 +19,7 @@ use crate::{
     self as pallet_product_registry, mock::*, Config, Error, Product, ProductId, Products,
     ProductsOfOrganization, Property,
 };
-use frame_support::{assert_err_ignore_postinfo, assert_noop, assert_ok, dispatch};
+use frame_support::{assert_err_ignore_postinfo, assert_noop, assert_ok, dispatch, traits::Get};
 
 type PalletEvent = pallet_product_registry::Event<Test>;
 
@@ -97,18 +97,21 @@ fn create_product_without_props() {
         );
 
         assert_eq!(
-            <ProductsOfOrganization<Test>>::get(org, YEAR1),
+            <ProductsOfOrganization<Test>>::get(org, YEAR1).map(|ids| ids.into_inner()),
             Some(vec![id.clone()])
         );
 
         assert_eq!(ProductRegistry::owner_of(&id), Some(org));
 
-        // Event is raised
+        assert_eq!(ProductRegistry::registered_at(&id), Some(now));
+
+        // Event is raised with the registration timestamp
         assert!(System::events().iter().any(|er| er.event
             == Event::pallet_product_registry(PalletEvent::ProductRegistered(
                 sender,
                 id.clone(),
-                org
+                org,
+                now
             ))));
     });
 }
@@ -147,18 +150,21 @@ fn create_product_with_valid_props() {
         );
 
         assert_eq!(
-            <ProductsOfOrganization<Test>>::get(&org, YEAR2),
+            <ProductsOfOrganization<Test>>::get(&org, YEAR2).map(|ids| ids.into_inner()),
             Some(vec![id.clone()])
         );
 
         assert_eq!(ProductRegistry::owner_of(&id), Some(org));
 
-        // Event is raised
+        assert_eq!(ProductRegistry::registered_at(&id), Some(now));
+
+        // Event is raised with the registration timestamp
         assert!(System::events().iter().any(|er| er.event
             == Event::pallet_product_registry(PalletEvent::ProductRegistered(
                 sender,
                 id.clone(),
-                org
+                org,
+                now
             ))));
     });
 }
@@ -316,3 +322,109 @@ fn create_product_with_invalid_prop_value() {
         );
     })
 }
+
+#[test]
+fn create_product_with_control_char_props() {
+    with_account_and_org(|sender, org, _now| {
+        assert_noop!(
+            ProductRegistry::register(
+                Origin::signed(sender.clone()),
+                TEST_PRODUCT_ID.as_bytes().to_owned(),
+                org.clone(),
+                YEAR1,
+                Some(vec![Property::new(b"pro\np1", b"val1")])
+            ),
+            Error::<Test>::InvalidPropName
+        );
+        assert_noop!(
+            ProductRegistry::register(
+                Origin::signed(sender.clone()),
+                TEST_PRODUCT_ID.as_bytes().to_owned(),
+                org.clone(),
+                YEAR1,
+                Some(vec![Property::new(b"prop1", b"val\x001")])
+            ),
+            Error::<Test>::InvalidPropValue
+        );
+        assert_ok!(ProductRegistry::register(
+            Origin::signed(sender),
+            TEST_PRODUCT_ID.as_bytes().to_owned(),
+            org,
+            YEAR1,
+            Some(vec![Property::new(b"prop1", b"val1")])
+        ));
+    })
+}
+
+#[test]
+fn create_products_up_to_max_per_year() {
+    with_account_and_org(|sender, org, _now| {
+        for i in 0..MaxProductsPerYear::get() {
+            assert_ok!(ProductRegistry::register(
+                Origin::signed(sender.clone()),
+                format!("PROD{}", i).into_bytes(),
+                org.clone(),
+                YEAR1,
+                None
+            ));
+        }
+        assert_eq!(
+            <ProductsOfOrganization<Test>>::get(&org, YEAR1).map(|ids| ids.len()),
+            Some(3)
+        );
+
+        assert_noop!(
+            ProductRegistry::register(
+                Origin::signed(sender.clone()),
+                b"PROD3".to_vec(),
+                org.clone(),
+                YEAR1,
+                None
+            ),
+            Error::<Test>::TooManyProducts
+        );
+
+        // other years have their own bucket
+        assert_ok!(ProductRegistry::register(
+            Origin::signed(sender),
+            b"PROD3".to_vec(),
+            org,
+            YEAR2,
+            None
+        ));
+    })
+}
+
+#[test]
+fn migrate_to_v1_bounds_products_of_organization() {
+    use frame_support::{
+        storage::unhashed,
+        traits::{GetStorageVersion, StorageVersion},
+    };
+
+    with_account_and_org(|_sender, org, _now| {
+        StorageVersion::new(0).put::<ProductRegistry>();
+
+        let ids: Vec<ProductId> = (0..5).map(|i| format!("PROD{}", i).into_bytes()).collect();
+        unhashed::put(
+            &<ProductsOfOrganization<Test>>::hashed_key_for(&org, YEAR1),
+            &ids,
+        );
+        unhashed::put(
+            &<ProductsOfOrganization<Test>>::hashed_key_for(&org, YEAR2),
+            &ids[..2].to_vec(),
+        );
+
+        crate::migrations::v1::migrate::<Test>();
+
+        assert_eq!(ProductRegistry::on_chain_storage_version(), 1);
+        assert_eq!(
+            <ProductsOfOrganization<Test>>::get(&org, YEAR1).map(|ids| ids.into_inner()),
+            Some(ids[..3].to_vec())
+        );
+        assert_eq!(
+            <ProductsOfOrganization<Test>>::get(&org, YEAR2).map(|ids| ids.into_inner()),
+            Some(ids[..2].to_vec())
+        );
+    })
+}
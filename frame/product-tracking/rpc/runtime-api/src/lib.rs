@@ -0,0 +1,19 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait ProductTrackingApi<AccountId>
+    where
+        AccountId: Codec + Send + Sync,
+    {
+		/// Get tracking ids of the organization registered in `year`
+		/// which current status is `status`.
+		fn trackings_of_org_by_status(org_id: AccountId, year: u32, status: Vec<u8>) -> Vec<Vec<u8>>;
+
+		/// Get `(min, max, avg)` of the tracking `id` readings,
+		/// `reading_type` is the SCALE index of the pallet `ReadingType`.
+		fn reading_summary(id: Vec<u8>, reading_type: u8) -> Option<(i64, i64, i64)>;
+	}
+}
@@ -0,0 +1,89 @@
+use codec::Codec;
+use jsonrpsee::{
+	core::{Error as JsonRpseeError, RpcResult},
+	proc_macros::rpc,
+};
+use sc_client_api::{BlockBackend, HeaderBackend};
+use sc_rpc_api::DenyUnsafe;
+use sp_api::{BlockId, ProvideRuntimeApi};
+use sp_runtime::traits::Block as BlockT;
+use std::{
+	marker::{PhantomData, Send, Sync},
+	sync::Arc,
+};
+
+#[rpc(client, server)]
+pub trait ProductTrackingApi<BlockHash, AccountId> {
+	/// Get tracking ids of the organization `org_id` registered in `year`
+	/// which current status is `status`.
+	#[method(name = "productTracking_trackingsOfOrgByStatus")]
+	fn trackings_of_org_by_status(
+		&self,
+		org_id: AccountId,
+		year: u32,
+		status: Vec<u8>,
+	) -> RpcResult<Vec<Vec<u8>>>;
+
+	/// Get `(min, max, avg)` of the tracking `id` readings of type `reading_type`.
+	#[method(name = "productTracking_readingSummary")]
+	fn reading_summary(&self, id: Vec<u8>, reading_type: u8)
+		-> RpcResult<Option<(i64, i64, i64)>>;
+}
+
+pub struct ProductTracking<Block: BlockT, Client> {
+	client: Arc<Client>,
+	deny_unsafe: DenyUnsafe,
+	_marker: PhantomData<Block>,
+}
+
+impl<Block: BlockT, Client> ProductTracking<Block, Client> {
+	/// Create a new ProductTracking API.
+	pub fn new(client: Arc<Client>, deny_unsafe: DenyUnsafe) -> Self {
+		Self { client, deny_unsafe, _marker: PhantomData::default() }
+	}
+}
+
+pub use pallet_product_tracking_runtime_api::ProductTrackingApi as ProductTrackingRuntimeApi;
+
+impl<Block, Client, AccountId> ProductTrackingApiServer<Block::Hash, AccountId>
+	for ProductTracking<Block, Client>
+where
+	Block: BlockT,
+	Client: BlockBackend<Block>
+		+ HeaderBackend<Block>
+		+ ProvideRuntimeApi<Block>
+		+ Send
+		+ Sync
+		+ 'static,
+	AccountId: Codec + Send + Sync + Clone,
+	Client::Api: pallet_product_tracking_runtime_api::ProductTrackingApi<Block, AccountId>,
+{
+	fn trackings_of_org_by_status(
+		&self,
+		org_id: AccountId,
+		year: u32,
+		status: Vec<u8>,
+	) -> RpcResult<Vec<Vec<u8>>> {
+		// scanning trackings is not cheap, only allow it on trusted nodes.
+		self.deny_unsafe.check_if_safe()?;
+		let api = self.client.runtime_api();
+		let block_id = BlockId::hash(self.client.info().best_hash);
+
+		api.trackings_of_org_by_status(&block_id, org_id, year, status)
+			.map_err(|e| JsonRpseeError::to_call_error(e))
+	}
+
+	fn reading_summary(
+		&self,
+		id: Vec<u8>,
+		reading_type: u8,
+	) -> RpcResult<Option<(i64, i64, i64)>> {
+		// scanning tracking events is not cheap, only allow it on trusted nodes.
+		self.deny_unsafe.check_if_safe()?;
+		let api = self.client.runtime_api();
+		let block_id = BlockId::hash(self.client.info().best_hash);
+
+		api.reading_summary(&block_id, id, reading_type)
+			.map_err(|e| JsonRpseeError::to_call_error(e))
+	}
+}
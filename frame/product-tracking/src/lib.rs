@@ -27,6 +27,8 @@ use frame_support::{
     //     storage_lock::{StorageLock, Time},
     // },
     sp_std::prelude::*,
+    traits::Get,
+    transactional,
     types::Property,
 };
 use frame_system::{self, ensure_signed, offchain::SendTransactionTypes};
@@ -55,6 +57,13 @@ pub const LOCK_TIMEOUT_EXPIRATION: u64 = 3000; // in milli-seconds
 pub const MAX_PROPS: usize = 5;
 pub const PROP_NAME_MAX_LENGTH: usize = 30;
 pub const PROP_VALUE_MAX_LENGTH: usize = 60;
+/// Maximum number of trackings scanned per status query.
+pub const STATUS_QUERY_MAX_SCAN: usize = 1000;
+/// Maximum number of tracking events scanned per reading summary query.
+pub const READING_SUMMARY_MAX_EVENTS: usize = 1000;
+/// DID delegate type of the tracking owner that documents an authorized decryptor
+/// of encrypted readings, see [`Pallet::can_read`].
+pub const TRACKING_READER_DELEGATE: &[u8] = b"TrackingReader";
 
 pub type Year = u32;
 
@@ -79,6 +88,14 @@ pub mod pallet {
     {
         type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
         // type CreateRoleOrigin: EnsureOrigin<Self::Origin>;
+
+        /// Maximum number of events that can be stored for a single tracking.
+        #[pallet::constant]
+        type MaxEventsPerTracking: Get<u32>;
+
+        /// Maximum number of updates in a single `update_status_batch`.
+        #[pallet::constant]
+        type MaxStatusBatch: Get<u32>;
     }
 
     #[pallet::storage]
@@ -111,6 +128,15 @@ pub mod pallet {
     pub type EventsOfTracking<T: Config> =
         StorageMap<_, Blake2_128Concat, TrackingId, Vec<TrackingEventIndex>>;
 
+    /// Trackings whose readings are stored as ciphertext.
+    ///
+    /// Chain state is public, this is access-control metadata for the readers only,
+    /// encryption of the readings is up to the tracking owner.
+    #[pallet::storage]
+    #[pallet::getter(fn has_encrypted_readings)]
+    pub type EncryptedReadings<T: Config> =
+        StorageMap<_, Blake2_128Concat, TrackingId, bool, ValueQuery>;
+
     // #[pallet::storage]
     // #[pallet::getter(fn ocw_notifications)]
     // pub type OcwNotifications<T: Config> =
@@ -121,6 +147,7 @@ pub mod pallet {
     pub enum Event<T: Config> {
         TrackingRegistered(T::AccountId, TrackingId, T::AccountId),
         TrackingStatusUpdated(T::AccountId, TrackingId, TrackingEventIndex, TrackingStatus),
+        EncryptedReadingsSet(T::AccountId, TrackingId, bool),
     }
 
     #[pallet::error]
@@ -141,6 +168,7 @@ pub mod pallet {
         TooManyProps,
         InvalidPropName,
         InvalidPropValue,
+        TooManyUpdates,
     }
 
     #[pallet::call]
@@ -194,7 +222,7 @@ pub mod pallet {
             let mut tracking_builder = Self::new_tracking()
                 .identified_by(id.clone())
                 .owned_by(org_id.clone())
-                .registered_at(<pallet_timestamp::Module<T>>::now())
+                .registered_at(Self::now())
                 .with_products(products);
 
             if let Some(props) = props {
@@ -258,50 +286,102 @@ pub mod pallet {
 
             Self::validate_props(&props)?;
 
-            let mut track = <Tracking<T>>::get(&id).ok_or(Error::<T>::TrackingIsUnknown)?;
+            let track = <Tracking<T>>::get(&id).ok_or(Error::<T>::TrackingIsUnknown)?;
 
             ensure!(status != track.status, Error::<T>::TrackingStatusNotChanged);
 
-            // Pastikan origin memiliki akses di organisasi (product owner)
-            // atau origin memiliki akses sebagai ProductTracker
-            ensure!(
-                <pallet_organization::Module<T>>::ensure_access_active_id(&who, &track.owner)
-                    .is_ok()
-                    || <pallet_did::Module<T>>::valid_delegate(
-                        &track.owner,
-                        b"ProductTracker",
-                        &who
-                    )
-                    .is_ok(),
-                Error::<T>::PermissionDenied
-            );
+            Self::ensure_can_update(&who, &track.owner)?;
 
-            // Create tracking event
-            let event = Self::new_tracking_event()
-                .of_type(TrackingEventType::TrackingUpdateStatus)
-                .for_tracking(id.clone())
-                .at_location(location)
-                .with_readings(readings.unwrap_or_default())
-                .at_time(timestamp)
-                .with_status(status.clone())
-                .with_props(props)
-                .build();
+            Self::apply_status_update(
+                who, id, track, status, timestamp, location, readings, props,
+            )?;
 
-            // Storage writes
-            // --------------
-            // Store tracking event (1 DB read, 3 DB writes)
-            let event_idx = Self::store_event(event)?;
-            // Update offchain notifications (1 DB write)
-            // <OcwNotifications<T>>::append(<frame_system::Module<T>>::block_number(), event_idx);
+            Ok(().into())
+        }
 
-            // Update tracking (1 DB write)
-            track.status = status.clone();
-            track.updated = Some(pallet_timestamp::Module::<T>::now());
+        /// Update status of many trackings at once.
+        ///
+        /// Dispatcher of this function must be _signed_ and allowed to update every tracking,
+        /// access is checked once per tracking owner. Every update follows the rules of
+        /// `update_status`, if any of them fails none is applied.
+        ///
+        /// * `updates` - List of tracking ID, status, timestamp and location, at most
+        ///   `MaxStatusBatch`.
+        #[pallet::weight(
+            (10_000_000 as Weight)
+            .saturating_add(
+                (20_000_000 as Weight)
+                    .saturating_add(T::DbWeight::get().reads(3 as Weight))
+                    .saturating_add(T::DbWeight::get().writes(2 as Weight))
+                    .saturating_mul(updates.len() as Weight)
+            )
+        )]
+        #[transactional]
+        pub fn update_status_batch(
+            origin: OriginFor<T>,
+            updates: Vec<(TrackingId, TrackingStatus, T::Moment, Option<ReadPoint>)>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
 
-            <Tracking<T>>::insert(&id, track);
+            ensure!(updates.len() as u32 <= T::MaxStatusBatch::get(), Error::<T>::TooManyUpdates);
 
-            // Raise events
-            Self::deposit_event(Event::TrackingStatusUpdated(who, id, event_idx, status));
+            let mut authorized: Vec<T::AccountId> = Vec::new();
+            for (id, status, timestamp, location) in updates {
+                Self::validate_identifier(&id)?;
+
+                let track = <Tracking<T>>::get(&id).ok_or(Error::<T>::TrackingIsUnknown)?;
+
+                ensure!(status != track.status, Error::<T>::TrackingStatusNotChanged);
+
+                if !authorized.contains(&track.owner) {
+                    Self::ensure_can_update(&who, &track.owner)?;
+                    authorized.push(track.owner.clone());
+                }
+
+                Self::apply_status_update(
+                    who.clone(),
+                    id,
+                    track,
+                    status,
+                    timestamp,
+                    location,
+                    None,
+                    None,
+                )?;
+            }
+
+            Ok(().into())
+        }
+
+        /// Mark readings of the tracking as stored encrypted,
+        /// authorized decryptors are the owner organization admins and
+        /// `TrackingReader` delegates of the owner.
+        ///
+        /// Dispatcher of this function must be _signed_ and have access to the owner organization.
+        ///
+        #[pallet::weight(
+            (10_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+        )]
+        pub fn set_encrypted_readings(
+            origin: OriginFor<T>,
+            id: TrackingId,
+            encrypted: bool,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let track = <Tracking<T>>::get(&id).ok_or(Error::<T>::TrackingIsUnknown)?;
+
+            <pallet_organization::Module<T>>::ensure_access_active_id(&who, &track.owner)?;
+
+            if encrypted {
+                <EncryptedReadings<T>>::insert(&id, true);
+            } else {
+                <EncryptedReadings<T>>::remove(&id);
+            }
+
+            Self::deposit_event(Event::EncryptedReadingsSet(who, id, encrypted));
 
             Ok(().into())
         }
@@ -337,6 +417,65 @@ pub use pallet::*;
 use codec::alloc::vec;
 
 impl<T: Config> Pallet<T> {
+    /// Current time in milliseconds, the same unit as `frame_support::types::now_millis`
+    /// so durations computed here agree with the other pallets.
+    fn now() -> T::Moment {
+        <pallet_timestamp::Module<T>>::now()
+    }
+
+    /// Pastikan `who` memiliki akses di organisasi (product owner)
+    /// atau memiliki akses sebagai ProductTracker.
+    fn ensure_can_update(who: &T::AccountId, owner: &T::AccountId) -> Result<(), Error<T>> {
+        ensure!(
+            <pallet_organization::Module<T>>::ensure_access_active_id(who, owner).is_ok()
+                || <pallet_did::Module<T>>::valid_delegate(owner, b"ProductTracker", who).is_ok(),
+            Error::<T>::PermissionDenied
+        );
+        Ok(())
+    }
+
+    /// Store the status update event of `track` and update its status.
+    #[allow(clippy::too_many_arguments)]
+    fn apply_status_update(
+        who: T::AccountId,
+        id: TrackingId,
+        mut track: Track<T::AccountId, T::Moment>,
+        status: TrackingStatus,
+        timestamp: T::Moment,
+        location: Option<ReadPoint>,
+        readings: Option<Vec<Reading<T::Moment>>>,
+        props: Option<Vec<Property>>,
+    ) -> Result<TrackingEventIndex, Error<T>> {
+        // Create tracking event
+        let event = Self::new_tracking_event()
+            .of_type(TrackingEventType::TrackingUpdateStatus)
+            .for_tracking(id.clone())
+            .at_location(location)
+            .with_readings(readings.unwrap_or_default())
+            .at_time(timestamp)
+            .with_status(status.clone())
+            .with_props(props)
+            .build();
+
+        // Storage writes
+        // --------------
+        // Store tracking event (1 DB read, 3 DB writes)
+        let event_idx = Self::store_event(event)?;
+        // Update offchain notifications (1 DB write)
+        // <OcwNotifications<T>>::append(<frame_system::Module<T>>::block_number(), event_idx);
+
+        // Update tracking (1 DB write)
+        track.status = status.clone();
+        track.updated = Some(Self::now());
+
+        <Tracking<T>>::insert(&id, track);
+
+        // Raise events
+        Self::deposit_event(Event::TrackingStatusUpdated(who, id, event_idx, status));
+
+        Ok(event_idx)
+    }
+
     fn new_tracking() -> TrackingBuilder<T::AccountId, T::Moment> {
         TrackingBuilder::<T::AccountId, T::Moment>::default()
     }
@@ -346,6 +485,12 @@ impl<T: Config> Pallet<T> {
     }
 
     fn store_event(event: TrackingEvent<T::Moment>) -> Result<TrackingEventIndex, Error<T>> {
+        let event_count = <EventsOfTracking<T>>::decode_len(&event.tracking_id).unwrap_or(0);
+        ensure!(
+            event_count < T::MaxEventsPerTracking::get() as usize,
+            Error::<T>::TrackingEventMaxExceeded
+        );
+
         let event_idx = <EventCount<T>>::get()
             .unwrap_or(0)
             .checked_add(1)
@@ -358,6 +503,91 @@ impl<T: Config> Pallet<T> {
         Ok(event_idx)
     }
 
+    /// Get trackings of the organization registered in `year`
+    /// whose current status equals `status`.
+    ///
+    /// Only the first [`STATUS_QUERY_MAX_SCAN`] trackings of the year are scanned,
+    /// this is intended to be used through runtime API / RPC only.
+    pub fn trackings_of_org_by_status(
+        org_id: &T::AccountId,
+        year: Year,
+        status: &[u8],
+    ) -> Vec<TrackingId> {
+        Self::trackings_of_org(org_id, year)
+            .unwrap_or_default()
+            .into_iter()
+            .take(STATUS_QUERY_MAX_SCAN)
+            .filter(|id| {
+                <Tracking<T>>::get(id)
+                    .map(|track| track.status == status)
+                    .unwrap_or(false)
+            })
+            .collect()
+    }
+
+    /// Whether `who` is documented as able to read the tracking readings.
+    ///
+    /// Readings that are not encrypted can be read by anyone, encrypted readings by
+    /// the owner organization admins and [`TRACKING_READER_DELEGATE`] delegates of the owner.
+    /// This is access-control metadata, not enforcement.
+    pub fn can_read(id: &[u8], who: &T::AccountId) -> bool {
+        if !Self::has_encrypted_readings(id) {
+            return true;
+        }
+        match <Tracking<T>>::get(id) {
+            Some(track) => {
+                <pallet_organization::Module<T>>::ensure_access_active_id(who, &track.owner)
+                    .is_ok()
+                    || <pallet_did::Module<T>>::valid_delegate(
+                        &track.owner,
+                        &TRACKING_READER_DELEGATE.to_vec(),
+                        who,
+                    )
+                    .is_ok()
+            }
+            None => false,
+        }
+    }
+
+    /// Get `(min, max, avg)` of the tracking readings of type `reading_type`,
+    /// returns `None` if the tracking has no such readings or its readings are encrypted.
+    ///
+    /// Reading values are parsed as decimal number with the fractional part truncated,
+    /// values that are not a number are skipped. Only the first
+    /// [`READING_SUMMARY_MAX_EVENTS`] events of the tracking are scanned,
+    /// this is intended to be used through runtime API / RPC only.
+    pub fn reading_summary(
+        id: &[u8],
+        reading_type: &ReadingType,
+    ) -> Option<(i64, i64, i64)> {
+        if Self::has_encrypted_readings(id) {
+            return None;
+        }
+
+        let values: Vec<i64> = Self::events_of_tracking(id)
+            .unwrap_or_default()
+            .into_iter()
+            .take(READING_SUMMARY_MAX_EVENTS)
+            .filter_map(|idx| Self::event_by_idx(idx))
+            .flat_map(|event| event.readings)
+            .filter(|reading| &reading.reading_type == reading_type)
+            .filter_map(|reading| Self::parse_reading_value(&reading.value))
+            .collect();
+
+        let min = *values.iter().min()?;
+        let max = *values.iter().max()?;
+        let sum: i128 = values.iter().map(|v| *v as i128).sum();
+        let avg = (sum / values.len() as i128) as i64;
+
+        Some((min, max, avg))
+    }
+
+    fn parse_reading_value(value: &[u8]) -> Option<i64> {
+        let value = core::str::from_utf8(value).ok()?;
+        let integer = value.split('.').next()?;
+        integer.parse::<i64>().ok()
+    }
+
     // (Public) Validation methods
     pub fn validate_identifier(id: &[u8]) -> Result<(), Error<T>> {
         // Basic identifier validation
@@ -139,9 +139,15 @@ impl pallet_product_registry::Config for Test {
     type Event = Event;
     // type CreateRoleOrigin = MockOrigin<Test>;
 }
+parameter_types! {
+    pub const MaxEventsPerTracking: u32 = 5;
+    pub const MaxStatusBatch: u32 = 3;
+}
 impl Config for Test {
     type Event = Event;
     // type CreateRoleOrigin = MockOrigin<Test>;
+    type MaxEventsPerTracking = MaxEventsPerTracking;
+    type MaxStatusBatch = MaxStatusBatch;
 }
 
 pub struct MockOrigin<T>(PhantomData<T>);
@@ -593,6 +593,94 @@ fn update_status_with_custom_props_works() {
     });
 }
 
+#[test]
+fn update_status_batch_works() {
+    with_account_and_org(|sender, org, now| {
+        let ids: Vec<TrackingId> = vec![b"0001".to_vec(), b"0002".to_vec()];
+        for id in ids.iter() {
+            store_test_tracking::<Test>(
+                id.clone(),
+                org,
+                STATUS_PENDING.to_vec(),
+                vec![TEST_PRODUCT_ID.as_bytes().to_owned()],
+                now,
+            );
+        }
+
+        assert_ok!(ProductTracking::update_status_batch(
+            Origin::signed(sender),
+            ids.iter()
+                .map(|id| (id.clone(), STATUS_IN_TRANSIT.to_vec(), now, None))
+                .collect()
+        ));
+
+        for id in ids.iter() {
+            assert_eq!(
+                ProductTracking::tracking(id).map(|t| t.status),
+                Some(STATUS_IN_TRANSIT.to_vec())
+            );
+            assert_eq!(ProductTracking::events_of_tracking(id).map(|e| e.len()), Some(1));
+        }
+
+        // satu event per update
+        let updated = System::events()
+            .iter()
+            .filter(|er| {
+                matches!(
+                    er.event,
+                    TestEvent::pallet_product_tracking(Event::TrackingStatusUpdated(..))
+                )
+            })
+            .count();
+        assert_eq!(updated, 2);
+    });
+}
+
+#[test]
+fn update_status_batch_rolls_back_on_illegal_transition() {
+    with_account_and_org(|sender, org, now| {
+        store_test_tracking::<Test>(
+            b"0001".to_vec(),
+            org,
+            STATUS_PENDING.to_vec(),
+            vec![TEST_PRODUCT_ID.as_bytes().to_owned()],
+            now,
+        );
+        store_test_tracking::<Test>(
+            b"0002".to_vec(),
+            org,
+            STATUS_IN_TRANSIT.to_vec(),
+            vec![TEST_PRODUCT_ID.as_bytes().to_owned()],
+            now,
+        );
+
+        // status 0002 tidak berubah, seluruh batch dibatalkan
+        assert_noop!(
+            ProductTracking::update_status_batch(
+                Origin::signed(sender),
+                vec![
+                    (b"0001".to_vec(), STATUS_IN_TRANSIT.to_vec(), now, None),
+                    (b"0002".to_vec(), STATUS_IN_TRANSIT.to_vec(), now, None),
+                ]
+            ),
+            Error::<Test>::TrackingStatusNotChanged
+        );
+        assert_eq!(
+            ProductTracking::tracking(b"0001".to_vec()).map(|t| t.status),
+            Some(STATUS_PENDING.to_vec())
+        );
+
+        // MaxStatusBatch = 3
+        assert_noop!(
+            ProductTracking::update_status_batch(
+                Origin::signed(sender),
+                vec![(b"0001".to_vec(), STATUS_DELIVER.to_vec(), now, None); 4]
+            ),
+            Error::<Test>::TooManyUpdates
+        );
+    });
+}
+
 #[test]
 fn update_status_with_custom_props_invalid() {
     // test ini memastikan bahwa update status yang menyertakan props
@@ -1089,3 +1177,203 @@ fn register_tracking_with_parent_id() {
         );
     })
 }
+
+#[test]
+fn trackings_of_org_filtered_by_status() {
+    new_test_ext().execute_with(|| {
+        let owner = account_key(TEST_ORGANIZATION);
+        let now = 64;
+        Timestamp::set_timestamp(now);
+
+        let in_transit_ids = vec![b"0001".to_vec(), b"0003".to_vec()];
+        let pending_ids = vec![b"0002".to_vec()];
+
+        for id in in_transit_ids.iter() {
+            store_test_tracking::<Test>(
+                id.clone(),
+                owner,
+                STATUS_IN_TRANSIT.to_vec(),
+                vec![],
+                now,
+            );
+        }
+        for id in pending_ids.iter() {
+            store_test_tracking::<Test>(id.clone(), owner, STATUS_PENDING.to_vec(), vec![], now);
+        }
+        for id in [b"0001", b"0002", b"0003"].iter() {
+            <TrackingOfOrganization<Test>>::append(&owner, YEAR1, id.to_vec());
+        }
+
+        assert_eq!(
+            ProductTracking::trackings_of_org_by_status(&owner, YEAR1, STATUS_IN_TRANSIT),
+            in_transit_ids
+        );
+        assert_eq!(
+            ProductTracking::trackings_of_org_by_status(&owner, YEAR1, STATUS_PENDING),
+            pending_ids
+        );
+        assert!(
+            ProductTracking::trackings_of_org_by_status(&owner, YEAR1, STATUS_DELIVER).is_empty()
+        );
+        assert!(
+            ProductTracking::trackings_of_org_by_status(&owner, YEAR2, STATUS_IN_TRANSIT)
+                .is_empty()
+        );
+    })
+}
+
+#[test]
+fn reading_summary_of_tracking() {
+    new_test_ext().execute_with(|| {
+        let now = 42;
+        Timestamp::set_timestamp(now);
+
+        let tracking_id = TEST_TRACKING_ID.as_bytes().to_owned();
+        let device_id = "14d453ea4bdf46bc8042".as_bytes().to_owned();
+        let reading = |reading_type: ReadingType, value: &[u8]| Reading {
+            device_id: device_id.clone(),
+            reading_type,
+            value: value.to_vec(),
+            timestamp: now,
+        };
+
+        assert_eq!(
+            ProductTracking::reading_summary(&tracking_id, &ReadingType::Temperature),
+            None
+        );
+
+        let events = vec![
+            vec![
+                reading(ReadingType::Temperature, b"4.5"),
+                reading(ReadingType::Humidity, b"80"),
+            ],
+            vec![reading(ReadingType::Temperature, b"-2")],
+            vec![
+                reading(ReadingType::Temperature, b"7"),
+                reading(ReadingType::Temperature, b"n/a"),
+            ],
+        ];
+        for readings in events {
+            let event = TrackingEvent {
+                event_type: TrackingEventType::TrackingScan,
+                tracking_id: tracking_id.clone(),
+                location: None,
+                readings,
+                status: STATUS_IN_TRANSIT.to_vec(),
+                timestamp: now,
+                props: None,
+            };
+            let event_idx = <EventCount<Test>>::get().unwrap_or(0) + 1;
+            <EventCount<Test>>::put(event_idx);
+            AllEvents::<Test>::insert(event_idx, event);
+            <EventsOfTracking<Test>>::append(&tracking_id, event_idx);
+        }
+
+        assert_eq!(
+            ProductTracking::reading_summary(&tracking_id, &ReadingType::Temperature),
+            Some((-2, 7, 3))
+        );
+        assert_eq!(
+            ProductTracking::reading_summary(&tracking_id, &ReadingType::Humidity),
+            Some((80, 80, 80))
+        );
+        assert_eq!(
+            ProductTracking::reading_summary(&tracking_id, &ReadingType::Shock),
+            None
+        );
+    })
+}
+
+#[test]
+fn store_event_rejected_over_max_events_per_tracking() {
+    new_test_ext().execute_with(|| {
+        let now = 42;
+        Timestamp::set_timestamp(now);
+
+        let tracking_id = TEST_TRACKING_ID.as_bytes().to_owned();
+        let new_event = || {
+            ProductTracking::new_tracking_event()
+                .of_type(TrackingEventType::TrackingScan)
+                .for_tracking(tracking_id.clone())
+                .with_status(STATUS_IN_TRANSIT.to_vec())
+                .at_time(now)
+                .build()
+        };
+
+        let max = <Test as Config>::MaxEventsPerTracking::get();
+        for _ in 0..max {
+            assert_ok!(ProductTracking::store_event(new_event()));
+        }
+        assert_eq!(
+            ProductTracking::events_of_tracking(&tracking_id).map(|e| e.len()),
+            Some(max as usize)
+        );
+
+        assert_noop!(
+            ProductTracking::store_event(new_event()),
+            Error::<Test>::TrackingEventMaxExceeded
+        );
+
+        // other trackings are not affected
+        assert_ok!(ProductTracking::store_event(
+            ProductTracking::new_tracking_event()
+                .for_tracking(b"0002".to_vec())
+                .build()
+        ));
+    })
+}
+
+#[test]
+fn tracking_reader_delegate_can_read_encrypted_readings() {
+    with_account_and_org(|sender, org, now| {
+        let id = TEST_TRACKING_ID.as_bytes().to_owned();
+
+        store_test_tracking::<Test>(
+            id.clone(),
+            org,
+            STATUS_PENDING.to_vec(),
+            vec![TEST_PRODUCT_ID.as_bytes().to_owned()],
+            now,
+        );
+
+        let reader = account_key("Wahid");
+        let stranger = account_key("Asing");
+
+        // readings tidak terenkripsi bisa dibaca siapa saja
+        assert!(ProductTracking::can_read(&id, &stranger));
+
+        assert_noop!(
+            ProductTracking::set_encrypted_readings(Origin::signed(stranger), id.clone(), true),
+            pallet_organization::Error::<Test>::PermissionDenied
+        );
+        assert_ok!(ProductTracking::set_encrypted_readings(
+            Origin::signed(sender),
+            id.clone(),
+            true
+        ));
+        assert!(ProductTracking::has_encrypted_readings(&id));
+
+        assert!(ProductTracking::can_read(&id, &sender));
+        assert!(!ProductTracking::can_read(&id, &reader));
+        assert!(!ProductTracking::can_read(&id, &stranger));
+
+        // berikan akses TrackingReader kepada Wahid
+        assert_ok!(pallet_organization::Module::<Test>::h_delegate_access_as(
+            &sender,
+            &org,
+            &reader,
+            TRACKING_READER_DELEGATE,
+            None
+        ));
+
+        assert!(ProductTracking::can_read(&id, &reader));
+        assert!(!ProductTracking::can_read(&id, &stranger));
+
+        assert_ok!(ProductTracking::set_encrypted_readings(
+            Origin::signed(sender),
+            id.clone(),
+            false
+        ));
+        assert!(ProductTracking::can_read(&id, &stranger));
+    });
+}
@@ -19,7 +19,9 @@ pub struct Track<AccountId, Moment> {
     pub owner: AccountId,
     pub status: TrackingStatus,
     pub products: Vec<ProductId>,
+    /// Registration time in milliseconds since the Unix epoch.
     pub registered: Moment,
+    /// Last status update time in milliseconds since the Unix epoch.
     pub updated: Option<Moment>,
     /// parent tracking id yg merefer ke track sebelumnya apabila ada.
     pub parent_id: Option<TrackingId>,
@@ -1,14 +1,65 @@
 use codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
-use sp_runtime::RuntimeDebug;
+use sp_runtime::{RuntimeDebug, SaturatedConversion};
 use sp_std::vec::Vec;
 
-use crate::BoundedVec;
+use crate::{traits::UnixTime, BoundedVec};
 
 pub type Text = Vec<u8>;
 pub type PropName<LN> = BoundedVec<u8, LN>;
 pub type PropValue<LN> = BoundedVec<u8, LN>;
 
+/// Returns `true` if `text` contains no ASCII control characters (`0x00..=0x1f`, `0x7f`).
+///
+/// Control characters in property names and values break JSON exports and UIs.
+pub fn is_printable(text: &[u8]) -> bool {
+	!text.iter().any(u8::is_ascii_control)
+}
+
+/// Number of milliseconds in a day.
+pub const MILLIS_PER_DAY: u64 = 86_400_000;
+
+/// Returns the current time of `T` in milliseconds since the Unix epoch.
+///
+/// Pallets store timestamps and compute durations in milliseconds, use this rather than
+/// converting `UnixTime::now()` by hand so every pallet agrees on the unit.
+pub fn now_millis<T: UnixTime>() -> u64 {
+	T::now().as_millis().saturated_into::<u64>()
+}
+
+fn is_valid_prop_text(text: &[u8], max_len: usize) -> bool {
+	!text.is_empty() && text.len() <= max_len && is_printable(text)
+}
+
+/// Type hint of a property value, tells consumers how to render the value.
+///
+/// Stored in [`Property`] as its `u8` code, codes not listed here are rejected.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub enum PropValueType {
+	/// Plain text.
+	String = 0,
+	/// Decimal number e.g. 12.5
+	Number = 1,
+	/// Date e.g. 2022-01-31
+	Date = 2,
+	/// URI e.g. https://nuchain.network
+	Uri = 3,
+}
+
+impl TryFrom<u8> for PropValueType {
+	type Error = ();
+
+	fn try_from(code: u8) -> Result<Self, Self::Error> {
+		match code {
+			0 => Ok(Self::String),
+			1 => Ok(Self::Number),
+			2 => Ok(Self::Date),
+			3 => Ok(Self::Uri),
+			_ => Err(()),
+		}
+	}
+}
+
 // Contains a name-value pair for a product property e.g. description: Ingredient ABC
 #[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub struct Property<NameT, ValueT> {
@@ -16,6 +67,8 @@ pub struct Property<NameT, ValueT> {
 	name: NameT,
 	// Value of the product property e.g. Ingredient ABC
 	value: ValueT,
+	// Optional `PropValueType` code of the value
+	value_type: Option<u8>,
 }
 
 impl<NameT, ValueT> Property<NameT, ValueT>
@@ -24,7 +77,28 @@ where
 	ValueT: AsRef<[u8]>,
 {
 	pub fn new(name: NameT, value: ValueT) -> Self {
-		Self { name, value }
+		Self { name, value, value_type: None }
+	}
+
+	/// Set the value type hint, see [`PropValueType`].
+	pub fn with_value_type(mut self, value_type: PropValueType) -> Self {
+		self.value_type = Some(value_type as u8);
+		self
+	}
+
+	/// Returns `true` if the name is non-empty, at most `max_len` bytes and printable.
+	pub fn has_valid_name(&self, max_len: usize) -> bool {
+		is_valid_prop_text(self.name(), max_len)
+	}
+
+	/// Returns `true` if the value is non-empty, at most `max_len` bytes and printable.
+	pub fn has_valid_value(&self, max_len: usize) -> bool {
+		is_valid_prop_text(self.value(), max_len)
+	}
+
+	/// Returns `true` if the value type is unset or a known [`PropValueType`] code.
+	pub fn has_valid_value_type(&self) -> bool {
+		self.value_type.map_or(true, |code| PropValueType::try_from(code).is_ok())
 	}
 
 	pub fn name(&self) -> &[u8] {
@@ -34,6 +108,63 @@ where
 	pub fn value(&self) -> &[u8] {
 		self.value.as_ref()
 	}
+
+	/// Value type hint, `None` when unset or not a known code.
+	pub fn value_type(&self) -> Option<PropValueType> {
+		self.value_type.and_then(|code| PropValueType::try_from(code).ok())
+	}
+}
+
+impl<NameT, ValueT> Property<NameT, ValueT> {
+	/// Create a property from its raw parts, `value_type` is the unchecked type code.
+	///
+	/// Used by storage migrations and by callers that validate with
+	/// [`Self::has_valid_value_type`] afterwards.
+	pub fn from_parts(name: NameT, value: ValueT, value_type: Option<u8>) -> Self {
+		Self { name, value, value_type }
+	}
+}
+
+/// Reason a property list is rejected by [`check_props`].
+#[derive(Clone, Copy, RuntimeDebug, PartialEq, Eq)]
+pub enum PropError {
+	/// More than the maximum number of properties.
+	TooMany,
+	/// A name failed [`Property::has_valid_name`].
+	InvalidName,
+	/// A value failed [`Property::has_valid_value`].
+	InvalidValue,
+	/// A value type failed [`Property::has_valid_value_type`].
+	InvalidValueType,
+}
+
+/// Checks a property list against the limits of a pallet, pallets map the [`PropError`]
+/// to their own errors.
+pub fn check_props<NameT, ValueT>(
+	props: &[Property<NameT, ValueT>],
+	max_props: usize,
+	name_max: usize,
+	value_max: usize,
+) -> Result<(), PropError>
+where
+	NameT: AsRef<[u8]>,
+	ValueT: AsRef<[u8]>,
+{
+	if props.len() > max_props {
+		return Err(PropError::TooMany)
+	}
+	for prop in props {
+		if !prop.has_valid_name(name_max) {
+			return Err(PropError::InvalidName)
+		}
+		if !prop.has_valid_value(value_max) {
+			return Err(PropError::InvalidValue)
+		}
+		if !prop.has_valid_value_type() {
+			return Err(PropError::InvalidValueType)
+		}
+	}
+	Ok(())
 }
 
 impl<NameT, ValueT> From<Property<Text, Text>>
@@ -46,6 +177,59 @@ where
 		Self {
 			name: prop.name.clone().try_into().ok().unwrap_or_default(),
 			value: prop.value.clone().try_into().ok().unwrap_or_default(),
+			value_type: prop.value_type,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn check_props_at_boundaries() {
+		let props = |name: &[u8], value: &[u8]| vec![Property::new(name.to_vec(), value.to_vec())];
+
+		assert_eq!(check_props(&props(b"n", b"v"), 1, 1, 1), Ok(()));
+		assert_eq!(check_props(&props(b"name", b"value"), 1, 4, 5), Ok(()));
+		assert_eq!(check_props::<Text, Text>(&[], 0, 4, 5), Ok(()));
+
+		assert_eq!(check_props(&props(b"name", b"value"), 0, 4, 5), Err(PropError::TooMany));
+		assert_eq!(check_props(&props(b"name", b"value"), 1, 3, 5), Err(PropError::InvalidName));
+		assert_eq!(check_props(&props(b"name", b"value"), 1, 4, 4), Err(PropError::InvalidValue));
+		assert_eq!(check_props(&props(b"", b"value"), 1, 4, 5), Err(PropError::InvalidName));
+		assert_eq!(check_props(&props(b"name", b""), 1, 4, 5), Err(PropError::InvalidValue));
+		assert_eq!(check_props(&props(b"na\tm", b"value"), 1, 4, 5), Err(PropError::InvalidName));
+		assert_eq!(
+			check_props(&props(b"name", b"val\x7fe"), 1, 4, 5),
+			Err(PropError::InvalidValue)
+		);
+		assert_eq!(
+			check_props(&[Property::from_parts(b"n".to_vec(), b"v".to_vec(), Some(4))], 1, 1, 1),
+			Err(PropError::InvalidValueType)
+		);
+	}
+
+	#[test]
+	fn property_value_type_roundtrip() {
+		let prop = Property::new(b"date".to_vec(), b"2022-01-31".to_vec())
+			.with_value_type(PropValueType::Date);
+		assert!(prop.has_valid_value_type());
+		assert_eq!(prop.value_type(), Some(PropValueType::Date));
+
+		let decoded = Property::<Text, Text>::decode(&mut &prop.encode()[..]).unwrap();
+		assert_eq!(decoded, prop);
+		assert_eq!(Property::new(b"n".to_vec(), b"v".to_vec()).value_type(), None);
+	}
+
+	#[test]
+	fn property_value_type_allowlist() {
+		for code in 0..=3u8 {
+			assert!(Property::from_parts(b"n".to_vec(), b"v".to_vec(), Some(code))
+				.has_valid_value_type());
 		}
+		let prop = Property::from_parts(b"n".to_vec(), b"v".to_vec(), Some(4));
+		assert!(!prop.has_valid_value_type());
+		assert_eq!(prop.value_type(), None);
 	}
 }